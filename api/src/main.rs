@@ -7,7 +7,10 @@ use std::{
 };
 
 use clap::Parser;
-use jito_merkle_tree::{airdrop_merkle_tree::AirdropMerkleTree, utils::get_merkle_distributor_pda};
+use jito_merkle_tree::{
+    airdrop_merkle_tree::AirdropMerkleTree,
+    utils::{get_campaign_pda, get_merkle_distributor_pda},
+};
 use router::RouterState;
 use solana_program::pubkey::Pubkey;
 use tracing::{info, instrument};
@@ -40,6 +43,10 @@ pub struct Args {
     /// Program ID
     #[clap(long, env)]
     program_id: Pubkey,
+
+    /// Whether the distributors draw from the vault of the campaign of the base and mint
+    #[clap(long, env)]
+    campaign: bool,
 }
 
 #[tokio::main]
@@ -107,6 +114,9 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         },
         tree,
         program_id: args.program_id,
+        campaign: args
+            .campaign
+            .then(|| get_campaign_pda(&args.program_id, &args.base, &args.mint).0),
     });
 
     let app = router::get_routes(state);
@@ -6,6 +6,7 @@ use std::{
     time::Duration,
 };
 
+use anchor_lang::{InstructionData, ToAccountMetas};
 use axum::{
     body::Body,
     error_handling::HandleErrorLayer,
@@ -15,7 +16,9 @@ use axum::{
     Json, Router,
 };
 use http::Request;
-use jito_merkle_tree::{airdrop_merkle_tree::UserProof, tree_node::TreeNode};
+use jito_merkle_tree::{
+    airdrop_merkle_tree::UserProof, tree_node::TreeNode, utils::get_claim_status_pda,
+};
 use serde_derive::{Deserialize, Serialize};
 use solana_program::pubkey::Pubkey;
 
@@ -36,6 +39,8 @@ pub struct RouterState {
     pub program_id: Pubkey,
     pub distributors: Distributors,
     pub tree: HashMap<Pubkey, (Pubkey, TreeNode)>,
+    /// Campaign of the distributors, when they are part of one
+    pub campaign: Option<Pubkey>,
 }
 
 impl Debug for RouterState {
@@ -52,7 +57,11 @@ pub fn get_routes(state: Arc<RouterState>) -> Router {
     let router = Router::new()
         .route("/", get(root))
         .route("/distributors", get(get_distributors))
-        .route("/user/:user_pubkey", get(get_user_info));
+        .route("/user/:user_pubkey", get(get_user_info))
+        .route(
+            "/user/:user_pubkey/close_claim_status",
+            get(get_close_claim_status_ix),
+        );
 
     router
         .layer(
@@ -95,6 +104,8 @@ async fn get_user_info(
         merkle_tree: node.0.to_string(),
         amount: node.1.unlocked_amount(),
         locked_amount: node.1.locked_amount(),
+        leaf_index: node.1.leaf_index,
+        vesting_schedule: node.1.vesting_schedule,
         proof: node
             .1
             .proof
@@ -105,6 +116,57 @@ async fn get_user_info(
     Ok(Json(proof))
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AccountMetaResponse {
+    pub pubkey: String,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct InstructionResponse {
+    pub program_id: String,
+    pub accounts: Vec<AccountMetaResponse>,
+    pub data: Vec<u8>,
+}
+
+/// Build the instruction closing the user claim status, to be signed by the user
+/// once the distributor is clawed back and the locked amount fully withdrawn
+#[instrument(ret)]
+async fn get_close_claim_status_ix(
+    State(state): State<Arc<RouterState>>,
+    Path(user_pubkey): Path<String>,
+) -> Result<impl IntoResponse> {
+    let user_pubkey: Pubkey = Pubkey::from_str(user_pubkey.as_str())?;
+    let (distributor, _node) = state
+        .tree
+        .get(&user_pubkey)
+        .ok_or(ApiError::UserNotFound(user_pubkey.to_string()))?;
+
+    let (claim_status, _bump) = get_claim_status_pda(&state.program_id, &user_pubkey, distributor);
+
+    let accounts = merkle_distributor::accounts::CloseWithdrawnClaimStatus {
+        distributor: *distributor,
+        claim_status,
+        claimant: user_pubkey,
+        campaign: state.campaign,
+    }
+    .to_account_metas(None)
+    .into_iter()
+    .map(|meta| AccountMetaResponse {
+        pubkey: meta.pubkey.to_string(),
+        is_signer: meta.is_signer,
+        is_writable: meta.is_writable,
+    })
+    .collect();
+
+    Ok(Json(InstructionResponse {
+        program_id: state.program_id.to_string(),
+        accounts,
+        data: merkle_distributor::instruction::CloseWithdrawnClaimStatus {}.data(),
+    }))
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct SingleDistributor {
     pub distributor_pubkey: String,
@@ -12,17 +12,26 @@ use anchor_lang::{
     solana_program::sysvar,
     AccountDeserialize, InstructionData, Key, ToAccountMetas,
 };
-use anchor_spl::token::{self, TokenAccount};
+use anchor_spl::token_interface::TokenAccount;
 use anyhow::Result;
 use bincode::deserialize;
 use clap::{Parser, Subcommand};
 use csv::Writer;
 use jito_merkle_tree::{
     airdrop_merkle_tree::AirdropMerkleTree,
+    bonus::{BonusCurveType, LockBonusTier},
     csv_entry::CsvEntry,
-    utils::{get_claim_status_pda, get_merkle_distributor_pda},
+    utils::{
+        get_campaign_pda, get_claim_bitmap_pda, get_claim_fee_pda, get_claim_hook_authority_pda,
+        get_claim_hook_pda, get_claim_revocation_pda, get_claim_status_pda,
+        get_lock_bonus_schedule_pda, get_merkle_distributor_pda,
+    },
+};
+use jito_merkle_verify::HashMode;
+use merkle_distributor::state::{
+    campaign::Campaign, claim_fee::ClaimFee, claim_hook::ClaimHook,
+    merkle_distributor::MerkleDistributor,
 };
-use merkle_distributor::state::merkle_distributor::MerkleDistributor;
 use solana_program::{clock::DEFAULT_MS_PER_SLOT, instruction::Instruction};
 use solana_rpc_client::rpc_client::{RpcClient, SerializableTransaction};
 use solana_sdk::{
@@ -32,7 +41,8 @@ use solana_sdk::{
     transaction::Transaction,
 };
 use spl_associated_token_account::{
-    get_associated_token_address, instruction::create_associated_token_account,
+    get_associated_token_address, get_associated_token_address_with_program_id,
+    instruction::{create_associated_token_account, create_associated_token_account_idempotent},
 };
 
 use crate::instructions::*;
@@ -79,6 +89,76 @@ impl Args {
             client.program(merkle_distributor::id()).unwrap();
         program
     }
+
+    /// Returns the token program owning the mint, either SPL Token or Token-2022
+    fn get_token_program_id(&self) -> Pubkey {
+        let client = RpcClient::new_with_commitment(&self.rpc_url, CommitmentConfig::confirmed());
+        client
+            .get_account(&self.mint)
+            .expect("failed to fetch mint account")
+            .owner
+    }
+
+    /// Returns the campaign owning the token vault of a distributor, if it is part of one
+    fn get_campaign(&self, distributor: &Pubkey) -> Option<Pubkey> {
+        let program = self.get_program_client();
+        let distributor_state = program.account::<MerkleDistributor>(*distributor).unwrap();
+        distributor_state.in_campaign().then(|| {
+            get_campaign_pda(
+                &self.program_id,
+                &distributor_state.base,
+                &distributor_state.mint,
+            )
+            .0
+        })
+    }
+
+    /// Returns whether a distributor has been clawed back, by itself or with its campaign
+    fn is_clawed_back(&self, distributor_state: &MerkleDistributor) -> bool {
+        if distributor_state.clawed_back() || !distributor_state.in_campaign() {
+            return distributor_state.clawed_back();
+        }
+        let program = self.get_program_client();
+        let (campaign, _bump) = get_campaign_pda(
+            &self.program_id,
+            &distributor_state.base,
+            &distributor_state.mint,
+        );
+        program.account::<Campaign>(campaign).unwrap().clawed_back()
+    }
+
+    /// Returns the claim fee PDA of a distributor and the account receiving the fee, if it
+    /// charges one. Token fees go to the token account of the fee receiver, created by `payer`
+    fn get_claim_fee(
+        &self,
+        distributor: &Pubkey,
+        payer: &Pubkey,
+        ixs: &mut Vec<Instruction>,
+    ) -> (Option<Pubkey>, Option<Pubkey>) {
+        let program = self.get_program_client();
+        let distributor_state = program.account::<MerkleDistributor>(*distributor).unwrap();
+        if !distributor_state.has_claim_fee() {
+            return (None, None);
+        }
+        let (claim_fee, _bump) = get_claim_fee_pda(&self.program_id, distributor);
+        let claim_fee_state = program.account::<ClaimFee>(claim_fee).unwrap();
+        if claim_fee_state.bps == 0 {
+            return (Some(claim_fee), Some(claim_fee_state.fee_receiver));
+        }
+        let token_program = self.get_token_program_id();
+        ixs.push(create_associated_token_account_idempotent(
+            payer,
+            &claim_fee_state.fee_receiver,
+            &self.mint,
+            &token_program,
+        ));
+        let fee_receiver = get_associated_token_address_with_program_id(
+            &claim_fee_state.fee_receiver,
+            &self.mint,
+            &token_program,
+        );
+        (Some(claim_fee), Some(fee_receiver))
+    }
 }
 
 // Subcommands
@@ -88,19 +168,73 @@ pub enum Commands {
     Claim(ClaimArgs),
     /// Claim by using jup API
     ClaimFromApi(ClaimFromApiArgs),
+    /// Sign a claim authorization that a relayer can submit
+    SignClaim(SignClaimArgs),
+    /// Submit a signed claim authorization, paying for the claim
+    RelayClaim(RelayClaimArgs),
+    /// Sign a claim voucher for a permissioned distributor, with the operator keypair
+    SignVoucher(SignVoucherArgs),
+    /// Claim from a permissioned distributor with a voucher of the operator
+    ClaimWithVoucher(ClaimWithVoucherArgs),
+    /// Claim from several distributors, of any base and mint, in one instruction
+    ClaimBatch(ClaimBatchArgs),
+    /// Push the claims of a PermissionlessPush distributor to the claimant ATAs, paying the rent
+    PushClaims(PushClaimsArgs),
+    /// Claim from a PermissionlessWithHook distributor, routing the tokens to its hook program
+    ClaimWithHook(ClaimWithHookArgs),
     /// Create a new instance of a merkle distributor
     NewDistributor(NewDistributorArgs),
 
     CloseDistributor(CloseDistributorArgs),
+    /// Close clawed back distributors and their token vaults
+    CloseClawedBackDistributor(CloseClawedBackDistributorArgs),
     CloseClaimStatus(CloseClaimStatusArgs),
+    /// Close the fully withdrawn claim statuses of the keypair in clawed back distributors
+    CloseWithdrawnClaimStatus(CloseWithdrawnClaimStatusArgs),
+    /// Transfer the claim status of a leaf to a new owner, signed by the current owner
+    TransferClaimStatus(TransferClaimStatusArgs),
+    /// Claim unlocked tokens of a claim status owned by the keypair
+    ClaimLocked(ClaimLockedArgs),
+    /// Withdraw the whole locked amount of a claim status now, minus the early exit penalty
+    ClaimLockedEarly(ClaimLockedArgs),
     /// Clawback tokens from merkle distributor
     #[clap(hide = true)]
     Clawback(ClawbackArgs),
+    /// Create a campaign owning a single vault for the distributors of its base
+    NewCampaign(NewCampaignArgs),
+    /// Fund the campaign vault up to the max total claim of its distributors
+    FundCampaign,
+    /// Clawback the campaign vault, for all its distributors at once
+    ClawbackCampaign,
+    /// Transfer vault tokens above the amount owed to claimants to the clawback receiver
+    SweepSurplus(SweepSurplusArgs),
     /// Create a Merkle tree, given a CSV of recipients
     CreateMerkleTree(CreateMerkleTreeArgs),
+    /// Split the unclaimed tokens of clawed back distributors pro rata to the wallets that
+    /// claimed, writing the CSV and the merkle trees of the next round
+    Redistribute(RedistributeArgs),
     SetAdmin(SetAdminArgs),
+    /// Nominate a new admin, which has to accept it
+    ProposeAdmin(SetAdminArgs),
+    /// Accept the admin nomination, signed by the pending admin
+    AcceptAdmin(PendingHandoverArgs),
+    CancelPendingAdmin(PendingHandoverArgs),
+    /// Pause claims of distributors
+    Pause(SetPauseArgs),
+    /// Unpause claims of distributors
+    Unpause(SetPauseArgs),
+    /// Revoke the unclaimed leaf of a claimant
+    RevokeClaim(RevokeClaimArgs),
+    /// Revoke the unvested locked amount of a claimant, returning it to the clawback receiver
+    RevokeVesting(RevokeVestingArgs),
 
     SetActivationPoint(SetActivationArgs),
+    /// Move clawback start later, extending the claim window
+    ExtendClawbackStartTs(ExtendClawbackStartTsArgs),
+    /// Create the claim bitmaps of bitmap distributors
+    NewClaimBitmap(NewClaimBitmapArgs),
+    /// Close the claim bitmaps of clawed back bitmap distributors, refunding their rent
+    CloseClaimBitmap(CloseClaimBitmapArgs),
     SetActivationSlotByTime(SetActivationSlotByTimeArgs),
 
     CreateTestList(CreateTestListArgs),
@@ -123,6 +257,11 @@ pub enum Commands {
     TotalClaim(TotalClaimAgrs),
 
     SetClawbackReceiver(ClawbackReceiverArgs),
+    /// Nominate a new clawback receiver, which has to accept it
+    ProposeClawbackReceiver(ProposeClawbackReceiverArgs),
+    /// Accept the clawback receiver nomination, signed by the receiver
+    AcceptClawbackReceiver(PendingHandoverArgs),
+    CancelPendingClawbackReceiver(PendingHandoverArgs),
 
     ViewDistributors(ViewDistributorsArgs),
     ViewDistributorByPubkey(ViewDistributorByPubkeyArgs),
@@ -139,8 +278,45 @@ pub struct CloseDistributorArgs {
     pub airdrop_version: Option<u64>,
 }
 
+#[derive(Parser, Debug)]
+pub struct CloseClawedBackDistributorArgs {
+    #[clap(long, env)]
+    pub from_version: u64,
+    #[clap(long, env)]
+    pub to_version: u64,
+}
+
 #[derive(Parser, Debug)]
 pub struct CloseClaimStatusArgs {}
+
+#[derive(Parser, Debug)]
+pub struct CloseWithdrawnClaimStatusArgs {
+    #[clap(long, env)]
+    pub from_version: u64,
+    #[clap(long, env)]
+    pub to_version: u64,
+}
+
+#[derive(Parser, Debug)]
+pub struct TransferClaimStatusArgs {
+    #[clap(long, env)]
+    pub airdrop_version: u64,
+    /// New owner of the claim status
+    #[clap(long, env)]
+    pub new_claimant: Pubkey,
+    /// Claimant of the leaf the claim status was created for, defaults to the keypair
+    #[clap(long, env)]
+    pub leaf_claimant: Option<Pubkey>,
+}
+
+#[derive(Parser, Debug)]
+pub struct ClaimLockedArgs {
+    #[clap(long, env)]
+    pub airdrop_version: u64,
+    /// Claimant of the leaf the claim status was created for, defaults to the keypair
+    #[clap(long, env)]
+    pub leaf_claimant: Option<Pubkey>,
+}
 // NewClaim and Claim subcommand args
 #[derive(Parser, Debug)]
 pub struct ClaimArgs {
@@ -149,6 +325,79 @@ pub struct ClaimArgs {
     pub merkle_tree_path: PathBuf,
 }
 
+#[derive(Parser, Debug)]
+pub struct SignClaimArgs {
+    /// Merkle distributor path
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+    /// Token account receiving the tokens, defaults to the claimant ATA
+    #[clap(long, env)]
+    pub destination: Option<Pubkey>,
+    /// Unix timestamp after which the authorization expires
+    #[clap(long, env)]
+    pub expiry: i64,
+    #[clap(long, env, default_value_t = 0)]
+    pub nonce: u64,
+    /// Signed authorization out path
+    #[clap(long, env)]
+    pub authorization_path: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub struct RelayClaimArgs {
+    /// Merkle distributor path
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+    /// Signed authorization path
+    #[clap(long, env)]
+    pub authorization_path: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub struct SignVoucherArgs {
+    #[clap(long, env)]
+    pub airdrop_version: u64,
+    /// Claimant allowed to claim
+    #[clap(long, env)]
+    pub claimant: Pubkey,
+    /// Unix timestamp after which the voucher expires
+    #[clap(long, env)]
+    pub expiry: i64,
+    /// Maximum amount transferred over the whole claim
+    #[clap(long, env)]
+    pub max_amount: Option<u64>,
+    /// Signed voucher out path
+    #[clap(long, env)]
+    pub voucher_path: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub struct ClaimWithVoucherArgs {
+    /// Merkle distributor path
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+    /// Signed voucher path
+    #[clap(long, env)]
+    pub voucher_path: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub struct ClaimBatchArgs {
+    /// Distributors to claim from
+    #[clap(long, required = true)]
+    pub distributor: Vec<Pubkey>,
+    /// Merkle tree path of each distributor, in the same order
+    #[clap(long, required = true)]
+    pub merkle_tree_path: Vec<PathBuf>,
+}
+
+#[derive(Parser, Debug)]
+pub struct PushClaimsArgs {
+    /// Merkle distributor path
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+}
+
 #[derive(Parser, Debug)]
 pub struct ClaimFromApiArgs {
     /// Merkle distributor path
@@ -193,6 +442,19 @@ pub struct VerifyArgs {
     #[clap(long, env)]
     pub closable: bool,
 
+    /// Whether the admin can revoke unvested locked amounts
+    #[clap(long, env)]
+    pub revocable: bool,
+
+    /// Penalty of early exits on the unvested amount before the lockup starts (bps),
+    /// decreasing linearly to 0 at the end of the lockup. 0 disables early exits
+    #[clap(long, env, default_value_t = 0)]
+    pub early_exit_penalty_bps: u16,
+
+    /// Send the penalty of early exits to the clawback receiver instead of leaving it in the vault
+    #[clap(long, env)]
+    pub early_exit_penalty_to_clawback: bool,
+
     #[clap(long, env)]
     pub skip_verify_amount: bool,
 
@@ -242,6 +504,23 @@ pub struct NewDistributorArgs {
     #[clap(long, env)]
     pub closable: bool,
 
+    /// Whether the admin can revoke unvested locked amounts
+    #[clap(long, env)]
+    pub revocable: bool,
+
+    /// Penalty of early exits on the unvested amount before the lockup starts (bps),
+    /// decreasing linearly to 0 at the end of the lockup. 0 disables early exits
+    #[clap(long, env, default_value_t = 0)]
+    pub early_exit_penalty_bps: u16,
+
+    /// Send the penalty of early exits to the clawback receiver instead of leaving it in the vault
+    #[clap(long, env)]
+    pub early_exit_penalty_to_clawback: bool,
+
+    /// Create the distributors in the campaign of the base, drawing from its vault
+    #[clap(long, env)]
+    pub campaign: bool,
+
     #[clap(long, env)]
     pub skip_verify: bool,
 
@@ -256,6 +535,15 @@ pub struct NewDistributorArgs {
     #[clap(long, env)]
     pub bonus_vesting_duration: u64,
 
+    /// Shape of the bonus over time: linear, step or exponential_decay. The vesting duration
+    /// is the half-life of the exponential decay
+    #[clap(long, env, default_value_t = BonusCurveType::Linear)]
+    pub bonus_curve: BonusCurveType,
+
+    /// Number of tiers of the step bonus curve
+    #[clap(long, env, default_value_t = 0)]
+    pub bonus_num_steps: u16,
+
     #[clap(long, env)]
     pub bonus_multiplier: u64,
 
@@ -267,6 +555,42 @@ pub struct NewDistributorArgs {
 
     #[clap(long, env)]
     pub locker: Pubkey,
+
+    /// Wallet receiving a fee on every claim, set with either --fee-lamports or --fee-bps
+    #[clap(long, env)]
+    pub fee_receiver: Option<Pubkey>,
+
+    /// Lamports paid by the claimant on every claim
+    #[clap(long, env, default_value_t = 0)]
+    pub fee_lamports: u64,
+
+    /// Basis points of the claimed tokens going to the token account of the fee receiver
+    #[clap(long, env, default_value_t = 0)]
+    pub fee_bps: u16,
+
+    /// Share of the bonus of claim and stake escrows locked for at least a duration, as
+    /// min_lock_duration:bonus_bps in increasing order. Max locks always get the full bonus
+    #[clap(long)]
+    pub lock_bonus_tier: Vec<LockBonusTier>,
+
+    /// Program called with every claim of a PermissionlessWithHook distributor
+    #[clap(long, env)]
+    pub hook_program: Option<Pubkey>,
+}
+
+#[derive(Parser, Debug)]
+pub struct NewCampaignArgs {
+    /// When to make the clawback period start, not before the clawback of any distributor
+    #[clap(long, env)]
+    pub clawback_start_ts: i64,
+
+    /// Base keypair
+    #[clap(long, env)]
+    pub base_path: String,
+
+    /// Clawback receiver owner
+    #[clap(long, env)]
+    pub clawback_receiver_owner: Pubkey,
 }
 
 #[derive(Parser, Debug)]
@@ -277,6 +601,59 @@ pub struct ClawbackArgs {
     pub to_version: u64,
 }
 
+#[derive(Parser, Debug)]
+pub struct SweepSurplusArgs {
+    #[clap(long, env)]
+    pub from_version: u64,
+    #[clap(long, env)]
+    pub to_version: u64,
+}
+
+#[derive(Parser, Debug)]
+pub struct ClaimWithHookArgs {
+    /// Merkle distributor path
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+
+    /// Token account of the hook receiving the tokens, defaults to the ATA of the hook authority
+    #[clap(long, env)]
+    pub hook_token_account: Option<Pubkey>,
+
+    /// Accounts of the hook program, in order, suffixed with :w when writable
+    #[clap(long)]
+    pub hook_account: Vec<String>,
+}
+
+#[derive(Parser, Debug)]
+pub struct RedistributeArgs {
+    /// First clawed back version
+    #[clap(long, env)]
+    pub from_version: u64,
+    /// Last clawed back version
+    #[clap(long, env)]
+    pub to_version: u64,
+
+    /// Amount to redistribute (token amount), defaults to the amount left unclaimed in the versions
+    #[clap(long, env)]
+    pub total_amount: Option<u64>,
+
+    /// CSV out path of the allocations
+    #[clap(long, env)]
+    pub csv_path: PathBuf,
+
+    /// Merkle tree out path of the next round
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+
+    /// Airdrop version of the first tree of the next round
+    #[clap(long, env)]
+    pub airdrop_version: u64,
+
+    /// max nodes per tree
+    #[clap(long, env)]
+    pub max_nodes_per_tree: u64,
+}
+
 #[derive(Parser, Debug)]
 pub struct CreateMerkleTreeArgs {
     /// CSV path
@@ -298,6 +675,15 @@ pub struct CreateMerkleTreeArgs {
     pub amount: u64,
     #[clap(long, env)]
     pub decimals: u32,
+
+    /// Commit the leaf index in each leaf, for distributors claimed through a claim bitmap
+    #[clap(long, env)]
+    pub with_leaf_index: bool,
+
+    /// Hashing of the tree, keccak builds OpenZeppelin StandardMerkleTree compatible trees
+    /// for EVM deployments
+    #[clap(long, env, default_value_t = HashMode::Sha256)]
+    pub hash_mode: HashMode,
 }
 
 #[derive(Parser, Debug)]
@@ -310,6 +696,41 @@ pub struct SetAdminArgs {
     pub to_version: u64,
 }
 
+#[derive(Parser, Debug)]
+pub struct PendingHandoverArgs {
+    #[clap(long, env)]
+    pub from_version: u64,
+    #[clap(long, env)]
+    pub to_version: u64,
+}
+
+#[derive(Parser, Debug)]
+pub struct SetPauseArgs {
+    #[clap(long, env)]
+    pub from_version: u64,
+    #[clap(long, env)]
+    pub to_version: u64,
+}
+
+#[derive(Parser, Debug)]
+pub struct RevokeClaimArgs {
+    /// Merkle distributor path
+    #[clap(long, env)]
+    pub merkle_tree_path: PathBuf,
+    /// Claimant whose leaf is revoked
+    #[clap(long, env)]
+    pub claimant: Pubkey,
+}
+
+#[derive(Parser, Debug)]
+pub struct RevokeVestingArgs {
+    #[clap(long, env)]
+    pub airdrop_version: u64,
+    /// Claimant whose vesting is revoked
+    #[clap(long, env)]
+    pub claimant: Pubkey,
+}
+
 #[derive(Parser, Debug)]
 pub struct SetActivationArgs {
     #[clap(long, env)]
@@ -320,6 +741,32 @@ pub struct SetActivationArgs {
     pub activation_point: u64, // can be timestamp or slot
 }
 
+#[derive(Parser, Debug)]
+pub struct ExtendClawbackStartTsArgs {
+    #[clap(long, env)]
+    pub from_version: u64,
+    #[clap(long, env)]
+    pub to_version: u64,
+    #[clap(long, env)]
+    pub clawback_start_ts: i64,
+}
+
+#[derive(Parser, Debug)]
+pub struct NewClaimBitmapArgs {
+    #[clap(long, env)]
+    pub from_version: u64,
+    #[clap(long, env)]
+    pub to_version: u64,
+}
+
+#[derive(Parser, Debug)]
+pub struct CloseClaimBitmapArgs {
+    #[clap(long, env)]
+    pub from_version: u64,
+    #[clap(long, env)]
+    pub to_version: u64,
+}
+
 #[derive(Parser, Debug)]
 pub struct SetActivationSlotByTimeArgs {
     /// Merkle tree out path
@@ -482,6 +929,16 @@ pub struct ClawbackReceiverArgs {
     receiver: Pubkey,
 }
 
+#[derive(Parser, Debug)]
+pub struct ProposeClawbackReceiverArgs {
+    #[clap(long, env)]
+    pub receiver: Pubkey,
+    #[clap(long, env)]
+    pub from_version: u64,
+    #[clap(long, env)]
+    pub to_version: u64,
+}
+
 #[derive(Parser, Debug)]
 pub struct ViewDistributorsArgs {
     #[clap(long, env)]
@@ -506,22 +963,75 @@ fn main() {
         Commands::CloseDistributor(close_distributor_args) => {
             process_close_distributor(&args, close_distributor_args);
         }
+        Commands::CloseClawedBackDistributor(sub_args) => {
+            process_close_clawed_back_distributor(&args, sub_args);
+        }
         Commands::Claim(claim_args) => {
             process_claim(&args, claim_args);
         }
         Commands::ClaimFromApi(claim_args) => {
             process_claim_from_api(&args, claim_args);
         }
+        Commands::SignClaim(sign_claim_args) => {
+            process_sign_claim(&args, sign_claim_args);
+        }
+        Commands::RelayClaim(relay_claim_args) => {
+            process_relay_claim(&args, relay_claim_args);
+        }
+        Commands::SignVoucher(sub_args) => {
+            process_sign_voucher(&args, sub_args);
+        }
+        Commands::ClaimWithVoucher(sub_args) => {
+            process_claim_with_voucher(&args, sub_args);
+        }
+        Commands::ClaimBatch(sub_args) => process_claim_batch(&args, sub_args),
+        Commands::PushClaims(sub_args) => process_push_claims(&args, sub_args),
         Commands::Clawback(clawback_args) => process_clawback(&args, clawback_args),
+        Commands::NewCampaign(sub_args) => process_new_campaign(&args, sub_args),
+        Commands::FundCampaign => process_fund_campaign(&args),
+        Commands::ClawbackCampaign => process_clawback_campaign(&args),
+        Commands::SweepSurplus(sub_args) => process_sweep_surplus(&args, sub_args),
         Commands::CreateMerkleTree(merkle_tree_args) => {
             process_create_merkle_tree(merkle_tree_args);
         }
+        Commands::Redistribute(sub_args) => process_redistribute(&args, sub_args),
+        Commands::ClaimWithHook(sub_args) => process_claim_with_hook(&args, sub_args),
         Commands::SetAdmin(set_admin_args) => {
             process_set_admin(&args, set_admin_args);
         }
+        Commands::ProposeAdmin(sub_args) => {
+            process_propose_admin(&args, sub_args);
+        }
+        Commands::AcceptAdmin(sub_args) => {
+            process_accept_admin(&args, sub_args);
+        }
+        Commands::CancelPendingAdmin(sub_args) => {
+            process_cancel_pending_admin(&args, sub_args);
+        }
+        Commands::Pause(sub_args) => {
+            process_set_pause(&args, sub_args, true);
+        }
+        Commands::Unpause(sub_args) => {
+            process_set_pause(&args, sub_args, false);
+        }
+        Commands::RevokeClaim(sub_args) => {
+            process_revoke_claim(&args, sub_args);
+        }
+        Commands::RevokeVesting(sub_args) => {
+            process_revoke_vesting(&args, sub_args);
+        }
         Commands::SetActivationPoint(sub_args) => {
             process_set_activation_point(&args, sub_args);
         }
+        Commands::ExtendClawbackStartTs(sub_args) => {
+            process_extend_clawback_start_ts(&args, sub_args);
+        }
+        Commands::NewClaimBitmap(sub_args) => {
+            process_new_claim_bitmap(&args, sub_args);
+        }
+        Commands::CloseClaimBitmap(sub_args) => {
+            process_close_claim_bitmap(&args, sub_args);
+        }
         Commands::SetActivationSlotByTime(sub_args) => {
             process_set_activation_slot_by_time(&args, sub_args);
         }
@@ -552,6 +1062,18 @@ fn main() {
         Commands::CloseClaimStatus(_args) => {
             process_close_claim_status(&args);
         }
+        Commands::CloseWithdrawnClaimStatus(sub_args) => {
+            process_close_withdrawn_claim_status(&args, sub_args);
+        }
+        Commands::TransferClaimStatus(sub_args) => {
+            process_transfer_claim_status(&args, sub_args);
+        }
+        Commands::ClaimLocked(sub_args) => {
+            process_claim_locked(&args, sub_args);
+        }
+        Commands::ClaimLockedEarly(sub_args) => {
+            process_claim_locked_early(&args, sub_args);
+        }
         Commands::FilterAndMergeList(filter_and_merge_list_args) => {
             process_filter_and_merge(filter_and_merge_list_args);
         }
@@ -572,6 +1094,15 @@ fn main() {
         Commands::SetClawbackReceiver(set_clawback_receiver_argrs) => {
             process_set_clawback_receiver(&args, set_clawback_receiver_argrs)
         }
+        Commands::ProposeClawbackReceiver(sub_args) => {
+            process_propose_clawback_receiver(&args, sub_args);
+        }
+        Commands::AcceptClawbackReceiver(sub_args) => {
+            process_accept_clawback_receiver(&args, sub_args);
+        }
+        Commands::CancelPendingClawbackReceiver(sub_args) => {
+            process_cancel_pending_clawback_receiver(&args, sub_args);
+        }
         Commands::UploadMerkleTree(upload_args) => {
             // Create a new args struct with the required parameters from the main Args
             let mut enhanced_upload_args = upload_args.clone();
@@ -638,6 +1169,20 @@ fn check_distributor_onchain_matches(
             return Err("closable mismatch");
         }
 
+        if distributor.revocable() != new_distributor_args.revocable {
+            return Err("revocable mismatch");
+        }
+
+        if distributor.early_exit_penalty_bps != new_distributor_args.early_exit_penalty_bps {
+            return Err("early_exit_penalty_bps mismatch");
+        }
+
+        if distributor.early_exit_penalty_to_clawback()
+            != new_distributor_args.early_exit_penalty_to_clawback
+        {
+            return Err("early_exit_penalty_to_clawback mismatch");
+        }
+
         if distributor.airdrop_bonus.total_bonus != total_bonus {
             return Err("total_bonus mismatch");
         }
@@ -647,6 +1192,23 @@ fn check_distributor_onchain_matches(
             return Err("bonus_vesting_duration mismatch");
         }
 
+        if distributor.airdrop_bonus.curve_type != new_distributor_args.bonus_curve as u8 {
+            return Err("bonus_curve mismatch");
+        }
+
+        if distributor.airdrop_bonus.num_steps != new_distributor_args.bonus_num_steps {
+            return Err("bonus_num_steps mismatch");
+        }
+
+        if distributor.has_claim_fee() != new_distributor_args.fee_receiver.is_some() {
+            return Err("claim fee mismatch");
+        }
+
+        if distributor.has_lock_bonus_schedule() == new_distributor_args.lock_bonus_tier.is_empty()
+        {
+            return Err("lock bonus schedule mismatch");
+        }
+
         if distributor.operator != new_distributor_args.operator {
             return Err("operator mismatch");
         }
@@ -655,12 +1217,22 @@ fn check_distributor_onchain_matches(
             return Err("locker mismatch");
         }
 
+        let (claim_hook, _bump) = get_claim_hook_pda(&args.program_id, &pubkey);
+        let hook_program = args
+            .get_program_client()
+            .account::<ClaimHook>(claim_hook)
+            .ok()
+            .map(|claim_hook| claim_hook.hook_program);
+        if hook_program != new_distributor_args.hook_program {
+            return Err("hook program mismatch");
+        }
+
         // TODO fix code
-        let clawback_receiver_token_account =
-            spl_associated_token_account::get_associated_token_address(
-                &new_distributor_args.clawback_receiver_owner,
-                &args.mint,
-            );
+        let clawback_receiver_token_account = get_associated_token_address_with_program_id(
+            &new_distributor_args.clawback_receiver_owner,
+            &args.mint,
+            &args.get_token_program_id(),
+        );
 
         if clawback_receiver_token_account != distributor.clawback_receiver {
             return Err("clawback_receiver mismatch");
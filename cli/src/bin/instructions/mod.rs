@@ -4,16 +4,30 @@ pub use process_claim::*;
 pub use process_new_claim::*;
 pub mod proccess_close_distribitor;
 pub use proccess_close_distribitor::*;
+pub mod process_close_clawed_back_distributor;
+pub use process_close_clawed_back_distributor::*;
 pub mod process_new_distributor;
 pub use process_new_distributor::*;
 pub mod process_clawback;
 pub use process_clawback::*;
+pub mod process_sweep_surplus;
+pub use process_sweep_surplus::*;
 pub mod process_create_merkle_tree;
 pub use process_create_merkle_tree::*;
 pub mod process_set_admin;
 pub use process_set_admin::*;
+pub mod process_transfer_admin;
+pub use process_transfer_admin::*;
 pub mod process_set_activation_point;
 pub use process_set_activation_point::*;
+pub mod process_extend_clawback_start_ts;
+pub use process_extend_clawback_start_ts::*;
+pub mod process_new_claim_bitmap;
+pub use process_new_claim_bitmap::*;
+pub mod process_close_claim_bitmap;
+pub use process_close_claim_bitmap::*;
+pub mod process_new_claim_with_bitmap;
+pub use process_new_claim_with_bitmap::*;
 pub mod process_set_enable_activation_slot_by_time;
 pub use process_set_enable_activation_slot_by_time::*;
 pub mod process_create_dummy_csv;
@@ -32,6 +46,8 @@ pub mod process_get_slot;
 pub use process_get_slot::*;
 pub mod process_close_claim_status;
 pub use process_close_claim_status::*;
+pub mod process_close_withdrawn_claim_status;
+pub use process_close_withdrawn_claim_status::*;
 pub mod process_filter_and_merge;
 pub use process_filter_and_merge::*;
 pub mod process_generate_kv_proof;
@@ -46,5 +62,29 @@ pub mod view_distributor;
 pub use view_distributor::*;
 pub mod process_claim_from_api;
 pub use process_claim_from_api::*;
+pub mod process_claim_with_signature;
+pub use process_claim_with_signature::*;
 pub mod upload_merkle_tree;
 pub use upload_merkle_tree::*;
+pub mod process_set_pause;
+pub use process_set_pause::*;
+pub mod process_revoke_claim;
+pub use process_revoke_claim::*;
+pub mod process_revoke_vesting;
+pub use process_revoke_vesting::*;
+pub mod process_transfer_claim_status;
+pub use process_transfer_claim_status::*;
+pub mod process_claim_with_voucher;
+pub use process_claim_with_voucher::*;
+pub mod process_campaign;
+pub use process_campaign::*;
+pub mod process_claim_batch;
+pub use process_claim_batch::*;
+pub mod process_push_claims;
+pub use process_push_claims::*;
+pub mod process_claim_locked_early;
+pub use process_claim_locked_early::*;
+pub mod process_redistribute;
+pub use process_redistribute::*;
+pub mod process_claim_with_hook;
+pub use process_claim_with_hook::*;
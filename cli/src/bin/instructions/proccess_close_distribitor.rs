@@ -10,6 +10,8 @@ pub fn process_close_distributor(args: &Args, close_distributor_args: &CloseDist
         .collect();
     paths.sort_by_key(|dir| dir.path());
 
+    let token_program = args.get_token_program_id();
+
     for file in paths {
         let single_tree_path = file.path();
 
@@ -49,9 +51,10 @@ pub fn process_close_distributor(args: &Args, close_distributor_args: &CloseDist
 
         let merkle_distributor_state = merkle_distributor_state.unwrap();
 
-        let destination_token_account = spl_associated_token_account::get_associated_token_address(
+        let destination_token_account = get_associated_token_address_with_program_id(
             &keypair.pubkey(),
             &args.mint,
+            &token_program,
         );
 
         if client.get_account_data(&destination_token_account).is_err() {
@@ -60,7 +63,7 @@ pub fn process_close_distributor(args: &Args, close_distributor_args: &CloseDist
                     &keypair.pubkey(),
                     &keypair.pubkey(),
                     &args.mint,
-                    &spl_token::ID,
+                    &token_program,
                 ),
             );
         }
@@ -72,7 +75,8 @@ pub fn process_close_distributor(args: &Args, close_distributor_args: &CloseDist
                 token_vault: merkle_distributor_state.token_vault,
                 admin: keypair.pubkey(),
                 destination_token_account,
-                token_program: spl_token::ID,
+                mint: args.mint,
+                token_program,
             }
             .to_account_metas(None),
             data: merkle_distributor::instruction::CloseDistributor {}.data(),
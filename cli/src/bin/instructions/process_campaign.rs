@@ -0,0 +1,214 @@
+use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions},
+};
+use merkle_distributor::state::campaign::Campaign;
+use solana_program::program_pack::Pack;
+
+use crate::*;
+
+pub fn process_new_campaign(args: &Args, new_campaign_args: &NewCampaignArgs) {
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::finalized());
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+    let base = read_keypair_file(&new_campaign_args.base_path).expect("Failed reading base file");
+
+    let token_program = args.get_token_program_id();
+    let (campaign, _bump) = get_campaign_pda(&args.program_id, &base.pubkey(), &args.mint);
+
+    let mut ixs = vec![];
+    // check priority fee
+    if let Some(priority_fee) = args.priority_fee {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+
+    let token_vault =
+        get_associated_token_address_with_program_id(&campaign, &args.mint, &token_program);
+    if client.get_account_data(&token_vault).is_err() {
+        ixs.push(create_associated_token_account(
+            &keypair.pubkey(),
+            &campaign,
+            &args.mint,
+            &token_program,
+        ));
+    }
+    let clawback_receiver = get_associated_token_address_with_program_id(
+        &new_campaign_args.clawback_receiver_owner,
+        &args.mint,
+        &token_program,
+    );
+    if client.get_account_data(&clawback_receiver).is_err() {
+        ixs.push(create_associated_token_account(
+            &keypair.pubkey(),
+            &new_campaign_args.clawback_receiver_owner,
+            &args.mint,
+            &token_program,
+        ));
+    }
+
+    ixs.push(Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::NewCampaign {
+            campaign,
+            base: base.pubkey(),
+            clawback_receiver,
+            mint: args.mint,
+            token_vault,
+            admin: keypair.pubkey(),
+            system_program: solana_program::system_program::id(),
+            token_program,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::NewCampaign {
+            clawback_start_ts: new_campaign_args.clawback_start_ts,
+        }
+        .data(),
+    });
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&keypair.pubkey()),
+        &[&keypair, &base],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    match client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(signature) => {
+            println!("Successfully created campaign {campaign}! signature: {signature:#?}");
+        }
+        Err(err) => {
+            println!("Failed to create campaign: {:?}", err);
+        }
+    }
+}
+
+pub fn process_fund_campaign(args: &Args) {
+    let program = args.get_program_client();
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::finalized());
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let token_program = args.get_token_program_id();
+    let (campaign, _bump) = get_campaign_pda(&args.program_id, &args.base, &args.mint);
+    let campaign_state: Campaign = program.account(campaign).unwrap();
+    let token_vault_state: TokenAccount = program.account(campaign_state.token_vault).unwrap();
+
+    if token_vault_state.amount >= campaign_state.max_total_claim {
+        println!("already fund campaign {campaign}!");
+        return;
+    }
+    let amount = campaign_state.max_total_claim - token_vault_state.amount;
+
+    let mint_data = client.get_account_data(&args.mint).unwrap();
+    let epoch = client.get_epoch_info().unwrap().epoch;
+    let (decimals, transfer_fee_config) = if token_program == spl_token_2022::ID {
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data).unwrap();
+        (
+            mint.base.decimals,
+            mint.get_extension::<TransferFeeConfig>().ok().copied(),
+        )
+    } else {
+        let mint = spl_token::state::Mint::unpack(&mint_data).unwrap();
+        (mint.decimals, None)
+    };
+
+    let mut ixs = vec![];
+    // check priority fee
+    if let Some(priority_fee) = args.priority_fee {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    // gross up the amount so the vault still receives it in full after the transfer fee
+    let transfer_fee = match transfer_fee_config {
+        Some(config) => config.calculate_inverse_epoch_fee(epoch, amount).unwrap(),
+        None => 0,
+    };
+    ixs.push(
+        spl_token_2022::instruction::transfer_checked(
+            &token_program,
+            &get_associated_token_address_with_program_id(
+                &keypair.pubkey(),
+                &args.mint,
+                &token_program,
+            ),
+            &args.mint,
+            &campaign_state.token_vault,
+            &keypair.pubkey(),
+            &[],
+            amount.checked_add(transfer_fee).unwrap(),
+            decimals,
+        )
+        .unwrap(),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    match client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(signature) => {
+            println!("Successfully funded campaign {campaign}! signature: {signature:#?}");
+        }
+        Err(err) => {
+            println!("Failed to fund campaign: {:?}", err);
+        }
+    }
+}
+
+pub fn process_clawback_campaign(args: &Args) {
+    let program = args.get_program_client();
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let (campaign, _bump) = get_campaign_pda(&args.program_id, &args.base, &args.mint);
+    let campaign_state: Campaign = program.account(campaign).unwrap();
+    if campaign_state.clawed_back() {
+        println!("already clawback campaign {campaign}");
+        return;
+    }
+
+    let mut ixs = vec![];
+    // check priority fee
+    if let Some(priority_fee) = args.priority_fee {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+
+    ixs.push(Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::ClawbackCampaign {
+            campaign,
+            from: campaign_state.token_vault,
+            clawback_receiver: campaign_state.clawback_receiver,
+            mint: args.mint,
+            token_program: args.get_token_program_id(),
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::ClawbackCampaign {}.data(),
+    });
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    match client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(signature) => {
+            println!("Successfully clawback campaign {campaign}! signature: {signature:#?}");
+        }
+        Err(err) => {
+            println!("Failed to clawback campaign: {:?}", err);
+        }
+    }
+}
@@ -10,6 +10,12 @@ pub fn process_claim(args: &Args, claim_args: &ClaimArgs) {
     let merkle_tree = AirdropMerkleTree::new_from_file(&claim_args.merkle_tree_path)
         .expect("failed to load merkle tree from file");
 
+    // bitmap distributors are unlocked only, there is nothing left to claim afterwards
+    if merkle_tree.get_node(&claimant).leaf_index.is_some() {
+        process_new_claim_with_bitmap(args, claim_args);
+        return;
+    }
+
     let (distributor, bump) = get_merkle_distributor_pda(
         &args.program_id,
         &args.base,
@@ -44,18 +50,32 @@ pub fn process_claim(args: &Args, claim_args: &ClaimArgs) {
         ));
     }
 
-    let claimant_ata = get_associated_token_address(&claimant, &args.mint);
+    let token_program = args.get_token_program_id();
+    let claimant_ata =
+        get_associated_token_address_with_program_id(&claimant, &args.mint, &token_program);
 
+    // distributors of a campaign draw from the campaign vault
+    let campaign = args.get_campaign(&distributor);
+    let (claim_fee, fee_receiver) = args.get_claim_fee(&distributor, &claimant, &mut ixs);
     ixs.push(Instruction {
         program_id: args.program_id,
         accounts: merkle_distributor::accounts::ClaimLocked {
             distributor,
             claim_status: claim_status_pda,
-            from: get_associated_token_address(&distributor, &args.mint),
+            from: get_associated_token_address_with_program_id(
+                &campaign.unwrap_or(distributor),
+                &args.mint,
+                &token_program,
+            ),
             to: claimant_ata,
+            mint: args.mint,
             claimant,
-            token_program: token::ID,
+            token_program,
             operator: None,
+            campaign,
+            claim_fee,
+            fee_receiver,
+            system_program: Some(solana_program::system_program::ID),
         }
         .to_account_metas(None),
         data: merkle_distributor::instruction::ClaimLocked {}.data(),
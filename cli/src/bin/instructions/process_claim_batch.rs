@@ -0,0 +1,112 @@
+use merkle_distributor::instructions::BatchClaim;
+use solana_program::instruction::AccountMeta;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+use crate::*;
+
+pub fn process_claim_batch(args: &Args, claim_batch_args: &ClaimBatchArgs) {
+    assert_eq!(
+        claim_batch_args.distributor.len(),
+        claim_batch_args.merkle_tree_path.len(),
+        "each distributor needs a merkle tree path"
+    );
+
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+    let claimant = keypair.pubkey();
+    println!("Claiming tokens for user {}...", claimant);
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    let mut ixs = vec![];
+
+    // check priority fee
+    if let Some(priority_fee) = args.priority_fee {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+
+    let mut claims = vec![];
+    let mut remaining_accounts = vec![];
+    for (distributor, merkle_tree_path) in claim_batch_args
+        .distributor
+        .iter()
+        .zip(claim_batch_args.merkle_tree_path.iter())
+    {
+        let merkle_tree = AirdropMerkleTree::new_from_file(merkle_tree_path)
+            .expect("failed to load merkle tree from file");
+        let distributor_state = program.account::<MerkleDistributor>(*distributor).unwrap();
+        assert_eq!(
+            distributor_state.version, merkle_tree.airdrop_version,
+            "merkle tree is not the one of distributor {distributor}"
+        );
+
+        // each claim passes the token program of its mint
+        let mint_token_program = client
+            .get_account(&distributor_state.mint)
+            .expect("failed to fetch mint account")
+            .owner;
+
+        let node = merkle_tree.get_node(&claimant);
+        let (claim_status, _bump) = get_claim_status_pda(&args.program_id, &claimant, distributor);
+        let (claim_revocation, _bump) =
+            get_claim_revocation_pda(&args.program_id, &claimant, distributor);
+
+        let claimant_ata = get_associated_token_address_with_program_id(
+            &claimant,
+            &distributor_state.mint,
+            &mint_token_program,
+        );
+        if client.get_account_data(&claimant_ata).is_err() {
+            ixs.push(create_associated_token_account(
+                &claimant,
+                &claimant,
+                &distributor_state.mint,
+                &mint_token_program,
+            ));
+        }
+
+        // distributors of a campaign draw from the campaign vault
+        let vault_authority = args.get_campaign(distributor).unwrap_or(*distributor);
+
+        remaining_accounts.extend([
+            AccountMeta::new(*distributor, false),
+            AccountMeta::new(claim_status, false),
+            AccountMeta::new_readonly(claim_revocation, false),
+            AccountMeta::new(distributor_state.token_vault, false),
+            AccountMeta::new(claimant_ata, false),
+            AccountMeta::new_readonly(distributor_state.mint, false),
+            AccountMeta::new_readonly(vault_authority, false),
+            AccountMeta::new_readonly(mint_token_program, false),
+        ]);
+        claims.push(BatchClaim {
+            amount_unlocked: node.unlocked_amount(),
+            amount_locked: node.locked_amount(),
+            proof: node.proof.expect("proof not found"),
+        });
+    }
+
+    let mut accounts = merkle_distributor::accounts::NewClaimBatch {
+        claimant,
+        operator: None,
+        system_program: solana_program::system_program::ID,
+    }
+    .to_account_metas(None);
+    accounts.extend(remaining_accounts);
+
+    ixs.push(Instruction {
+        program_id: args.program_id,
+        accounts,
+        data: merkle_distributor::instruction::NewClaimBatch { claims }.data(),
+    });
+
+    let blockhash = client.get_latest_blockhash().unwrap();
+    let tx = Transaction::new_signed_with_payer(&ixs, Some(&claimant), &[&keypair], blockhash);
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!("successfully claimed in batch with signature {signature:#?}");
+}
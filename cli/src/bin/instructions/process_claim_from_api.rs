@@ -21,6 +21,8 @@ pub fn process_claim_from_api(args: &Args, claim_args: &ClaimFromApiArgs) {
     let distributor = Pubkey::from_str(&kv_proof.merkle_tree).unwrap();
 
     let (claim_status_pda, _bump) = get_claim_status_pda(&args.program_id, &claimant, &distributor);
+    let (claim_revocation, _bump) =
+        get_claim_revocation_pda(&args.program_id, &claimant, &distributor);
 
     let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
 
@@ -32,7 +34,9 @@ pub fn process_claim_from_api(args: &Args, claim_args: &ClaimFromApiArgs) {
         ));
     }
 
-    let claimant_ata = get_associated_token_address(&claimant, &args.mint);
+    let token_program = args.get_token_program_id();
+    let claimant_ata =
+        get_associated_token_address_with_program_id(&claimant, &args.mint, &token_program);
 
     if client.get_account_data(&claimant_ata).is_err() {
         ixs.push(
@@ -40,22 +44,34 @@ pub fn process_claim_from_api(args: &Args, claim_args: &ClaimFromApiArgs) {
                 &keypair.pubkey(),
                 &claimant_ata,
                 &args.mint,
-                &spl_token::ID,
+                &token_program,
             ),
         );
     }
 
+    // distributors of a campaign draw from the campaign vault
+    let campaign = args.get_campaign(&distributor);
+    let (claim_fee, fee_receiver) = args.get_claim_fee(&distributor, &claimant, &mut ixs);
     ixs.push(Instruction {
         program_id: args.program_id,
         accounts: merkle_distributor::accounts::NewClaim {
             distributor,
             claim_status: claim_status_pda,
-            from: get_associated_token_address(&distributor, &args.mint),
+            claim_revocation,
+            from: get_associated_token_address_with_program_id(
+                &campaign.unwrap_or(distributor),
+                &args.mint,
+                &token_program,
+            ),
             to: claimant_ata,
+            mint: args.mint,
             claimant,
-            token_program: token::ID,
+            token_program,
             system_program: solana_program::system_program::ID,
             operator: None,
+            campaign,
+            claim_fee,
+            fee_receiver,
         }
         .to_account_metas(None),
         data: merkle_distributor::instruction::NewClaim {
@@ -0,0 +1,95 @@
+use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+use crate::*;
+
+pub fn process_claim_locked_early(args: &Args, claim_locked_args: &ClaimLockedArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+    let claimant = keypair.pubkey();
+
+    let (distributor, _bump) = get_merkle_distributor_pda(
+        &args.program_id,
+        &args.base,
+        &args.mint,
+        claim_locked_args.airdrop_version,
+    );
+    let leaf_claimant = claim_locked_args.leaf_claimant.unwrap_or(claimant);
+    let (claim_status, _bump) =
+        get_claim_status_pda(&args.program_id, &leaf_claimant, &distributor);
+
+    let program = args.get_program_client();
+    let distributor_state = program.account::<MerkleDistributor>(distributor).unwrap();
+    if distributor_state.early_exit_penalty_bps == 0 {
+        panic!(
+            "airdrop version {} does not allow early exits",
+            claim_locked_args.airdrop_version
+        );
+    }
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let mut ixs = vec![];
+    // check priority fee
+    if let Some(priority_fee) = args.priority_fee {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+
+    let token_program = args.get_token_program_id();
+    let claimant_ata =
+        get_associated_token_address_with_program_id(&claimant, &args.mint, &token_program);
+    match client.get_account(&claimant_ata) {
+        Ok(_) => {}
+        Err(e) => {
+            if e.to_string().contains("AccountNotFound") {
+                ixs.push(create_associated_token_account(
+                    &claimant,
+                    &claimant,
+                    &args.mint,
+                    &token_program,
+                ));
+            } else {
+                panic!("error getting ATA: {e}")
+            }
+        }
+    }
+
+    // distributors of a campaign draw from the campaign vault
+    let campaign = args.get_campaign(&distributor);
+    ixs.push(Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::ClaimLockedEarly {
+            distributor,
+            claim_status,
+            from: get_associated_token_address_with_program_id(
+                &campaign.unwrap_or(distributor),
+                &args.mint,
+                &token_program,
+            ),
+            to: claimant_ata,
+            clawback_receiver: distributor_state.clawback_receiver,
+            mint: args.mint,
+            claimant,
+            operator: None,
+            token_program,
+            campaign,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::ClaimLockedEarly {}.data(),
+    });
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&claimant),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!(
+        "Successfully exited early from claim status {claim_status}! signature: {signature:#?}"
+    );
+}
@@ -0,0 +1,125 @@
+use merkle_distributor::state::merkle_distributor::ClaimType;
+use solana_program::instruction::AccountMeta;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+use crate::*;
+
+pub fn process_claim_with_hook(args: &Args, claim_args: &ClaimWithHookArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+    let claimant = keypair.pubkey();
+    println!("Claiming tokens for user {}...", claimant);
+
+    let merkle_tree = AirdropMerkleTree::new_from_file(&claim_args.merkle_tree_path)
+        .expect("failed to load merkle tree from file");
+
+    let (distributor, _bump) = get_merkle_distributor_pda(
+        &args.program_id,
+        &args.base,
+        &args.mint,
+        merkle_tree.airdrop_version,
+    );
+
+    let program = args.get_program_client();
+    let distributor_state = program.account::<MerkleDistributor>(distributor).unwrap();
+    if distributor_state.claim_type != u8::from(ClaimType::PermissionlessWithHook) {
+        panic!(
+            "airdrop version {} does not route claims to a hook",
+            merkle_tree.airdrop_version
+        );
+    }
+    let (claim_hook, _bump) = get_claim_hook_pda(&args.program_id, &distributor);
+    let hook_program = program
+        .account::<ClaimHook>(claim_hook)
+        .unwrap()
+        .hook_program;
+
+    // Get user's node in claim
+    let node = merkle_tree.get_node(&claimant);
+    if node.locked_amount() > 0 {
+        panic!("hook distributors only claim unlocked amounts");
+    }
+
+    let (claim_status, _bump) = get_claim_status_pda(&args.program_id, &claimant, &distributor);
+    let (claim_revocation, _bump) =
+        get_claim_revocation_pda(&args.program_id, &claimant, &distributor);
+
+    let token_program = args.get_token_program_id();
+    let (hook_authority, _bump) = get_claim_hook_authority_pda(&hook_program, &distributor);
+    let hook_token_account = claim_args.hook_token_account.unwrap_or_else(|| {
+        get_associated_token_address_with_program_id(&hook_authority, &args.mint, &token_program)
+    });
+
+    let hook_accounts: Vec<AccountMeta> = claim_args
+        .hook_account
+        .iter()
+        .map(|account| match account.strip_suffix(":w") {
+            Some(pubkey) => AccountMeta::new(Pubkey::from_str(pubkey).unwrap(), false),
+            None => AccountMeta::new_readonly(Pubkey::from_str(account).unwrap(), false),
+        })
+        .collect();
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let mut ixs = vec![];
+
+    // check priority fee
+    if let Some(priority_fee) = args.priority_fee {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+
+    if claim_args.hook_token_account.is_none()
+        && client.get_account_data(&hook_token_account).is_err()
+    {
+        ixs.push(create_associated_token_account(
+            &claimant,
+            &hook_authority,
+            &args.mint,
+            &token_program,
+        ));
+    }
+
+    // distributors of a campaign draw from the campaign vault
+    let campaign = args.get_campaign(&distributor);
+    let mut accounts = merkle_distributor::accounts::NewClaimWithHook {
+        distributor,
+        claim_status,
+        claim_revocation,
+        from: get_associated_token_address_with_program_id(
+            &campaign.unwrap_or(distributor),
+            &args.mint,
+            &token_program,
+        ),
+        claim_hook,
+        hook_token_account,
+        mint: args.mint,
+        claimant,
+        hook_program,
+        token_program,
+        system_program: solana_program::system_program::ID,
+        campaign,
+    }
+    .to_account_metas(None);
+    accounts.extend(hook_accounts);
+
+    ixs.push(Instruction {
+        program_id: args.program_id,
+        accounts,
+        data: merkle_distributor::instruction::NewClaimWithHook {
+            amount_unlocked: node.unlocked_amount(),
+            proof: node.proof.expect("proof not found"),
+        }
+        .data(),
+    });
+
+    let blockhash = client.get_latest_blockhash().unwrap();
+    let tx =
+        Transaction::new_signed_with_payer(&ixs, Some(&claimant.key()), &[&keypair], blockhash);
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!("successfully claimed tokens to hook {hook_program} with signature {signature:#?}");
+}
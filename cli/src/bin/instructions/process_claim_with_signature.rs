@@ -0,0 +1,212 @@
+use anchor_client::solana_sdk::{
+    compute_budget::ComputeBudgetInstruction, ed25519_program, signature::Signature,
+};
+use merkle_distributor::instructions::ClaimAuthorization;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+/// Claim authorization signed by a claimant, handed over to a relayer
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignedClaimAuthorization {
+    pub claimant: String,
+    pub distributor: String,
+    pub amount_unlocked: u64,
+    pub amount_locked: u64,
+    pub destination: String,
+    pub expiry: i64,
+    pub nonce: u64,
+    pub signature: String,
+}
+
+impl SignedClaimAuthorization {
+    fn authorization(&self) -> ClaimAuthorization {
+        ClaimAuthorization {
+            distributor: Pubkey::from_str(&self.distributor).unwrap(),
+            amount_unlocked: self.amount_unlocked,
+            amount_locked: self.amount_locked,
+            destination: Pubkey::from_str(&self.destination).unwrap(),
+            expiry: self.expiry,
+            nonce: self.nonce,
+        }
+    }
+}
+
+/// Builds an ed25519 program instruction verifying an existing signature
+pub fn new_ed25519_instruction(
+    pubkey: &Pubkey,
+    signature: &Signature,
+    message: &[u8],
+) -> Instruction {
+    const DATA_START: u16 = 16;
+    let public_key_offset = DATA_START;
+    let signature_offset = public_key_offset + 32;
+    let message_data_offset = signature_offset + 64;
+
+    let mut data = vec![1u8, 0];
+    for offset in [
+        signature_offset,
+        u16::MAX,
+        public_key_offset,
+        u16::MAX,
+        message_data_offset,
+        message.len() as u16,
+        u16::MAX,
+    ] {
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+    data.extend_from_slice(pubkey.as_ref());
+    data.extend_from_slice(signature.as_ref());
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+pub fn process_sign_claim(args: &Args, sign_claim_args: &SignClaimArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+    let claimant = keypair.pubkey();
+
+    let merkle_tree = AirdropMerkleTree::new_from_file(&sign_claim_args.merkle_tree_path)
+        .expect("failed to load merkle tree from file");
+
+    let (distributor, _bump) = get_merkle_distributor_pda(
+        &args.program_id,
+        &args.base,
+        &args.mint,
+        merkle_tree.airdrop_version,
+    );
+
+    let node = merkle_tree.get_node(&claimant);
+
+    let destination = sign_claim_args.destination.unwrap_or_else(|| {
+        get_associated_token_address_with_program_id(
+            &claimant,
+            &args.mint,
+            &args.get_token_program_id(),
+        )
+    });
+
+    let authorization = ClaimAuthorization {
+        distributor,
+        amount_unlocked: node.unlocked_amount(),
+        amount_locked: node.locked_amount(),
+        destination,
+        expiry: sign_claim_args.expiry,
+        nonce: sign_claim_args.nonce,
+    };
+    let signature = keypair.sign_message(&authorization.to_message());
+
+    let signed_authorization = SignedClaimAuthorization {
+        claimant: claimant.to_string(),
+        distributor: distributor.to_string(),
+        amount_unlocked: authorization.amount_unlocked,
+        amount_locked: authorization.amount_locked,
+        destination: destination.to_string(),
+        expiry: authorization.expiry,
+        nonce: authorization.nonce,
+        signature: signature.to_string(),
+    };
+
+    let serialized = serde_json::to_string_pretty(&signed_authorization).unwrap();
+    fs::write(&sign_claim_args.authorization_path, serialized).unwrap();
+    println!(
+        "signed claim authorization for user {} written to {:?}",
+        claimant, sign_claim_args.authorization_path
+    );
+}
+
+pub fn process_relay_claim(args: &Args, relay_claim_args: &RelayClaimArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+    let payer = keypair.pubkey();
+
+    let data = fs::read_to_string(&relay_claim_args.authorization_path).unwrap();
+    let signed_authorization: SignedClaimAuthorization = serde_json::from_str(&data).unwrap();
+    let claimant = Pubkey::from_str(&signed_authorization.claimant).unwrap();
+    let signature = Signature::from_str(&signed_authorization.signature).unwrap();
+    let authorization = signed_authorization.authorization();
+
+    let merkle_tree = AirdropMerkleTree::new_from_file(&relay_claim_args.merkle_tree_path)
+        .expect("failed to load merkle tree from file");
+    let node = merkle_tree.get_node(&claimant);
+
+    let distributor = authorization.distributor;
+    let (claim_status_pda, _bump) = get_claim_status_pda(&args.program_id, &claimant, &distributor);
+    let (claim_revocation, _bump) =
+        get_claim_revocation_pda(&args.program_id, &claimant, &distributor);
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let token_program = args.get_token_program_id();
+
+    let mut ixs = vec![];
+
+    // check priority fee
+    if let Some(priority_fee) = args.priority_fee {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+
+    let claimant_ata =
+        get_associated_token_address_with_program_id(&claimant, &args.mint, &token_program);
+    if authorization.destination == claimant_ata && client.get_account_data(&claimant_ata).is_err()
+    {
+        ixs.push(create_associated_token_account(
+            &payer,
+            &claimant,
+            &args.mint,
+            &token_program,
+        ));
+    }
+
+    // the program verifies the instruction right before the claim
+    ixs.push(new_ed25519_instruction(
+        &claimant,
+        &signature,
+        &authorization.to_message(),
+    ));
+
+    // distributors of a campaign draw from the campaign vault
+    let campaign = args.get_campaign(&distributor);
+    ixs.push(Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::NewClaimWithSignature {
+            distributor,
+            claim_status: claim_status_pda,
+            claim_revocation,
+            from: get_associated_token_address_with_program_id(
+                &campaign.unwrap_or(distributor),
+                &args.mint,
+                &token_program,
+            ),
+            to: authorization.destination,
+            mint: args.mint,
+            claimant,
+            payer,
+            operator: None,
+            instructions_sysvar: sysvar::instructions::ID,
+            token_program,
+            system_program: solana_program::system_program::ID,
+            campaign,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::NewClaimWithSignature {
+            authorization,
+            proof: node.proof.expect("proof not found"),
+        }
+        .data(),
+    });
+
+    let blockhash = client.get_latest_blockhash().unwrap();
+    let tx = Transaction::new_signed_with_payer(&ixs, Some(&payer), &[&keypair], blockhash);
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!("successfully relayed claim for user {claimant} with signature {signature:#?}");
+}
@@ -0,0 +1,184 @@
+use anchor_client::solana_sdk::{compute_budget::ComputeBudgetInstruction, signature::Signature};
+use merkle_distributor::instructions::ClaimVoucher;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+/// Claim voucher signed by the operator, handed over to the claimant
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignedClaimVoucher {
+    pub operator: String,
+    pub distributor: String,
+    pub claimant: String,
+    pub expiry: i64,
+    pub max_amount: Option<u64>,
+    pub signature: String,
+}
+
+impl SignedClaimVoucher {
+    fn voucher(&self) -> ClaimVoucher {
+        ClaimVoucher {
+            distributor: Pubkey::from_str(&self.distributor).unwrap(),
+            claimant: Pubkey::from_str(&self.claimant).unwrap(),
+            expiry: self.expiry,
+            max_amount: self.max_amount,
+        }
+    }
+}
+
+pub fn process_sign_voucher(args: &Args, sign_voucher_args: &SignVoucherArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+    let operator = keypair.pubkey();
+
+    let (distributor, _bump) = get_merkle_distributor_pda(
+        &args.program_id,
+        &args.base,
+        &args.mint,
+        sign_voucher_args.airdrop_version,
+    );
+
+    let voucher = ClaimVoucher {
+        distributor,
+        claimant: sign_voucher_args.claimant,
+        expiry: sign_voucher_args.expiry,
+        max_amount: sign_voucher_args.max_amount,
+    };
+    let signature = keypair.sign_message(&voucher.to_message());
+
+    let signed_voucher = SignedClaimVoucher {
+        operator: operator.to_string(),
+        distributor: distributor.to_string(),
+        claimant: voucher.claimant.to_string(),
+        expiry: voucher.expiry,
+        max_amount: voucher.max_amount,
+        signature: signature.to_string(),
+    };
+
+    let serialized = serde_json::to_string_pretty(&signed_voucher).unwrap();
+    fs::write(&sign_voucher_args.voucher_path, serialized).unwrap();
+    println!(
+        "signed claim voucher for user {} written to {:?}",
+        voucher.claimant, sign_voucher_args.voucher_path
+    );
+}
+
+pub fn process_claim_with_voucher(args: &Args, claim_with_voucher_args: &ClaimWithVoucherArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+    let claimant = keypair.pubkey();
+
+    let data = fs::read_to_string(&claim_with_voucher_args.voucher_path).unwrap();
+    let signed_voucher: SignedClaimVoucher = serde_json::from_str(&data).unwrap();
+    let operator = Pubkey::from_str(&signed_voucher.operator).unwrap();
+    let signature = Signature::from_str(&signed_voucher.signature).unwrap();
+    let voucher = signed_voucher.voucher();
+    if voucher.claimant != claimant {
+        panic!("voucher is for {}, not {}", voucher.claimant, claimant);
+    }
+
+    let distributor = voucher.distributor;
+    let (claim_status_pda, _bump) = get_claim_status_pda(&args.program_id, &claimant, &distributor);
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let token_program = args.get_token_program_id();
+
+    let mut ixs = vec![];
+
+    // check priority fee
+    if let Some(priority_fee) = args.priority_fee {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+
+    let claimant_ata =
+        get_associated_token_address_with_program_id(&claimant, &args.mint, &token_program);
+    if client.get_account_data(&claimant_ata).is_err() {
+        ixs.push(create_associated_token_account(
+            &claimant,
+            &claimant,
+            &args.mint,
+            &token_program,
+        ));
+    }
+
+    // the program verifies the instruction right before the claim
+    ixs.push(new_ed25519_instruction(
+        &operator,
+        &signature,
+        &voucher.to_message(),
+    ));
+
+    // distributors of a campaign draw from the campaign vault
+    let campaign = args.get_campaign(&distributor);
+    let from = get_associated_token_address_with_program_id(
+        &campaign.unwrap_or(distributor),
+        &args.mint,
+        &token_program,
+    );
+    match client.get_account(&claim_status_pda) {
+        Ok(_) => {
+            ixs.push(Instruction {
+                program_id: args.program_id,
+                accounts: merkle_distributor::accounts::ClaimLockedWithVoucher {
+                    distributor,
+                    claim_status: claim_status_pda,
+                    from,
+                    to: claimant_ata,
+                    mint: args.mint,
+                    claimant,
+                    instructions_sysvar: sysvar::instructions::ID,
+                    token_program,
+                    campaign,
+                }
+                .to_account_metas(None),
+                data: merkle_distributor::instruction::ClaimLockedWithVoucher { voucher }.data(),
+            });
+        }
+        Err(e) => {
+            if !e.to_string().contains("AccountNotFound") {
+                panic!("error getting PDA: {e}")
+            }
+            let merkle_tree =
+                AirdropMerkleTree::new_from_file(&claim_with_voucher_args.merkle_tree_path)
+                    .expect("failed to load merkle tree from file");
+            let node = merkle_tree.get_node(&claimant);
+            let (claim_revocation, _bump) =
+                get_claim_revocation_pda(&args.program_id, &claimant, &distributor);
+
+            ixs.push(Instruction {
+                program_id: args.program_id,
+                accounts: merkle_distributor::accounts::NewClaimWithVoucher {
+                    distributor,
+                    claim_status: claim_status_pda,
+                    claim_revocation,
+                    from,
+                    to: claimant_ata,
+                    mint: args.mint,
+                    claimant,
+                    instructions_sysvar: sysvar::instructions::ID,
+                    token_program,
+                    system_program: solana_program::system_program::ID,
+                    campaign,
+                }
+                .to_account_metas(None),
+                data: merkle_distributor::instruction::NewClaimWithVoucher {
+                    amount_unlocked: node.unlocked_amount(),
+                    amount_locked: node.locked_amount(),
+                    voucher,
+                    proof: node.proof.expect("proof not found"),
+                }
+                .data(),
+            });
+        }
+    }
+
+    let blockhash = client.get_latest_blockhash().unwrap();
+    let tx = Transaction::new_signed_with_payer(&ixs, Some(&claimant), &[&keypair], blockhash);
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!("successfully claimed with voucher for user {claimant} with signature {signature:#?}");
+}
@@ -28,6 +28,8 @@ fn clawback(args: &Args, clawback_args: &ClawbackArgs) -> Result<()> {
     let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
     let program = args.get_program_client();
 
+    let token_program = args.get_token_program_id();
+
     let mut is_error = false;
 
     let from_version = clawback_args.from_version;
@@ -63,7 +65,8 @@ fn clawback(args: &Args, clawback_args: &ClawbackArgs) -> Result<()> {
             accounts: merkle_distributor::accounts::Clawback {
                 distributor,
                 from: distributor_state.token_vault,
-                token_program: spl_token::ID,
+                mint: args.mint,
+                token_program,
                 clawback_receiver: distributor_state.clawback_receiver,
             }
             .to_account_metas(None),
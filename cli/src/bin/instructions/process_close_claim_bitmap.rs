@@ -0,0 +1,81 @@
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+use crate::*;
+
+/// Closes the claim bitmaps of clawed back or closed bitmap distributors, refunding their rent
+pub fn process_close_claim_bitmap(args: &Args, close_claim_bitmap_args: &CloseClaimBitmapArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    let from_version = close_claim_bitmap_args.from_version;
+    let to_version = close_claim_bitmap_args.to_version;
+    for version in from_version..=to_version {
+        let (distributor, _bump) =
+            get_merkle_distributor_pda(&args.program_id, &args.base, &args.mint, version);
+
+        // a closed distributor can't be claimed from anymore
+        let campaign = match program.account::<MerkleDistributor>(distributor) {
+            Ok(distributor_state) => {
+                if !args.is_clawed_back(&distributor_state) {
+                    println!("not clawed back skip airdrop version {}", version);
+                    continue;
+                }
+                args.get_campaign(&distributor)
+            }
+            Err(_) => None,
+        };
+
+        // chunks are created in order, stop at the first missing one
+        for chunk_index in 0.. {
+            let (claim_bitmap, _bump) =
+                get_claim_bitmap_pda(&args.program_id, &distributor, chunk_index);
+            if client.get_account_data(&claim_bitmap).is_err() {
+                break;
+            }
+
+            let mut ixs = vec![];
+
+            // check priority fee
+            if let Some(priority_fee) = args.priority_fee {
+                ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+                    priority_fee,
+                ));
+            }
+
+            ixs.push(Instruction {
+                program_id: args.program_id,
+                accounts: merkle_distributor::accounts::CloseClaimBitmap {
+                    distributor,
+                    claim_bitmap,
+                    admin: keypair.pubkey(),
+                    campaign,
+                }
+                .to_account_metas(None),
+                data: merkle_distributor::instruction::CloseClaimBitmap {}.data(),
+            });
+
+            let tx = Transaction::new_signed_with_payer(
+                &ixs,
+                Some(&keypair.pubkey()),
+                &[&keypair],
+                client.get_latest_blockhash().unwrap(),
+            );
+
+            match client.send_and_confirm_transaction_with_spinner(&tx) {
+                Ok(signature) => {
+                    println!(
+                        "Successfully closed claim bitmap {} airdrop version {} ! signature: {signature:#?}",
+                        chunk_index, version
+                    );
+                }
+                Err(err) => {
+                    println!("airdrop version {} {}", version, err);
+                    break;
+                }
+            }
+        }
+    }
+}
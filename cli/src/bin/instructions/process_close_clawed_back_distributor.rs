@@ -0,0 +1,76 @@
+use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+use crate::*;
+
+pub fn process_close_clawed_back_distributor(
+    args: &Args,
+    close_args: &CloseClawedBackDistributorArgs,
+) {
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::finalized());
+    let program = args.get_program_client();
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let token_program = args.get_token_program_id();
+
+    for i in close_args.from_version..=close_args.to_version {
+        let (distributor, _bump) =
+            get_merkle_distributor_pda(&args.program_id, &args.base, &args.mint, i);
+
+        // verify distributor is existed and clawed back
+        let merkle_distributor_state = match program.account::<MerkleDistributor>(distributor) {
+            Ok(merkle_distributor_state) => merkle_distributor_state,
+            Err(_) => {
+                println!("skip version {}", i);
+                continue;
+            }
+        };
+        if !args.is_clawed_back(&merkle_distributor_state) {
+            println!("not clawed back skip version {}", i);
+            continue;
+        }
+
+        let mut ixs = vec![];
+        // check priority fee
+        if let Some(priority_fee) = args.priority_fee {
+            ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+                priority_fee,
+            ));
+        }
+
+        ixs.push(Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::CloseClawedBackDistributor {
+                distributor,
+                token_vault: merkle_distributor_state.token_vault,
+                clawback_receiver: merkle_distributor_state.clawback_receiver,
+                mint: merkle_distributor_state.mint,
+                admin: keypair.pubkey(),
+                token_program,
+                campaign: args.get_campaign(&distributor),
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::CloseClawedBackDistributor {}.data(),
+        });
+
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&keypair.pubkey()),
+            &[&keypair],
+            blockhash,
+        );
+        match client.send_transaction(&tx) {
+            Ok(_) => {
+                println!(
+                    "done close clawed back merkle distributor version {} {:?}",
+                    i,
+                    tx.get_signature(),
+                );
+            }
+            Err(e) => {
+                println!("Failed to close MerkleDistributor version {}: {:?}", i, e);
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use merkle_distributor::state::claim_status::ClaimStatus;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+use crate::*;
+
+/// Closes the claim statuses of the keypair once fully withdrawn from clawed back distributors,
+/// or left on closed distributors
+pub fn process_close_withdrawn_claim_status(
+    args: &Args,
+    close_args: &CloseWithdrawnClaimStatusArgs,
+) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+    let claimant = keypair.pubkey();
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    for i in close_args.from_version..=close_args.to_version {
+        let (distributor, _bump) =
+            get_merkle_distributor_pda(&args.program_id, &args.base, &args.mint, i);
+        let (claim_status, _bump) = get_claim_status_pda(&args.program_id, &claimant, &distributor);
+
+        // a closed distributor no longer holds anything to withdraw
+        let (distributor_closed, campaign) = match program.account::<MerkleDistributor>(distributor)
+        {
+            Ok(distributor_state) => {
+                if !args.is_clawed_back(&distributor_state) {
+                    println!("not clawed back skip airdrop version {}", i);
+                    continue;
+                }
+                (false, args.get_campaign(&distributor))
+            }
+            Err(_) => (true, None),
+        };
+        let claim_status_state = match program.account::<ClaimStatus>(claim_status) {
+            Ok(claim_status_state) => claim_status_state,
+            Err(_) => {
+                println!("no claim status skip airdrop version {}", i);
+                continue;
+            }
+        };
+        if !distributor_closed
+            && claim_status_state.locked_amount_withdrawn < claim_status_state.locked_amount
+        {
+            println!(
+                "locked amount not fully withdrawn {}/{} skip airdrop version {}",
+                claim_status_state.locked_amount_withdrawn, claim_status_state.locked_amount, i
+            );
+            continue;
+        }
+
+        loop {
+            let mut ixs = vec![];
+            // check priority fee
+            if let Some(priority_fee) = args.priority_fee {
+                ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+                    priority_fee,
+                ));
+            }
+            ixs.push(Instruction {
+                program_id: args.program_id,
+                accounts: merkle_distributor::accounts::CloseWithdrawnClaimStatus {
+                    distributor,
+                    claim_status,
+                    claimant,
+                    campaign,
+                }
+                .to_account_metas(None),
+                data: merkle_distributor::instruction::CloseWithdrawnClaimStatus {}.data(),
+            });
+
+            let tx = Transaction::new_signed_with_payer(
+                &ixs,
+                Some(&claimant),
+                &[&keypair],
+                client.get_latest_blockhash().unwrap(),
+            );
+
+            match client.send_and_confirm_transaction_with_spinner(&tx) {
+                Ok(signature) => {
+                    println!(
+                        "Successfully closed claim status {} airdrop version {} ! signature: {signature:#?}",
+                        claim_status, i
+                    );
+                    break;
+                }
+                Err(err) => {
+                    println!("airdrop version {} {}", i, err);
+                }
+            }
+        }
+    }
+}
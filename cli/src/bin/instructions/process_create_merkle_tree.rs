@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use jito_merkle_tree::tree_node::TreeNode;
+
 use crate::*;
 
 pub fn process_create_merkle_tree(merkle_tree_args: &CreateMerkleTreeArgs) {
@@ -38,9 +40,7 @@ pub fn process_create_merkle_tree(merkle_tree_args: &CreateMerkleTreeArgs) {
         csv_entries = csv_entries[last_index..csv_entries.len()].to_vec();
 
         // use index as version
-        let merkle_tree =
-            AirdropMerkleTree::new_from_entries(sub_tree, index, merkle_tree_args.decimals)
-                .unwrap();
+        let merkle_tree = new_merkle_tree(merkle_tree_args, sub_tree, index);
 
         let base_path_clone = base_path.clone();
         let path = base_path_clone
@@ -59,12 +59,13 @@ pub fn process_create_merkle_tree(merkle_tree_args: &CreateMerkleTreeArgs) {
                 pubkey: x,
                 amount: merkle_tree_args.amount.to_string(),
                 locked_amount: "0".to_string(),
+                cliff_ts: None,
+                period: None,
+                num_periods: None,
             })
             .collect::<Vec<CsvEntry>>();
 
-        let merkle_tree =
-            AirdropMerkleTree::new_from_entries(test_list, index, merkle_tree_args.decimals as u32)
-                .unwrap();
+        let merkle_tree = new_merkle_tree(merkle_tree_args, test_list, index);
         let base_path_clone = base_path.clone();
         let path = base_path_clone
             .as_path()
@@ -73,3 +74,22 @@ pub fn process_create_merkle_tree(merkle_tree_args: &CreateMerkleTreeArgs) {
         merkle_tree.write_to_file(&path);
     }
 }
+
+fn new_merkle_tree(
+    merkle_tree_args: &CreateMerkleTreeArgs,
+    entries: Vec<CsvEntry>,
+    version: u64,
+) -> AirdropMerkleTree {
+    let tree_nodes = entries
+        .into_iter()
+        .map(|x| TreeNode::from_csv(x, merkle_tree_args.decimals))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    AirdropMerkleTree::new_with_hash_mode(
+        tree_nodes,
+        version,
+        merkle_tree_args.with_leaf_index,
+        merkle_tree_args.hash_mode,
+    )
+    .unwrap()
+}
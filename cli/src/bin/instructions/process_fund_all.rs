@@ -1,7 +1,12 @@
 use std::{thread, time::Duration};
 
 use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions},
+};
 use anyhow::Error;
+use solana_program::program_pack::Pack;
 
 use crate::*;
 pub fn process_fund_all(args: &Args, fund_all_args: &FundAllArgs) {
@@ -35,7 +40,22 @@ fn fund_all(args: &Args, fund_all_args: &FundAllArgs) -> Result<()> {
         .collect();
     paths.sort_by_key(|dir| dir.path());
 
-    let source_vault = get_associated_token_address(&keypair.pubkey(), &args.mint);
+    let token_program = args.get_token_program_id();
+    let source_vault =
+        get_associated_token_address_with_program_id(&keypair.pubkey(), &args.mint, &token_program);
+
+    let mint_data = client.get_account_data(&args.mint)?;
+    let epoch = client.get_epoch_info()?.epoch;
+    let (decimals, transfer_fee_config) = if token_program == spl_token_2022::ID {
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+        (
+            mint.base.decimals,
+            mint.get_extension::<TransferFeeConfig>().ok().copied(),
+        )
+    } else {
+        let mint = spl_token::state::Mint::unpack(&mint_data)?;
+        (mint.decimals, None)
+    };
 
     // println!("source vault {}", source_vault);
 
@@ -53,7 +73,11 @@ fn fund_all(args: &Args, fund_all_args: &FundAllArgs) -> Result<()> {
 
         let distributor_state: MerkleDistributor = program.account(distributor_pubkey)?;
 
-        let token_vault = get_associated_token_address(&distributor_pubkey, &args.mint);
+        let token_vault = get_associated_token_address_with_program_id(
+            &distributor_pubkey,
+            &args.mint,
+            &token_program,
+        );
 
         let token_vault_state: TokenAccount = program.account(token_vault)?;
         if token_vault_state.amount >= distributor_state.max_total_claim {
@@ -71,14 +95,26 @@ fn fund_all(args: &Args, fund_all_args: &FundAllArgs) -> Result<()> {
                 priority_fee,
             ));
         }
+        // gross up the amount so the vault still receives max_total_claim after the transfer fee
+        let transfer_fee = match transfer_fee_config {
+            Some(config) => config
+                .calculate_inverse_epoch_fee(epoch, distributor_state.max_total_claim)
+                .unwrap(),
+            None => 0,
+        };
         ixs.push(
-            spl_token::instruction::transfer(
-                &spl_token::id(),
+            spl_token_2022::instruction::transfer_checked(
+                &token_program,
                 &source_vault,
+                &args.mint,
                 &token_vault,
                 &keypair.pubkey(),
                 &[],
-                distributor_state.max_total_claim,
+                distributor_state
+                    .max_total_claim
+                    .checked_add(transfer_fee)
+                    .unwrap(),
+                decimals,
             )
             .unwrap(),
         );
@@ -1,5 +1,6 @@
 use std::{collections::HashMap, fs::File, io::Write};
 
+use jito_merkle_tree::tree_node::VestingSchedule;
 use serde::{Deserialize, Serialize};
 
 use crate::*;
@@ -9,6 +10,12 @@ pub struct KvProof {
     pub merkle_tree: String,
     pub amount: u64,
     pub locked_amount: u64,
+    /// Leaf index, only set for trees claimed through a claim bitmap
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leaf_index: Option<u64>,
+    /// Vesting schedule, only set when the leaf has its own
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vesting_schedule: Option<VestingSchedule>,
     /// Claimant's proof of inclusion in the Merkle Tree
     pub proof: Vec<[u8; 32]>,
 }
@@ -49,6 +56,8 @@ pub fn process_generate_kv_proof(args: &Args, generate_kv_proof_args: &GenerateK
                     merkle_tree: distributor_pubkey.to_string(),
                     amount: node.amount,
                     locked_amount: node.locked_amount,
+                    leaf_index: node.leaf_index,
+                    vesting_schedule: node.vesting_schedule,
                     proof: node.proof.clone().unwrap(),
                 },
             );
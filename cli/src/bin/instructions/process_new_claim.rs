@@ -1,3 +1,4 @@
+use merkle_distributor::state::claim_status::VestingSchedule;
 use solana_sdk::compute_budget::ComputeBudgetInstruction;
 
 use crate::*;
@@ -22,10 +23,14 @@ pub fn process_new_claim(args: &Args, claim_args: &ClaimArgs) {
     let node = merkle_tree.get_node(&claimant);
 
     let (claim_status_pda, _bump) = get_claim_status_pda(&args.program_id, &claimant, &distributor);
+    let (claim_revocation, _bump) =
+        get_claim_revocation_pda(&args.program_id, &claimant, &distributor);
 
     let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
 
-    let claimant_ata = get_associated_token_address(&claimant, &args.mint);
+    let token_program = args.get_token_program_id();
+    let claimant_ata =
+        get_associated_token_address_with_program_id(&claimant, &args.mint, &token_program);
 
     let mut ixs = vec![];
 
@@ -46,7 +51,7 @@ pub fn process_new_claim(args: &Args, claim_args: &ClaimArgs) {
                     &claimant,
                     &claimant,
                     &args.mint,
-                    &token::ID,
+                    &token_program,
                 ));
             } else {
                 panic!("Error fetching PDA: {e}")
@@ -54,26 +59,72 @@ pub fn process_new_claim(args: &Args, claim_args: &ClaimArgs) {
         }
     }
 
-    ixs.push(Instruction {
-        program_id: args.program_id,
-        accounts: merkle_distributor::accounts::NewClaim {
-            distributor,
-            claim_status: claim_status_pda,
-            from: get_associated_token_address(&distributor, &args.mint),
-            to: claimant_ata,
-            claimant,
-            token_program: token::ID,
-            system_program: solana_program::system_program::ID,
-            operator: None,
-        }
-        .to_account_metas(None),
-        data: merkle_distributor::instruction::NewClaim {
-            amount_unlocked: node.unlocked_amount(),
-            amount_locked: node.locked_amount(),
-            proof: node.proof.expect("proof not found"),
-        }
-        .data(),
-    });
+    // distributors of a campaign draw from the campaign vault
+    let campaign = args.get_campaign(&distributor);
+    let (claim_fee, fee_receiver) = args.get_claim_fee(&distributor, &claimant, &mut ixs);
+    let from = get_associated_token_address_with_program_id(
+        &campaign.unwrap_or(distributor),
+        &args.mint,
+        &token_program,
+    );
+
+    // leaves with their own vesting schedule commit to it in the hash
+    let ix = match node.vesting_schedule {
+        Some(vesting_schedule) => Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::NewClaimWithSchedule {
+                distributor,
+                claim_status: claim_status_pda,
+                claim_revocation,
+                from,
+                to: claimant_ata,
+                mint: args.mint,
+                claimant,
+                token_program,
+                system_program: solana_program::system_program::ID,
+                operator: None,
+                campaign,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::NewClaimWithSchedule {
+                amount_unlocked: node.unlocked_amount(),
+                amount_locked: node.locked_amount(),
+                vesting_schedule: VestingSchedule {
+                    cliff_ts: vesting_schedule.cliff_ts,
+                    period: vesting_schedule.period,
+                    num_periods: vesting_schedule.num_periods,
+                },
+                proof: node.proof.expect("proof not found"),
+            }
+            .data(),
+        },
+        None => Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::NewClaim {
+                distributor,
+                claim_status: claim_status_pda,
+                claim_revocation,
+                from,
+                to: claimant_ata,
+                mint: args.mint,
+                claimant,
+                token_program,
+                system_program: solana_program::system_program::ID,
+                operator: None,
+                campaign,
+                claim_fee,
+                fee_receiver,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::NewClaim {
+                amount_unlocked: node.unlocked_amount(),
+                amount_locked: node.locked_amount(),
+                proof: node.proof.expect("proof not found"),
+            }
+            .data(),
+        },
+    };
+    ixs.push(ix);
 
     let blockhash = client.get_latest_blockhash().unwrap();
     let tx =
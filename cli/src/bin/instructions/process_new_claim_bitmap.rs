@@ -0,0 +1,75 @@
+use merkle_distributor::state::claim_bitmap::LEAVES_PER_CLAIM_BITMAP;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+use crate::*;
+
+pub fn process_new_claim_bitmap(args: &Args, new_claim_bitmap_args: &NewClaimBitmapArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    let from_version = new_claim_bitmap_args.from_version;
+    let to_version = new_claim_bitmap_args.to_version;
+    for version in from_version..=to_version {
+        let (distributor, _bump) =
+            get_merkle_distributor_pda(&args.program_id, &args.base, &args.mint, version);
+
+        let distributor_state = program.account::<MerkleDistributor>(distributor).unwrap();
+        let num_chunks = (distributor_state.max_num_nodes + LEAVES_PER_CLAIM_BITMAP - 1)
+            / LEAVES_PER_CLAIM_BITMAP;
+
+        for chunk_index in 0..num_chunks {
+            let (claim_bitmap, _bump) =
+                get_claim_bitmap_pda(&args.program_id, &distributor, chunk_index);
+            if client.get_account_data(&claim_bitmap).is_ok() {
+                println!(
+                    "already created claim bitmap {} airdrop version {}",
+                    chunk_index, version
+                );
+                continue;
+            }
+
+            let mut ixs = vec![];
+
+            // check priority fee
+            if let Some(priority_fee) = args.priority_fee {
+                ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+                    priority_fee,
+                ));
+            }
+
+            ixs.push(Instruction {
+                program_id: args.program_id,
+                accounts: merkle_distributor::accounts::NewClaimBitmap {
+                    distributor,
+                    claim_bitmap,
+                    admin: keypair.pubkey(),
+                    system_program: solana_program::system_program::ID,
+                }
+                .to_account_metas(None),
+                data: merkle_distributor::instruction::NewClaimBitmap { chunk_index }.data(),
+            });
+
+            let tx = Transaction::new_signed_with_payer(
+                &ixs,
+                Some(&keypair.pubkey()),
+                &[&keypair],
+                client.get_latest_blockhash().unwrap(),
+            );
+
+            match client.send_and_confirm_transaction_with_spinner(&tx) {
+                Ok(signature) => {
+                    println!(
+                        "Successfully created claim bitmap {} airdrop version {} ! signature: {signature:#?}",
+                        chunk_index, version
+                    );
+                }
+                Err(err) => {
+                    println!("airdrop version {} {}", version, err);
+                }
+            }
+        }
+    }
+}
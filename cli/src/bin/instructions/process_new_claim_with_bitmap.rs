@@ -0,0 +1,91 @@
+use merkle_distributor::state::claim_bitmap::ClaimBitmap;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+use crate::*;
+
+pub fn process_new_claim_with_bitmap(args: &Args, claim_args: &ClaimArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+    let claimant = keypair.pubkey();
+    println!("Claiming tokens for user {}...", claimant);
+
+    let merkle_tree = AirdropMerkleTree::new_from_file(&claim_args.merkle_tree_path)
+        .expect("failed to load merkle tree from file");
+
+    let (distributor, _bump) = get_merkle_distributor_pda(
+        &args.program_id,
+        &args.base,
+        &args.mint,
+        merkle_tree.airdrop_version,
+    );
+
+    // Get user's node in claim
+    let node = merkle_tree.get_node(&claimant);
+    let leaf_index = node.leaf_index.expect("merkle tree has no leaf index");
+
+    let (claim_bitmap, _bump) = get_claim_bitmap_pda(
+        &args.program_id,
+        &distributor,
+        ClaimBitmap::chunk_index_of(leaf_index),
+    );
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let token_program = args.get_token_program_id();
+    let claimant_ata =
+        get_associated_token_address_with_program_id(&claimant, &args.mint, &token_program);
+
+    let mut ixs = vec![];
+
+    // check priority fee
+    if let Some(priority_fee) = args.priority_fee {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+
+    if client.get_account_data(&claimant_ata).is_err() {
+        ixs.push(create_associated_token_account(
+            &claimant,
+            &claimant,
+            &args.mint,
+            &token_program,
+        ));
+    }
+
+    // distributors of a campaign draw from the campaign vault
+    let campaign = args.get_campaign(&distributor);
+    ixs.push(Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::NewClaimWithBitmap {
+            distributor,
+            claim_bitmap,
+            from: get_associated_token_address_with_program_id(
+                &campaign.unwrap_or(distributor),
+                &args.mint,
+                &token_program,
+            ),
+            to: claimant_ata,
+            mint: args.mint,
+            claimant,
+            token_program,
+            campaign,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::NewClaimWithBitmap {
+            leaf_index,
+            amount_unlocked: node.unlocked_amount(),
+            proof: node.proof.expect("proof not found"),
+        }
+        .data(),
+    });
+
+    let blockhash = client.get_latest_blockhash().unwrap();
+    let tx =
+        Transaction::new_signed_with_payer(&ixs, Some(&claimant.key()), &[&keypair], blockhash);
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!("successfully claimed tokens with signature {signature:#?}");
+}
@@ -2,7 +2,7 @@ use std::{thread, time::Duration};
 
 use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
 use anyhow::{Error, Result};
-use merkle_distributor::instructions::NewDistributorParams;
+use merkle_distributor::instructions::{ClaimFeeParams, LockBonusTierParams, NewDistributorParams};
 
 use crate::*;
 
@@ -40,6 +40,8 @@ fn create_new_distributor(args: &Args, new_distributor_args: &NewDistributorArgs
         .collect();
     paths.sort_by_key(|dir| dir.path());
 
+    let token_program = args.get_token_program_id();
+
     let mut is_error = false;
     for file in paths {
         let single_tree_path = file.path();
@@ -85,6 +87,14 @@ fn create_new_distributor(args: &Args, new_distributor_args: &NewDistributorArgs
         }
 
         let mut ixs = vec![];
+        let claim_fee = new_distributor_args
+            .fee_receiver
+            .map(|_| get_claim_fee_pda(&args.program_id, &distributor_pubkey).0);
+        let lock_bonus_schedule = (!new_distributor_args.lock_bonus_tier.is_empty())
+            .then(|| get_lock_bonus_schedule_pda(&args.program_id, &distributor_pubkey).0);
+        let claim_hook = new_distributor_args
+            .hook_program
+            .map(|_| get_claim_hook_pda(&args.program_id, &distributor_pubkey).0);
 
         // check priority fee
         if let Some(priority_fee) = args.priority_fee {
@@ -93,70 +103,81 @@ fn create_new_distributor(args: &Args, new_distributor_args: &NewDistributorArgs
             ));
         }
 
-        let token_vault = spl_associated_token_account::get_associated_token_address(
-            &distributor_pubkey,
-            &args.mint,
-        );
-        if client.get_account_data(&token_vault).is_err() {
-            ixs.push(
-                spl_associated_token_account::instruction::create_associated_token_account(
-                    &keypair.pubkey(),
-                    &distributor_pubkey,
-                    &args.mint,
-                    &spl_token::ID,
-                ),
+        if new_distributor_args.campaign {
+            let (campaign, _bump) = get_campaign_pda(&args.program_id, &base.pubkey(), &args.mint);
+            ixs.push(Instruction {
+                program_id: args.program_id,
+                accounts: merkle_distributor::accounts::NewCampaignDistributor {
+                    campaign,
+                    distributor: distributor_pubkey,
+                    base: base.pubkey(),
+                    mint: args.mint,
+                    admin: keypair.pubkey(),
+                    system_program: solana_program::system_program::id(),
+                    claim_fee,
+                    claim_hook,
+                }
+                .to_account_metas(None),
+                data: merkle_distributor::instruction::NewCampaignDistributor {
+                    params: new_distributor_params(&merkle_tree, new_distributor_args, total_bonus),
+                }
+                .data(),
+            });
+        } else {
+            let token_vault = get_associated_token_address_with_program_id(
+                &distributor_pubkey,
+                &args.mint,
+                &token_program,
             );
-        }
-        let clawback_receiver = spl_associated_token_account::get_associated_token_address(
-            &new_distributor_args.clawback_receiver_owner,
-            &args.mint,
-        );
-
-        if client.get_account_data(&clawback_receiver).is_err() {
-            ixs.push(
-                spl_associated_token_account::instruction::create_associated_token_account(
-                    &keypair.pubkey(),
-                    &new_distributor_args.clawback_receiver_owner,
-                    &args.mint,
-                    &spl_token::ID,
-                ),
+            if client.get_account_data(&token_vault).is_err() {
+                ixs.push(
+                    spl_associated_token_account::instruction::create_associated_token_account(
+                        &keypair.pubkey(),
+                        &distributor_pubkey,
+                        &args.mint,
+                        &token_program,
+                    ),
+                );
+            }
+            let clawback_receiver = get_associated_token_address_with_program_id(
+                &new_distributor_args.clawback_receiver_owner,
+                &args.mint,
+                &token_program,
             );
-        }
 
-        ixs.push(Instruction {
-            program_id: args.program_id,
-            accounts: merkle_distributor::accounts::NewDistributor {
-                base: base.pubkey(),
-                clawback_receiver,
-                mint: args.mint,
-                token_vault,
-                distributor: distributor_pubkey,
-                system_program: solana_program::system_program::id(),
-                token_program: token::ID,
-                admin: keypair.pubkey(),
-            }
-            .to_account_metas(None),
-            data: merkle_distributor::instruction::NewDistributor {
-                params: NewDistributorParams {
-                    version: merkle_tree.airdrop_version,
-                    root: merkle_tree.merkle_root,
-                    total_claim: merkle_tree.get_max_total_claim(),
-                    max_num_nodes: merkle_tree.max_num_nodes,
-                    start_vesting_ts: new_distributor_args.start_vesting_ts,
-                    end_vesting_ts: new_distributor_args.end_vesting_ts,
-                    clawback_start_ts: new_distributor_args.clawback_start_ts,
-                    activation_point: new_distributor_args.activation_point,
-                    activation_type: new_distributor_args.activation_type,
-                    closable: new_distributor_args.closable,
-                    total_bonus,
-                    locker: new_distributor_args.locker,
-                    operator: new_distributor_args.operator,
-                    claim_type: new_distributor_args.claim_type,
-                    bonus_vesting_duration: new_distributor_args.bonus_vesting_duration,
-                },
+            if client.get_account_data(&clawback_receiver).is_err() {
+                ixs.push(
+                    spl_associated_token_account::instruction::create_associated_token_account(
+                        &keypair.pubkey(),
+                        &new_distributor_args.clawback_receiver_owner,
+                        &args.mint,
+                        &token_program,
+                    ),
+                );
             }
-            .data(),
-        });
+
+            ixs.push(Instruction {
+                program_id: args.program_id,
+                accounts: merkle_distributor::accounts::NewDistributor {
+                    base: base.pubkey(),
+                    clawback_receiver,
+                    mint: args.mint,
+                    token_vault,
+                    distributor: distributor_pubkey,
+                    system_program: solana_program::system_program::id(),
+                    token_program,
+                    admin: keypair.pubkey(),
+                    claim_fee,
+                    lock_bonus_schedule,
+                    claim_hook,
+                }
+                .to_account_metas(None),
+                data: merkle_distributor::instruction::NewDistributor {
+                    params: new_distributor_params(&merkle_tree, new_distributor_args, total_bonus),
+                }
+                .data(),
+            });
+        }
 
         let blockhash = client.get_latest_blockhash().unwrap();
         let tx = Transaction::new_signed_with_payer(
@@ -231,3 +252,48 @@ fn create_new_distributor(args: &Args, new_distributor_args: &NewDistributorArgs
     }
     Ok(())
 }
+
+fn new_distributor_params(
+    merkle_tree: &AirdropMerkleTree,
+    new_distributor_args: &NewDistributorArgs,
+    total_bonus: u64,
+) -> NewDistributorParams {
+    NewDistributorParams {
+        version: merkle_tree.airdrop_version,
+        root: merkle_tree.merkle_root,
+        total_claim: merkle_tree.get_max_total_claim(),
+        max_num_nodes: merkle_tree.max_num_nodes,
+        start_vesting_ts: new_distributor_args.start_vesting_ts,
+        end_vesting_ts: new_distributor_args.end_vesting_ts,
+        clawback_start_ts: new_distributor_args.clawback_start_ts,
+        activation_point: new_distributor_args.activation_point,
+        activation_type: new_distributor_args.activation_type,
+        closable: new_distributor_args.closable,
+        revocable: new_distributor_args.revocable,
+        early_exit_penalty_bps: new_distributor_args.early_exit_penalty_bps,
+        early_exit_penalty_to_clawback: new_distributor_args.early_exit_penalty_to_clawback,
+        total_bonus,
+        locker: new_distributor_args.locker,
+        operator: new_distributor_args.operator,
+        claim_type: new_distributor_args.claim_type,
+        bonus_vesting_duration: new_distributor_args.bonus_vesting_duration,
+        bonus_curve_type: new_distributor_args.bonus_curve as u8,
+        bonus_num_steps: new_distributor_args.bonus_num_steps,
+        hook_program: new_distributor_args.hook_program,
+        claim_fee: new_distributor_args
+            .fee_receiver
+            .map(|fee_receiver| ClaimFeeParams {
+                fee_receiver,
+                lamports: new_distributor_args.fee_lamports,
+                bps: new_distributor_args.fee_bps,
+            }),
+        lock_bonus_schedule: new_distributor_args
+            .lock_bonus_tier
+            .iter()
+            .map(|tier| LockBonusTierParams {
+                min_lock_duration: tier.min_lock_duration,
+                bonus_bps: tier.bonus_bps,
+            })
+            .collect(),
+    }
+}
@@ -0,0 +1,109 @@
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+use crate::*;
+
+pub fn process_push_claims(args: &Args, push_claims_args: &PushClaimsArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let merkle_tree = AirdropMerkleTree::new_from_file(&push_claims_args.merkle_tree_path)
+        .expect("failed to load merkle tree from file");
+    // push distributors only claim unlocked amounts, a locked amount could never be withdrawn
+    if let Some(node) = merkle_tree.find_locked_node() {
+        panic!(
+            "claimant {} has locked amount {}, push distributors only claim unlocked amounts",
+            node.claimant,
+            node.locked_amount()
+        );
+    }
+
+    let (distributor, _bump) = get_merkle_distributor_pda(
+        &args.program_id,
+        &args.base,
+        &args.mint,
+        merkle_tree.airdrop_version,
+    );
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let token_program = args.get_token_program_id();
+
+    // distributors of a campaign draw from the campaign vault
+    let campaign = args.get_campaign(&distributor);
+    let from = get_associated_token_address_with_program_id(
+        &campaign.unwrap_or(distributor),
+        &args.mint,
+        &token_program,
+    );
+
+    let mut num_pushed = 0;
+    for node in merkle_tree.tree_nodes.iter() {
+        let claimant = node.claimant;
+        let (claim_status, _bump) = get_claim_status_pda(&args.program_id, &claimant, &distributor);
+        if client.get_account_data(&claim_status).is_ok() {
+            continue;
+        }
+        let (claim_revocation, _bump) =
+            get_claim_revocation_pda(&args.program_id, &claimant, &distributor);
+        let claimant_ata =
+            get_associated_token_address_with_program_id(&claimant, &args.mint, &token_program);
+
+        let mut ixs = vec![];
+        // check priority fee
+        if let Some(priority_fee) = args.priority_fee {
+            ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+                priority_fee,
+            ));
+        }
+
+        ixs.push(create_associated_token_account_idempotent(
+            &keypair.pubkey(),
+            &claimant,
+            &args.mint,
+            &token_program,
+        ));
+        ixs.push(Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::PushClaim {
+                distributor,
+                claim_status,
+                claim_revocation,
+                from,
+                to: claimant_ata,
+                mint: args.mint,
+                claimant,
+                payer: keypair.pubkey(),
+                token_program,
+                system_program: solana_program::system_program::ID,
+                campaign,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::PushClaim {
+                amount_unlocked: node.unlocked_amount(),
+                proof: node.proof.clone().expect("proof not found"),
+            }
+            .data(),
+        });
+
+        let tx = Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&keypair.pubkey()),
+            &[&keypair],
+            client.get_latest_blockhash().unwrap(),
+        );
+
+        match client.send_and_confirm_transaction_with_spinner(&tx) {
+            Ok(signature) => {
+                num_pushed += 1;
+                println!("pushed claim of {claimant}, signature: {signature:#?}");
+            }
+            Err(err) => {
+                println!("Failed to push claim of {claimant}: {:?}", err);
+            }
+        }
+    }
+    println!(
+        "pushed {} claims of airdrop version {}",
+        num_pushed, merkle_tree.airdrop_version
+    );
+}
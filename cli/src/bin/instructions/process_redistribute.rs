@@ -0,0 +1,99 @@
+use anchor_client::solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use anchor_lang::Space;
+use anchor_spl::token_2022::spl_token_2022::{extension::StateWithExtensions, state::Mint};
+use jito_merkle_tree::redistribution::{allocations_to_csv_entries, pro_rata_allocations};
+use merkle_distributor::state::{claim_status::ClaimStatus, merkle_distributor::ClaimType};
+
+use crate::*;
+
+pub fn process_redistribute(args: &Args, redistribute_args: &RedistributeArgs) {
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    let mut unclaimed_amount = 0u64;
+    let mut claimed_amounts = vec![];
+    for version in redistribute_args.from_version..=redistribute_args.to_version {
+        let (distributor, _bump) =
+            get_merkle_distributor_pda(&args.program_id, &args.base, &args.mint, version);
+        let distributor_state = program.account::<MerkleDistributor>(distributor).unwrap();
+        if !args.is_clawed_back(&distributor_state) {
+            panic!("airdrop version {version} is not clawed back");
+        }
+        if distributor_state.claim_type == u8::from(ClaimType::PermissionlessWithBitmap) {
+            // claims of bitmap distributors have no claim status to snapshot
+            println!("skip airdrop version {version}, claimed through a claim bitmap");
+            continue;
+        }
+        unclaimed_amount = unclaimed_amount
+            .checked_add(distributor_state.max_total_claim - distributor_state.total_amount_claimed)
+            .unwrap();
+
+        let claim_status_accounts: Vec<(Pubkey, ClaimStatus)> = program
+            .accounts(vec![
+                RpcFilterType::DataSize((ClaimStatus::INIT_SPACE) as u64 + 8),
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                    8 + 32,
+                    distributor.to_bytes().to_vec(),
+                )),
+            ])
+            .unwrap();
+        println!(
+            "airdrop version {version}: {} claim statuses",
+            claim_status_accounts.len()
+        );
+
+        // weight each wallet by the amount it actually received, owners of transferred
+        // claim statuses included
+        for (_, claim_status) in claim_status_accounts.iter() {
+            let claimed_amount = claim_status
+                .unlocked_amount
+                .checked_add(claim_status.bonus_amount)
+                .unwrap()
+                .checked_add(claim_status.locked_amount_withdrawn)
+                .unwrap();
+            claimed_amounts.push((claim_status.claimant, claimed_amount));
+        }
+    }
+
+    let total_amount = redistribute_args.total_amount.unwrap_or(unclaimed_amount);
+    let allocations = pro_rata_allocations(&claimed_amounts, total_amount);
+    println!(
+        "redistribute {total_amount} (unclaimed {unclaimed_amount}) to {} wallets",
+        allocations.len()
+    );
+
+    let mint_data = client.get_account_data(&args.mint).unwrap();
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint_data)
+        .unwrap()
+        .base
+        .decimals as u32;
+    let mut csv_entries = allocations_to_csv_entries(&allocations, decimals);
+
+    let mut wtr = Writer::from_path(&redistribute_args.csv_path).unwrap();
+    for entry in csv_entries.iter() {
+        wtr.serialize(entry).unwrap();
+    }
+    wtr.flush().unwrap();
+
+    // create merkle tree folder if not existed
+    fs::create_dir_all(&redistribute_args.merkle_tree_path).unwrap();
+    let max_nodes_per_tree = redistribute_args.max_nodes_per_tree as usize;
+    let mut version = redistribute_args.airdrop_version;
+    while !csv_entries.is_empty() {
+        let last_index = max_nodes_per_tree.min(csv_entries.len());
+        let sub_tree = csv_entries.drain(0..last_index).collect();
+
+        let merkle_tree = AirdropMerkleTree::new_from_entries(sub_tree, version, decimals).unwrap();
+        let path = redistribute_args
+            .merkle_tree_path
+            .as_path()
+            .join(format!("tree_{}.json", version));
+        merkle_tree.write_to_file(&path);
+        version += 1;
+    }
+    println!(
+        "created merkle trees of airdrop versions {} to {}",
+        redistribute_args.airdrop_version,
+        version.saturating_sub(1)
+    );
+}
@@ -0,0 +1,78 @@
+use merkle_distributor::state::claim_status::VestingSchedule;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+use crate::*;
+
+pub fn process_revoke_claim(args: &Args, revoke_claim_args: &RevokeClaimArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+    let claimant = revoke_claim_args.claimant;
+
+    let merkle_tree = AirdropMerkleTree::new_from_file(&revoke_claim_args.merkle_tree_path)
+        .expect("failed to load merkle tree from file");
+
+    let (distributor, _bump) = get_merkle_distributor_pda(
+        &args.program_id,
+        &args.base,
+        &args.mint,
+        merkle_tree.airdrop_version,
+    );
+
+    let node = merkle_tree.get_node(&claimant);
+    if node.leaf_index.is_some() {
+        panic!("leaves of bitmap distributors can't be revoked");
+    }
+
+    let (claim_status, _bump) = get_claim_status_pda(&args.program_id, &claimant, &distributor);
+    let (claim_revocation, _bump) =
+        get_claim_revocation_pda(&args.program_id, &claimant, &distributor);
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let mut ixs = vec![];
+
+    // check priority fee
+    if let Some(priority_fee) = args.priority_fee {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+
+    let vesting_schedule = node
+        .vesting_schedule
+        .map(|vesting_schedule| VestingSchedule {
+            cliff_ts: vesting_schedule.cliff_ts,
+            period: vesting_schedule.period,
+            num_periods: vesting_schedule.num_periods,
+        })
+        .unwrap_or_default();
+
+    ixs.push(Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::RevokeClaim {
+            distributor,
+            claim_revocation,
+            claim_status,
+            claimant,
+            admin: keypair.pubkey(),
+            system_program: solana_program::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::RevokeClaim {
+            amount_unlocked: node.unlocked_amount(),
+            amount_locked: node.locked_amount(),
+            vesting_schedule,
+            proof: node.proof.expect("proof not found"),
+        }
+        .data(),
+    });
+
+    let blockhash = client.get_latest_blockhash().unwrap();
+    let tx =
+        Transaction::new_signed_with_payer(&ixs, Some(&keypair.pubkey()), &[&keypair], blockhash);
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!("successfully revoked claim of {claimant} with signature {signature:#?}");
+}
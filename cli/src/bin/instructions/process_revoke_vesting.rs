@@ -0,0 +1,70 @@
+use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
+use merkle_distributor::state::claim_status::ClaimStatus;
+
+use crate::*;
+
+pub fn process_revoke_vesting(args: &Args, revoke_vesting_args: &RevokeVestingArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    let token_program = args.get_token_program_id();
+
+    let (distributor, _bump) = get_merkle_distributor_pda(
+        &args.program_id,
+        &args.base,
+        &args.mint,
+        revoke_vesting_args.airdrop_version,
+    );
+    let distributor_state = program.account::<MerkleDistributor>(distributor).unwrap();
+    if !distributor_state.revocable() {
+        panic!(
+            "airdrop version {} is not revocable",
+            revoke_vesting_args.airdrop_version
+        );
+    }
+
+    let claimant = revoke_vesting_args.claimant;
+    let (claim_status, _bump) = get_claim_status_pda(&args.program_id, &claimant, &distributor);
+    let claim_status_state = program.account::<ClaimStatus>(claim_status).unwrap();
+    if claim_status_state.revoked() {
+        panic!("vesting of {} is already revoked", claimant);
+    }
+
+    let mut ixs = vec![];
+    // check priority fee
+    if let Some(priority_fee) = args.priority_fee {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+
+    ixs.push(Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::RevokeVesting {
+            distributor,
+            claim_status,
+            from: distributor_state.token_vault,
+            clawback_receiver: distributor_state.clawback_receiver,
+            mint: args.mint,
+            admin: keypair.pubkey(),
+            token_program,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::RevokeVesting {}.data(),
+    });
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!("Successfully revoked vesting of {claimant}! signature: {signature:#?}");
+}
@@ -17,9 +17,10 @@ pub fn process_set_clawback_receiver(
         .collect();
     paths.sort_by_key(|dir| dir.path());
 
-    let new_clawback_account = spl_associated_token_account::get_associated_token_address(
+    let new_clawback_account = get_associated_token_address_with_program_id(
         &set_clawback_receiver_args.receiver,
         &args.mint,
+        &args.get_token_program_id(),
     );
 
     for file in paths {
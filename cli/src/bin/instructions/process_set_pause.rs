@@ -0,0 +1,67 @@
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+use crate::*;
+pub fn process_set_pause(args: &Args, set_pause_args: &SetPauseArgs, paused: bool) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    let from_version = set_pause_args.from_version;
+    let to_version = set_pause_args.to_version;
+    for i in from_version..=to_version {
+        let (distributor, _bump) =
+            get_merkle_distributor_pda(&args.program_id, &args.base, &args.mint, i);
+
+        loop {
+            let distributor_state = program.account::<MerkleDistributor>(distributor).unwrap();
+            if distributor_state.paused() == paused {
+                println!("already the same skip airdrop version {}", i);
+                break;
+            }
+            let mut ixs = vec![];
+            // check priority fee
+            if let Some(priority_fee) = args.priority_fee {
+                ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+                    priority_fee,
+                ));
+            }
+            let accounts = merkle_distributor::accounts::SetPause {
+                distributor,
+                admin: keypair.pubkey(),
+            }
+            .to_account_metas(None);
+            let data = if paused {
+                merkle_distributor::instruction::Pause {}.data()
+            } else {
+                merkle_distributor::instruction::Unpause {}.data()
+            };
+            ixs.push(Instruction {
+                program_id: args.program_id,
+                accounts,
+                data,
+            });
+
+            let tx = Transaction::new_signed_with_payer(
+                &ixs,
+                Some(&keypair.pubkey()),
+                &[&keypair],
+                client.get_latest_blockhash().unwrap(),
+            );
+
+            match client.send_transaction(&tx) {
+                Ok(signature) => {
+                    println!(
+                        "Successfully set paused {} airdrop version {} ! signature: {signature:#?}",
+                        paused, i
+                    );
+                    break;
+                }
+                Err(err) => {
+                    println!("airdrop version {} {}", i, err);
+                }
+            }
+        }
+    }
+}
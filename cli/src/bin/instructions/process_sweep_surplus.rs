@@ -0,0 +1,69 @@
+use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+use crate::*;
+
+pub fn process_sweep_surplus(args: &Args, sweep_surplus_args: &SweepSurplusArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    let token_program = args.get_token_program_id();
+
+    for version in sweep_surplus_args.from_version..=sweep_surplus_args.to_version {
+        let (distributor, _bump) =
+            get_merkle_distributor_pda(&args.program_id, &args.base, &args.mint, version);
+        let distributor_state = program.account::<MerkleDistributor>(distributor).unwrap();
+        let vault: TokenAccount = program.account(distributor_state.token_vault).unwrap();
+
+        let owed_amount =
+            distributor_state.max_total_claim - distributor_state.total_amount_claimed;
+        if vault.amount <= owed_amount {
+            println!("no surplus skip airdrop version {}", version);
+            continue;
+        }
+
+        let mut ixs = vec![];
+        // check priority fee
+        if let Some(priority_fee) = args.priority_fee {
+            ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+                priority_fee,
+            ));
+        }
+
+        ixs.push(Instruction {
+            program_id: args.program_id,
+            accounts: merkle_distributor::accounts::SweepSurplus {
+                distributor,
+                from: distributor_state.token_vault,
+                clawback_receiver: distributor_state.clawback_receiver,
+                mint: args.mint,
+                admin: keypair.pubkey(),
+                token_program,
+            }
+            .to_account_metas(None),
+            data: merkle_distributor::instruction::SweepSurplus {}.data(),
+        });
+
+        let tx = Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&keypair.pubkey()),
+            &[&keypair],
+            client.get_latest_blockhash().unwrap(),
+        );
+
+        match client.send_and_confirm_transaction_with_spinner(&tx) {
+            Ok(signature) => {
+                println!(
+                    "Successfully swept surplus {} airdrop version {} ! signature: {signature:#?}",
+                    vault.amount - owed_amount,
+                    version
+                );
+            }
+            Err(err) => {
+                println!("airdrop version {} {}", version, err);
+            }
+        }
+    }
+}
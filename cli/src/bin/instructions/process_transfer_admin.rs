@@ -0,0 +1,215 @@
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+use crate::*;
+
+/// Sends the instruction built by `build_ix` to every distributor version in the range,
+/// skipping the versions `build_ix` returns None for
+fn process_handover(
+    args: &Args,
+    from_version: u64,
+    to_version: u64,
+    action: &str,
+    build_ix: impl Fn(&Keypair, Pubkey, &MerkleDistributor) -> Option<Instruction>,
+) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+    let program = args.get_program_client();
+
+    for i in from_version..=to_version {
+        let (distributor, _bump) =
+            get_merkle_distributor_pda(&args.program_id, &args.base, &args.mint, i);
+
+        loop {
+            let distributor_state = program.account::<MerkleDistributor>(distributor).unwrap();
+            let ix = match build_ix(&keypair, distributor, &distributor_state) {
+                Some(ix) => ix,
+                None => {
+                    println!("nothing to {} skip airdrop version {}", action, i);
+                    break;
+                }
+            };
+            let mut ixs = vec![];
+            // check priority fee
+            if let Some(priority_fee) = args.priority_fee {
+                ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+                    priority_fee,
+                ));
+            }
+            ixs.push(ix);
+
+            let tx = Transaction::new_signed_with_payer(
+                &ixs,
+                Some(&keypair.pubkey()),
+                &[&keypair],
+                client.get_latest_blockhash().unwrap(),
+            );
+
+            match client.send_transaction(&tx) {
+                Ok(signature) => {
+                    println!(
+                        "Successfully {} airdrop version {} ! signature: {signature:#?}",
+                        action, i
+                    );
+                    break;
+                }
+                Err(err) => {
+                    println!("airdrop version {} {}", i, err);
+                }
+            }
+        }
+    }
+}
+
+pub fn process_propose_admin(args: &Args, propose_admin_args: &SetAdminArgs) {
+    let new_admin = propose_admin_args.new_admin;
+    process_handover(
+        args,
+        propose_admin_args.from_version,
+        propose_admin_args.to_version,
+        "propose admin",
+        |keypair, distributor, distributor_state| {
+            if distributor_state.admin == new_admin || distributor_state.pending_admin == new_admin
+            {
+                return None;
+            }
+            Some(Instruction {
+                program_id: args.program_id,
+                accounts: merkle_distributor::accounts::ProposeAdmin {
+                    distributor,
+                    admin: keypair.pubkey(),
+                    new_admin,
+                }
+                .to_account_metas(None),
+                data: merkle_distributor::instruction::ProposeAdmin {}.data(),
+            })
+        },
+    );
+}
+
+/// Signed by the pending admin
+pub fn process_accept_admin(args: &Args, accept_admin_args: &PendingHandoverArgs) {
+    process_handover(
+        args,
+        accept_admin_args.from_version,
+        accept_admin_args.to_version,
+        "accept admin",
+        |keypair, distributor, distributor_state| {
+            if distributor_state.pending_admin != keypair.pubkey() {
+                return None;
+            }
+            Some(Instruction {
+                program_id: args.program_id,
+                accounts: merkle_distributor::accounts::AcceptAdmin {
+                    distributor,
+                    pending_admin: keypair.pubkey(),
+                }
+                .to_account_metas(None),
+                data: merkle_distributor::instruction::AcceptAdmin {}.data(),
+            })
+        },
+    );
+}
+
+pub fn process_cancel_pending_admin(args: &Args, cancel_args: &PendingHandoverArgs) {
+    process_handover(
+        args,
+        cancel_args.from_version,
+        cancel_args.to_version,
+        "cancel pending admin",
+        |keypair, distributor, distributor_state| {
+            if distributor_state.pending_admin == Pubkey::default() {
+                return None;
+            }
+            Some(Instruction {
+                program_id: args.program_id,
+                accounts: merkle_distributor::accounts::CancelPendingAdmin {
+                    distributor,
+                    admin: keypair.pubkey(),
+                }
+                .to_account_metas(None),
+                data: merkle_distributor::instruction::CancelPendingAdmin {}.data(),
+            })
+        },
+    );
+}
+
+pub fn process_propose_clawback_receiver(args: &Args, propose_args: &ProposeClawbackReceiverArgs) {
+    let new_clawback_account = get_associated_token_address_with_program_id(
+        &propose_args.receiver,
+        &args.mint,
+        &args.get_token_program_id(),
+    );
+    process_handover(
+        args,
+        propose_args.from_version,
+        propose_args.to_version,
+        "propose clawback receiver",
+        |keypair, distributor, distributor_state| {
+            if distributor_state.clawback_receiver == new_clawback_account
+                || distributor_state.pending_clawback_receiver == new_clawback_account
+            {
+                return None;
+            }
+            Some(Instruction {
+                program_id: args.program_id,
+                accounts: merkle_distributor::accounts::ProposeClawbackReceiver {
+                    distributor,
+                    new_clawback_account,
+                    admin: keypair.pubkey(),
+                }
+                .to_account_metas(None),
+                data: merkle_distributor::instruction::ProposeClawbackReceiver {}.data(),
+            })
+        },
+    );
+}
+
+/// Signed by the owner of the pending clawback receiver
+pub fn process_accept_clawback_receiver(args: &Args, accept_args: &PendingHandoverArgs) {
+    process_handover(
+        args,
+        accept_args.from_version,
+        accept_args.to_version,
+        "accept clawback receiver",
+        |keypair, distributor, distributor_state| {
+            if distributor_state.pending_clawback_receiver == Pubkey::default() {
+                return None;
+            }
+            Some(Instruction {
+                program_id: args.program_id,
+                accounts: merkle_distributor::accounts::AcceptClawbackReceiver {
+                    distributor,
+                    new_clawback_account: distributor_state.pending_clawback_receiver,
+                    owner: keypair.pubkey(),
+                }
+                .to_account_metas(None),
+                data: merkle_distributor::instruction::AcceptClawbackReceiver {}.data(),
+            })
+        },
+    );
+}
+
+pub fn process_cancel_pending_clawback_receiver(args: &Args, cancel_args: &PendingHandoverArgs) {
+    process_handover(
+        args,
+        cancel_args.from_version,
+        cancel_args.to_version,
+        "cancel pending clawback receiver",
+        |keypair, distributor, distributor_state| {
+            if distributor_state.pending_clawback_receiver == Pubkey::default() {
+                return None;
+            }
+            Some(Instruction {
+                program_id: args.program_id,
+                accounts: merkle_distributor::accounts::CancelPendingClawbackReceiver {
+                    distributor,
+                    admin: keypair.pubkey(),
+                }
+                .to_account_metas(None),
+                data: merkle_distributor::instruction::CancelPendingClawbackReceiver {}.data(),
+            })
+        },
+    );
+}
@@ -0,0 +1,143 @@
+use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+use crate::*;
+
+pub fn process_transfer_claim_status(
+    args: &Args,
+    transfer_claim_status_args: &TransferClaimStatusArgs,
+) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+    let claimant = keypair.pubkey();
+
+    let (distributor, _bump) = get_merkle_distributor_pda(
+        &args.program_id,
+        &args.base,
+        &args.mint,
+        transfer_claim_status_args.airdrop_version,
+    );
+    let leaf_claimant = transfer_claim_status_args.leaf_claimant.unwrap_or(claimant);
+    let (claim_status, _bump) =
+        get_claim_status_pda(&args.program_id, &leaf_claimant, &distributor);
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let mut ixs = vec![];
+    // check priority fee
+    if let Some(priority_fee) = args.priority_fee {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+
+    ixs.push(Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::TransferClaimStatus {
+            distributor,
+            claim_status,
+            claimant,
+            new_claimant: transfer_claim_status_args.new_claimant,
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::TransferClaimStatus {}.data(),
+    });
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&claimant),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!(
+        "Successfully transferred claim status {claim_status} to {}! signature: {signature:#?}",
+        transfer_claim_status_args.new_claimant
+    );
+}
+
+pub fn process_claim_locked(args: &Args, claim_locked_args: &ClaimLockedArgs) {
+    let keypair = read_keypair_file(&args.keypair_path.clone().unwrap())
+        .expect("Failed reading keypair file");
+    let claimant = keypair.pubkey();
+
+    let (distributor, _bump) = get_merkle_distributor_pda(
+        &args.program_id,
+        &args.base,
+        &args.mint,
+        claim_locked_args.airdrop_version,
+    );
+    let leaf_claimant = claim_locked_args.leaf_claimant.unwrap_or(claimant);
+    let (claim_status, _bump) =
+        get_claim_status_pda(&args.program_id, &leaf_claimant, &distributor);
+
+    let client = RpcClient::new_with_commitment(&args.rpc_url, CommitmentConfig::confirmed());
+
+    let mut ixs = vec![];
+    // check priority fee
+    if let Some(priority_fee) = args.priority_fee {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+
+    let token_program = args.get_token_program_id();
+    let claimant_ata =
+        get_associated_token_address_with_program_id(&claimant, &args.mint, &token_program);
+    match client.get_account(&claimant_ata) {
+        Ok(_) => {}
+        Err(e) => {
+            if e.to_string().contains("AccountNotFound") {
+                ixs.push(create_associated_token_account(
+                    &claimant,
+                    &claimant,
+                    &args.mint,
+                    &token_program,
+                ));
+            } else {
+                panic!("error getting ATA: {e}")
+            }
+        }
+    }
+
+    // distributors of a campaign draw from the campaign vault
+    let campaign = args.get_campaign(&distributor);
+    let (claim_fee, fee_receiver) = args.get_claim_fee(&distributor, &claimant, &mut ixs);
+    ixs.push(Instruction {
+        program_id: args.program_id,
+        accounts: merkle_distributor::accounts::ClaimLocked {
+            distributor,
+            claim_status,
+            from: get_associated_token_address_with_program_id(
+                &campaign.unwrap_or(distributor),
+                &args.mint,
+                &token_program,
+            ),
+            to: claimant_ata,
+            mint: args.mint,
+            claimant,
+            token_program,
+            operator: None,
+            campaign,
+            claim_fee,
+            fee_receiver,
+            system_program: Some(solana_program::system_program::ID),
+        }
+        .to_account_metas(None),
+        data: merkle_distributor::instruction::ClaimLocked {}.data(),
+    });
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&claimant),
+        &[&keypair],
+        client.get_latest_blockhash().unwrap(),
+    );
+
+    let signature = client
+        .send_and_confirm_transaction_with_spinner(&tx)
+        .unwrap();
+    println!("Successfully claimed from claim status {claim_status}! signature: {signature:#?}");
+}
@@ -9,6 +9,7 @@ pub fn process_verify(args: &Args, verfify_args: &VerifyArgs) {
 
     // TODO fix code
     let program = args.get_program_client();
+    let token_program = args.get_token_program_id();
 
     for file in paths {
         let single_tree_path = file.path();
@@ -40,7 +41,11 @@ pub fn process_verify(args: &Args, verfify_args: &VerifyArgs) {
         );
 
         if !verfify_args.skip_verify_amount {
-            let token_vault = get_associated_token_address(&distributor_pubkey, &args.mint);
+            let token_vault = get_associated_token_address_with_program_id(
+                &distributor_pubkey,
+                &args.mint,
+                &token_program,
+            );
             let token_vault_account: TokenAccount = program.account(token_vault).unwrap();
             assert_eq!(
                 token_vault_account.amount,
@@ -60,6 +65,15 @@ pub fn process_verify(args: &Args, verfify_args: &VerifyArgs) {
         );
 
         assert_eq!(merke_tree_state.closable(), verfify_args.closable);
+        assert_eq!(merke_tree_state.revocable(), verfify_args.revocable);
+        assert_eq!(
+            merke_tree_state.early_exit_penalty_bps,
+            verfify_args.early_exit_penalty_bps
+        );
+        assert_eq!(
+            merke_tree_state.early_exit_penalty_to_clawback(),
+            verfify_args.early_exit_penalty_to_clawback
+        );
 
         assert_eq!(merke_tree_state.admin, verfify_args.admin);
         assert_eq!(
@@ -77,8 +91,11 @@ pub fn process_verify(args: &Args, verfify_args: &VerifyArgs) {
 
         assert_eq!(merke_tree_state.locker, verfify_args.locker);
 
-        let clawback_receiver =
-            get_associated_token_address(&verfify_args.clawback_receiver_owner, &args.mint);
+        let clawback_receiver = get_associated_token_address_with_program_id(
+            &verfify_args.clawback_receiver_owner,
+            &args.mint,
+            &token_program,
+        );
         assert_eq!(merke_tree_state.clawback_receiver, clawback_receiver);
     }
 }
@@ -7,15 +7,15 @@ use std::{
 };
 
 use indexmap::IndexMap;
-use jito_merkle_verify::verify;
+use jito_merkle_verify::{verify_with_hash_mode, HashMode};
 use serde::{Deserialize, Serialize};
-use solana_program::{hash::hashv, pubkey::Pubkey};
+use solana_program::pubkey::Pubkey;
 
 use crate::{
     csv_entry::CsvEntry,
     error::MerkleTreeError::{self, MerkleValidationError},
     merkle_tree::MerkleTree,
-    tree_node::TreeNode,
+    tree_node::{TreeNode, VestingSchedule},
     utils::{get_proof, get_total_locked_amount, get_total_unlocked_amount},
 };
 
@@ -28,15 +28,16 @@ pub struct UserProof {
     pub amount: u64,
     /// locked amount
     pub locked_amount: u64,
+    /// leaf index, only set for trees claimed through a claim bitmap
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leaf_index: Option<u64>,
+    /// vesting schedule of the locked amount, only set when the leaf has its own
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vesting_schedule: Option<VestingSchedule>,
     /// proof
     pub proof: Vec<[u8; 32]>,
 }
 
-// We need to discern between leaf and intermediate nodes to prevent trivial second
-// pre-image attacks.
-// https://flawed.net.nz/2018/02/21/attacking-merkle-trees-with-a-second-preimage-attack
-const LEAF_PREFIX: &[u8] = &[0];
-
 /// Merkle Tree which will be used to distribute tokens to claimants.
 /// Contains all the information necessary to verify claims against the Merkle Tree.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -47,6 +48,9 @@ pub struct AirdropMerkleTree {
     pub max_num_nodes: u64,
     pub total_unlocked_amount: u64,
     pub total_locked_amount: u64,
+    /// Hashing of the leaves and nodes, sha256 for the Solana program
+    #[serde(default)]
+    pub hash_mode: HashMode,
     pub tree_nodes: Vec<TreeNode>,
 }
 
@@ -59,6 +63,32 @@ impl AirdropMerkleTree {
             .unwrap()
     }
     pub fn new(tree_nodes: Vec<TreeNode>, airdrop_version: u64) -> Result<Self> {
+        Self::build(tree_nodes, airdrop_version, false, HashMode::Sha256)
+    }
+
+    /// Creates a tree whose leaves commit to their index, for distributors that track
+    /// claims in a bitmap. Such distributors are unlocked only.
+    pub fn new_with_leaf_index(tree_nodes: Vec<TreeNode>, airdrop_version: u64) -> Result<Self> {
+        Self::build(tree_nodes, airdrop_version, true, HashMode::Sha256)
+    }
+
+    /// Creates a tree hashed with `hash_mode`. [HashMode::Keccak] trees are meant for
+    /// OpenZeppelin `MerkleProof` verifiers on EVM chains, not for the Solana program.
+    pub fn new_with_hash_mode(
+        tree_nodes: Vec<TreeNode>,
+        airdrop_version: u64,
+        with_leaf_index: bool,
+        hash_mode: HashMode,
+    ) -> Result<Self> {
+        Self::build(tree_nodes, airdrop_version, with_leaf_index, hash_mode)
+    }
+
+    fn build(
+        tree_nodes: Vec<TreeNode>,
+        airdrop_version: u64,
+        with_leaf_index: bool,
+        hash_mode: HashMode,
+    ) -> Result<Self> {
         // Combine tree nodes with the same claimant, while retaining original order
         let mut tree_nodes_map: IndexMap<Pubkey, TreeNode> = IndexMap::new();
         for tree_node in tree_nodes {
@@ -67,6 +97,11 @@ impl AirdropMerkleTree {
                 .entry(claimant)
                 .and_modify(|n| {
                     println!("duplicate claimant {} found, combining", n.claimant);
+                    assert_eq!(
+                        n.vesting_schedule, tree_node.vesting_schedule,
+                        "duplicate claimant {} has different vesting schedules",
+                        n.claimant
+                    );
                     n.amount = n.amount.checked_add(tree_node.amount).unwrap();
                     n.locked_amount = n
                         .locked_amount
@@ -79,12 +114,16 @@ impl AirdropMerkleTree {
         // Convert IndexMap back to Vec while preserving the order
         let mut tree_nodes: Vec<TreeNode> = tree_nodes_map.values().cloned().collect();
 
+        for (i, tree_node) in tree_nodes.iter_mut().enumerate() {
+            tree_node.leaf_index = with_leaf_index.then_some(i as u64);
+        }
+
         let hashed_nodes = tree_nodes
             .iter()
-            .map(|claim_info| claim_info.hash().to_bytes())
+            .map(|claim_info| claim_info.hash_with_mode(hash_mode).to_bytes())
             .collect::<Vec<_>>();
 
-        let tree = MerkleTree::new(&hashed_nodes[..], true);
+        let tree = MerkleTree::new_with_hash_mode(&hashed_nodes[..], true, hash_mode);
 
         for (i, tree_node) in tree_nodes.iter_mut().enumerate() {
             tree_node.proof = Some(get_proof(&tree, i));
@@ -101,6 +140,7 @@ impl AirdropMerkleTree {
             max_num_nodes: tree_nodes.len() as u64,
             total_unlocked_amount,
             total_locked_amount,
+            hash_mode,
             tree_nodes,
         };
 
@@ -118,7 +158,7 @@ impl AirdropMerkleTree {
         let tree_nodes: Vec<TreeNode> = csv_entries
             .into_iter()
             .map(|x| TreeNode::from_csv(x, decimals))
-            .collect();
+            .collect::<Result<_>>()?;
         let tree = Self::new(tree_nodes, version)?;
         Ok(tree)
     }
@@ -131,7 +171,7 @@ impl AirdropMerkleTree {
         let tree_nodes: Vec<TreeNode> = csv_entries
             .into_iter()
             .map(|x| TreeNode::from_csv(x, decimals))
-            .collect();
+            .collect::<Result<_>>()?;
         let tree = Self::new(tree_nodes, version)?;
         Ok(tree)
     }
@@ -162,6 +202,12 @@ impl AirdropMerkleTree {
         panic!("Claimant not found in tree");
     }
 
+    /// Returns the first node with a locked amount. Push and hook distributors only
+    /// claim unlocked amounts, such trees can't be used for them.
+    pub fn find_locked_node(&self) -> Option<&TreeNode> {
+        self.tree_nodes.iter().find(|node| node.locked_amount() > 0)
+    }
+
     fn validate(&self) -> Result<()> {
         // The Merkle tree can be at most height 32, implying a max node count of 2^32 - 1
         if self.max_num_nodes > 2u64.pow(32) - 1 {
@@ -189,6 +235,35 @@ impl AirdropMerkleTree {
             ));
         }
 
+        // validate leaf indexes, either every node is indexed by its position or none is
+        let with_leaf_index = self
+            .tree_nodes
+            .first()
+            .is_some_and(|n| n.leaf_index.is_some());
+        for (i, node) in self.tree_nodes.iter().enumerate() {
+            let expected = with_leaf_index.then_some(i as u64);
+            if node.leaf_index != expected {
+                return Err(MerkleValidationError(format!(
+                    "Tree node {} has leaf index {:?}, expected {:?}",
+                    i, node.leaf_index, expected
+                )));
+            }
+            if with_leaf_index && (node.locked_amount != 0 || node.vesting_schedule.is_some()) {
+                return Err(MerkleValidationError(format!(
+                    "Tree node {} has locked amount {}, leaf indexed trees are unlocked only",
+                    i, node.locked_amount
+                )));
+            }
+            if let Some(schedule) = node.vesting_schedule {
+                if schedule.num_periods == 0 || (schedule.num_periods > 1 && schedule.period == 0) {
+                    return Err(MerkleValidationError(format!(
+                        "Tree node {} has invalid vesting schedule {:?}",
+                        i, schedule
+                    )));
+                }
+            }
+        }
+
         // validate total_unlocked_amount
         let total_unlocked_amount = get_total_unlocked_amount(&self.tree_nodes);
         if total_unlocked_amount != self.total_unlocked_amount {
@@ -224,9 +299,9 @@ impl AirdropMerkleTree {
         let hashed_nodes: Vec<[u8; 32]> = self
             .tree_nodes
             .iter()
-            .map(|n| n.hash().to_bytes())
+            .map(|n| n.hash_with_mode(self.hash_mode).to_bytes())
             .collect();
-        let mk = MerkleTree::new(&hashed_nodes[..], true);
+        let mk = MerkleTree::new_with_hash_mode(&hashed_nodes[..], true, self.hash_mode);
 
         assert_eq!(
             mk.get_root()
@@ -237,10 +312,10 @@ impl AirdropMerkleTree {
 
         // Verify each node against the root
         for (i, _node) in hashed_nodes.iter().enumerate() {
-            let node = hashv(&[LEAF_PREFIX, &hashed_nodes[i]]);
+            let node = self.hash_mode.hash_leaf(&hashed_nodes[i]);
             let proof = get_proof(&mk, i);
 
-            if !verify(proof, root, node.to_bytes()) {
+            if !verify_with_hash_mode(proof, root, node, self.hash_mode) {
                 return Err(MerkleValidationError("invalid merkle proof".to_string()));
             }
         }
@@ -292,6 +367,8 @@ mod tests {
                 claimant: new_test_key(),
                 amount: rand_balance(),
                 locked_amount: rand_balance(),
+                leaf_index: None,
+                vesting_schedule: None,
                 proof: None,
             });
         }
@@ -307,6 +384,8 @@ mod tests {
             claimant: Pubkey::default(),
             amount: 2,
             locked_amount: 0,
+            leaf_index: None,
+            vesting_schedule: None,
             proof: None,
         }];
         let merkle_tree = AirdropMerkleTree::new(tree_nodes, 0).unwrap();
@@ -321,18 +400,24 @@ mod tests {
                 claimant: pubkey!("FLYqJsmJ5AGMxMxK3Qy1rSen4ES2dqqo6h51W3C1tYS"),
                 amount: (100 * u64::pow(10, 9)),
                 locked_amount: 0,
+                leaf_index: None,
+                vesting_schedule: None,
                 proof: None,
             },
             TreeNode {
                 claimant: pubkey!("EDGARWktv3nDxRYjufjdbZmryqGXceaFPoPpbUzdpqED"),
                 amount: (100 * u64::pow(10, 9)),
                 locked_amount: 0,
+                leaf_index: None,
+                vesting_schedule: None,
                 proof: None,
             },
             TreeNode {
                 claimant: pubkey!("EDGARWktv3nDxRYjufjdbZmryqGXceaFPoPpbUzdpqEH"),
                 amount: (100 * u64::pow(10, 9)),
                 locked_amount: 1,
+                leaf_index: None,
+                vesting_schedule: None,
                 proof: None,
             },
         ];
@@ -363,18 +448,24 @@ mod tests {
                 claimant: duplicate_pubkey,
                 amount: 10,
                 locked_amount: 10,
+                leaf_index: None,
+                vesting_schedule: None,
                 proof: None,
             },
             TreeNode {
                 claimant: duplicate_pubkey,
                 amount: 1,
                 locked_amount: 10,
+                leaf_index: None,
+                vesting_schedule: None,
                 proof: None,
             },
             TreeNode {
                 claimant: Pubkey::new_unique(),
                 amount: 0,
                 locked_amount: 10,
+                leaf_index: None,
+                vesting_schedule: None,
                 proof: None,
             },
         ];
@@ -384,4 +475,131 @@ mod tests {
         assert_eq!(tree.tree_nodes[0].amount, 11);
         assert_eq!(tree.tree_nodes[0].locked_amount, 20);
     }
+
+    #[test]
+    fn test_new_merkle_tree_with_leaf_index() {
+        let tree_nodes = (0..5)
+            .map(|i| TreeNode {
+                claimant: Pubkey::new_unique(),
+                amount: 100 + i,
+                locked_amount: 0,
+                leaf_index: None,
+                vesting_schedule: None,
+                proof: None,
+            })
+            .collect::<Vec<_>>();
+
+        let tree = AirdropMerkleTree::new_with_leaf_index(tree_nodes.clone(), 0).unwrap();
+        for (i, node) in tree.tree_nodes.iter().enumerate() {
+            assert_eq!(node.leaf_index, Some(i as u64));
+        }
+        assert!(tree.verify_proof().is_ok(), "verify failed");
+
+        // the index is part of the leaf, so the root differs from the plain tree
+        let plain_tree = AirdropMerkleTree::new(tree_nodes, 0).unwrap();
+        assert_ne!(tree.merkle_root, plain_tree.merkle_root);
+    }
+
+    #[test]
+    fn test_new_merkle_tree_with_leaf_index_rejects_locked_amount() {
+        let tree_nodes = vec![TreeNode {
+            claimant: Pubkey::new_unique(),
+            amount: 1,
+            locked_amount: 1,
+            leaf_index: None,
+            vesting_schedule: None,
+            proof: None,
+        }];
+
+        assert!(AirdropMerkleTree::new_with_leaf_index(tree_nodes, 0).is_err());
+    }
+
+    #[test]
+    fn test_find_locked_node() {
+        let mut tree_nodes = (0..3)
+            .map(|i| TreeNode {
+                claimant: Pubkey::new_unique(),
+                amount: 100 + i,
+                locked_amount: 0,
+                leaf_index: None,
+                vesting_schedule: None,
+                proof: None,
+            })
+            .collect::<Vec<_>>();
+
+        let tree = AirdropMerkleTree::new(tree_nodes.clone(), 0).unwrap();
+        assert!(tree.find_locked_node().is_none());
+
+        tree_nodes[1].locked_amount = 50;
+        let tree = AirdropMerkleTree::new(tree_nodes.clone(), 0).unwrap();
+        assert_eq!(
+            tree.find_locked_node().map(|node| node.claimant),
+            Some(tree_nodes[1].claimant)
+        );
+    }
+
+    #[test]
+    fn test_new_merkle_tree_with_keccak() {
+        let tree_nodes = (0..2)
+            .map(|i| TreeNode {
+                claimant: Pubkey::new_unique(),
+                amount: 100 + i,
+                locked_amount: 0,
+                leaf_index: None,
+                vesting_schedule: None,
+                proof: None,
+            })
+            .collect::<Vec<_>>();
+
+        let tree =
+            AirdropMerkleTree::new_with_hash_mode(tree_nodes.clone(), 0, false, HashMode::Keccak)
+                .unwrap();
+        assert!(tree.verify_proof().is_ok(), "verify failed");
+
+        // StandardMerkleTree: double hashed leaves, sorted pairs
+        let mut leaves = tree_nodes
+            .iter()
+            .map(|n| HashMode::Keccak.hash_leaf(&n.hash_with_mode(HashMode::Keccak).to_bytes()))
+            .collect::<Vec<_>>();
+        leaves.sort();
+        assert_eq!(
+            tree.merkle_root,
+            HashMode::Keccak.hash_intermediate(&leaves[0], &leaves[1])
+        );
+
+        let serialized = serde_json::to_string(&tree).unwrap();
+        let deserialized: AirdropMerkleTree = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.hash_mode, HashMode::Keccak);
+        assert!(deserialized.verify_proof().is_ok());
+
+        let plain_tree = AirdropMerkleTree::new(tree_nodes, 0).unwrap();
+        assert_eq!(plain_tree.hash_mode, HashMode::Sha256);
+        assert_ne!(tree.merkle_root, plain_tree.merkle_root);
+    }
+
+    #[test]
+    fn test_new_merkle_tree_with_keccak_standard_root() {
+        let tree_nodes = (1..=5)
+            .map(|i| {
+                let mut claimant = [0u8; 32];
+                claimant[31] = i as u8;
+                TreeNode {
+                    claimant: Pubkey::new_from_array(claimant),
+                    amount: 99 + i,
+                    locked_amount: 0,
+                    leaf_index: None,
+                    vesting_schedule: None,
+                    proof: None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let tree =
+            AirdropMerkleTree::new_with_hash_mode(tree_nodes, 0, false, HashMode::Keccak).unwrap();
+        // StandardMerkleTree.of(values, ["bytes32", "uint256", "uint256"]).root
+        assert_eq!(
+            hex::encode(tree.merkle_root),
+            "c0bc476da4c45b98597c49c204e6a9626171de2f2876a9be120bdbdffd316d1e"
+        );
+    }
 }
@@ -0,0 +1,417 @@
+use std::{fmt, str::FromStr};
+
+/// Basis points of the full bonus
+const MAX_BPS: u128 = 10_000;
+
+/// Shape of the bonus of a claimant over time, from the activation point.
+/// Mirrors `BonusCurveType` of the program.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum BonusCurveType {
+    /// grows linearly to the max bonus over the vesting duration
+    #[default]
+    Linear = 0,
+    /// grows in `num_steps` equal tiers over the vesting duration
+    Step = 1,
+    /// max bonus at activation, halving every vesting duration
+    ExponentialDecay = 2,
+}
+
+impl TryFrom<u8> for BonusCurveType {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(BonusCurveType::Linear),
+            1 => Ok(BonusCurveType::Step),
+            2 => Ok(BonusCurveType::ExponentialDecay),
+            _ => Err(format!("unknown bonus curve type {value}")),
+        }
+    }
+}
+
+impl FromStr for BonusCurveType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "linear" => Ok(BonusCurveType::Linear),
+            "step" => Ok(BonusCurveType::Step),
+            "exponential_decay" => Ok(BonusCurveType::ExponentialDecay),
+            _ => Err(format!(
+                "unknown bonus curve {s}, expected linear, step or exponential_decay"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for BonusCurveType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BonusCurveType::Linear => write!(f, "linear"),
+            BonusCurveType::Step => write!(f, "step"),
+            BonusCurveType::ExponentialDecay => write!(f, "exponential_decay"),
+        }
+    }
+}
+
+/// Bonus settings of a distributor, as in its `AirdropBonus`.
+/// Previews return the same amounts as the program, or None where the program fails.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AirdropBonusConfig {
+    pub total_bonus: u64,
+    pub vesting_duration: u64,
+    pub curve_type: BonusCurveType,
+    pub num_steps: u16,
+}
+
+impl AirdropBonusConfig {
+    /// Share of the total bonus of a claimant, `max_total_claim` includes the total bonus
+    pub fn max_bonus(&self, unlocked_amount: u64, max_total_claim: u64) -> Option<u64> {
+        let max_total_claim_without_bonus = max_total_claim.checked_sub(self.total_bonus)? as u128;
+
+        let amount = (unlocked_amount as u128)
+            .checked_mul(self.total_bonus as u128)?
+            .checked_div(max_total_claim_without_bonus)?;
+        Some(amount as u64)
+    }
+
+    /// Bonus of a claimant claiming `unlocked_amount` at `curr_point`, scaled down to
+    /// `lock_bonus_bps` of the curve, see [lock_bonus_bps]. Plain claims get 10_000 bps
+    pub fn preview_bonus(
+        &self,
+        unlocked_amount: u64,
+        max_total_claim: u64,
+        activation_point: u64,
+        curr_point: u64,
+        lock_bonus_bps: u64,
+    ) -> Option<u64> {
+        let max_bonus = self.max_bonus(unlocked_amount, max_total_claim)?;
+        let bonus = self.bonus_at(max_bonus, activation_point, curr_point)?;
+        let amount = (bonus as u128)
+            .checked_mul(lock_bonus_bps.into())?
+            .checked_div(MAX_BPS)?;
+        Some(amount as u64)
+    }
+
+    /// Part of `max_bonus` given at `curr_point` by the bonus curve
+    pub fn bonus_at(&self, max_bonus: u64, activation_point: u64, curr_point: u64) -> Option<u64> {
+        if curr_point < activation_point {
+            return Some(0);
+        }
+        let duration_into_unlock = curr_point - activation_point;
+        let total_unlock_duration = self.vesting_duration;
+
+        match self.curve_type {
+            BonusCurveType::Linear => {
+                if duration_into_unlock >= total_unlock_duration {
+                    Some(max_bonus)
+                } else {
+                    let amount = (duration_into_unlock as u128)
+                        .checked_mul(max_bonus as u128)?
+                        .checked_div(total_unlock_duration as u128)?;
+                    Some(amount as u64)
+                }
+            }
+            BonusCurveType::Step => {
+                if duration_into_unlock >= total_unlock_duration {
+                    Some(max_bonus)
+                } else {
+                    let num_steps = self.num_steps as u128;
+                    let steps_reached = (duration_into_unlock as u128)
+                        .checked_mul(num_steps)?
+                        .checked_div(total_unlock_duration as u128)?;
+                    let amount = steps_reached
+                        .checked_mul(max_bonus as u128)?
+                        .checked_div(num_steps)?;
+                    Some(amount as u64)
+                }
+            }
+            BonusCurveType::ExponentialDecay => {
+                let num_halvings = duration_into_unlock.checked_div(total_unlock_duration)?;
+                if num_halvings >= u64::BITS as u64 {
+                    return Some(0);
+                }
+                let bonus_before = max_bonus >> num_halvings;
+                let bonus_after = bonus_before >> 1;
+                let duration_into_halving = duration_into_unlock % total_unlock_duration;
+
+                let decay = ((bonus_before - bonus_after) as u128)
+                    .checked_mul(duration_into_halving as u128)?
+                    .checked_div(total_unlock_duration as u128)?;
+                Some(bonus_before - decay as u64)
+            }
+        }
+    }
+}
+
+/// Tier of the lock bonus schedule of a claim and stake distributor, as in its `LockBonusSchedule`.
+/// Parsed from `min_lock_duration:bonus_bps`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LockBonusTier {
+    pub min_lock_duration: u64,
+    pub bonus_bps: u16,
+}
+
+impl FromStr for LockBonusTier {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (min_lock_duration, bonus_bps) = s.split_once(':').ok_or(format!(
+            "invalid lock bonus tier {s}, expected duration:bps"
+        ))?;
+        Ok(LockBonusTier {
+            min_lock_duration: min_lock_duration.parse().map_err(|e| format!("{e}"))?,
+            bonus_bps: bonus_bps.parse().map_err(|e| format!("{e}"))?,
+        })
+    }
+}
+
+/// Share of the bonus (bps) of an escrow locked for `lock_duration` more seconds, as given by
+/// the program: max lock escrows get it all, others the highest tier they reach or nothing
+pub fn lock_bonus_bps(tiers: &[LockBonusTier], lock_duration: u64, is_max_lock: bool) -> u64 {
+    if is_max_lock {
+        return MAX_BPS as u64;
+    }
+    tiers
+        .iter()
+        .rev()
+        .find(|tier| tier.min_lock_duration <= lock_duration)
+        .map_or(0, |tier| tier.bonus_bps.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(curve_type: BonusCurveType, num_steps: u16) -> AirdropBonusConfig {
+        AirdropBonusConfig {
+            total_bonus: 1_000,
+            vesting_duration: 100,
+            curve_type,
+            num_steps,
+        }
+    }
+
+    #[test]
+    fn test_max_bonus() {
+        let config = config(BonusCurveType::Linear, 0);
+        // 10% of the amounts without bonus
+        assert_eq!(config.max_bonus(1_000, 11_000), Some(100));
+        assert_eq!(config.max_bonus(1_000, 999), None);
+    }
+
+    #[test]
+    fn test_linear_bonus() {
+        let config = config(BonusCurveType::Linear, 0);
+        assert_eq!(config.bonus_at(1_000, 50, 40), Some(0));
+        assert_eq!(config.bonus_at(1_000, 50, 50), Some(0));
+        assert_eq!(config.bonus_at(1_000, 50, 75), Some(250));
+        assert_eq!(config.bonus_at(1_000, 50, 150), Some(1_000));
+        assert_eq!(config.bonus_at(1_000, 50, 500), Some(1_000));
+        assert_eq!(
+            config.preview_bonus(1_000, 11_000, 50, 100, 10_000),
+            Some(50)
+        );
+        assert_eq!(
+            config.preview_bonus(1_000, 11_000, 50, 100, 2_500),
+            Some(12)
+        );
+        assert_eq!(config.preview_bonus(1_000, 11_000, 50, 100, 0), Some(0));
+    }
+
+    #[test]
+    fn test_step_bonus() {
+        let config = config(BonusCurveType::Step, 4);
+        assert_eq!(config.bonus_at(1_000, 0, 24), Some(0));
+        assert_eq!(config.bonus_at(1_000, 0, 25), Some(250));
+        assert_eq!(config.bonus_at(1_000, 0, 74), Some(500));
+        assert_eq!(config.bonus_at(1_000, 0, 99), Some(750));
+        assert_eq!(config.bonus_at(1_000, 0, 100), Some(1_000));
+
+        // the program rejects step curves without steps
+        assert_eq!(
+            AirdropBonusConfig {
+                num_steps: 0,
+                ..config
+            }
+            .bonus_at(1_000, 0, 50),
+            None
+        );
+    }
+
+    #[test]
+    fn test_exponential_decay_bonus() {
+        let config = config(BonusCurveType::ExponentialDecay, 0);
+        assert_eq!(config.bonus_at(1_000, 50, 40), Some(0));
+        assert_eq!(config.bonus_at(1_000, 50, 50), Some(1_000));
+        assert_eq!(config.bonus_at(1_000, 50, 100), Some(750));
+        assert_eq!(config.bonus_at(1_000, 50, 150), Some(500));
+        assert_eq!(config.bonus_at(1_000, 50, 200), Some(375));
+        assert_eq!(config.bonus_at(1_000, 50, 250), Some(250));
+        assert_eq!(config.bonus_at(u64::MAX, 0, 6_400), Some(0));
+
+        // decreasing, rewarding early claimers
+        let bonuses: Vec<u64> = (50..2_000)
+            .map(|curr_point| config.bonus_at(1_000, 50, curr_point).unwrap())
+            .collect();
+        assert!(bonuses.windows(2).all(|w| w[0] >= w[1]));
+        assert_eq!(*bonuses.last().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_bonus_at_matches_program() {
+        use merkle_distributor::state::merkle_distributor::{
+            ActivationHandler, AirdropBonus, MAX_BPS,
+        };
+
+        for (curve_type, num_steps) in [
+            (BonusCurveType::Linear, 0),
+            (BonusCurveType::Step, 0),
+            (BonusCurveType::Step, 3),
+            (BonusCurveType::Step, 4),
+            (BonusCurveType::Step, 7),
+            (BonusCurveType::ExponentialDecay, 0),
+        ] {
+            for vesting_duration in [0, 1, 100, 101] {
+                let config = AirdropBonusConfig {
+                    vesting_duration,
+                    ..config(curve_type, num_steps)
+                };
+                for max_bonus in [0, 1, 999, 1_000, u64::MAX] {
+                    for curr_point in (0..1_000).chain([6_449, 6_450, 6_500, u64::MAX]) {
+                        let handler = ActivationHandler {
+                            curr_point,
+                            activation_point: 50,
+                            airdrop_bonus: AirdropBonus {
+                                vesting_duration,
+                                curve_type: curve_type as u8,
+                                num_steps,
+                                ..Default::default()
+                            },
+                            lock_bonus_bps: MAX_BPS as u64,
+                        };
+                        assert_eq!(
+                            config.bonus_at(max_bonus, 50, curr_point),
+                            handler.get_bonus_for_a_claimaint(max_bonus).ok(),
+                            "{curve_type} num_steps {num_steps} vesting_duration {vesting_duration} \
+                             max_bonus {max_bonus} curr_point {curr_point}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lock_bonus_matches_program() {
+        use merkle_distributor::state::{
+            lock_bonus_schedule::{self, LockBonusSchedule},
+            merkle_distributor::{ActivationHandler, AirdropBonus, MerkleDistributor},
+        };
+
+        const MONTH: u64 = 30 * 24 * 3600;
+        let tiers = [
+            LockBonusTier {
+                min_lock_duration: 6 * MONTH,
+                bonus_bps: 2_500,
+            },
+            LockBonusTier {
+                min_lock_duration: 12 * MONTH,
+                bonus_bps: 6_000,
+            },
+        ];
+        let mut schedule = LockBonusSchedule {
+            num_tiers: tiers.len() as u8,
+            ..Default::default()
+        };
+        for (tier, program_tier) in tiers.iter().zip(schedule.tiers.iter_mut()) {
+            *program_tier = lock_bonus_schedule::LockBonusTier {
+                min_lock_duration: tier.min_lock_duration,
+                bonus_bps: tier.bonus_bps,
+                ..Default::default()
+            };
+        }
+
+        let config = config(BonusCurveType::Linear, 0);
+        let distributor = MerkleDistributor {
+            max_total_claim: 11_000,
+            airdrop_bonus: AirdropBonus {
+                total_bonus: config.total_bonus,
+                vesting_duration: config.vesting_duration,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        for lock_duration in [
+            0,
+            6 * MONTH - 1,
+            6 * MONTH,
+            12 * MONTH - 1,
+            12 * MONTH,
+            u64::MAX,
+        ] {
+            for is_max_lock in [false, true] {
+                let bps = lock_bonus_bps(&tiers, lock_duration, is_max_lock);
+                assert_eq!(bps, schedule.bonus_bps(lock_duration, is_max_lock));
+
+                for curr_point in [50, 75, 149, 150, 1_000] {
+                    let handler = ActivationHandler {
+                        curr_point,
+                        activation_point: 50,
+                        airdrop_bonus: distributor.airdrop_bonus,
+                        lock_bonus_bps: 10_000,
+                    }
+                    .with_lock_bonus_bps(bps);
+                    assert_eq!(
+                        config.preview_bonus(1_234, 11_000, 50, curr_point, bps),
+                        distributor.get_bonus_for_a_claimaint(1_234, &handler).ok()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bonus_curve_type_roundtrip() {
+        for curve_type in [
+            BonusCurveType::Linear,
+            BonusCurveType::Step,
+            BonusCurveType::ExponentialDecay,
+        ] {
+            assert_eq!(
+                BonusCurveType::from_str(&curve_type.to_string()),
+                Ok(curve_type)
+            );
+            assert_eq!(BonusCurveType::try_from(curve_type as u8), Ok(curve_type));
+        }
+        assert!(BonusCurveType::try_from(3).is_err());
+    }
+
+    #[test]
+    fn test_lock_bonus_bps() {
+        const MONTH: u64 = 30 * 24 * 3600;
+        let tiers: Vec<LockBonusTier> = ["15552000:2500", "31104000:6000"]
+            .iter()
+            .map(|tier| LockBonusTier::from_str(tier).unwrap())
+            .collect();
+        assert_eq!(
+            tiers[0],
+            LockBonusTier {
+                min_lock_duration: 6 * MONTH,
+                bonus_bps: 2_500
+            }
+        );
+
+        assert_eq!(lock_bonus_bps(&tiers, 0, true), 10_000);
+        assert_eq!(lock_bonus_bps(&tiers, 6 * MONTH - 1, false), 0);
+        assert_eq!(lock_bonus_bps(&tiers, 6 * MONTH, false), 2_500);
+        assert_eq!(lock_bonus_bps(&tiers, 11 * MONTH, false), 2_500);
+        assert_eq!(lock_bonus_bps(&tiers, 24 * MONTH, false), 6_000);
+        assert_eq!(lock_bonus_bps(&[], 24 * MONTH, false), 0);
+
+        assert!(LockBonusTier::from_str("15552000").is_err());
+        assert!(LockBonusTier::from_str("15552000:x").is_err());
+    }
+}
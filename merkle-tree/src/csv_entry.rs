@@ -15,6 +15,15 @@ pub struct CsvEntry {
     pub amount: String,
     /// amount locked, (ui amount)
     pub locked_amount: String,
+    /// first unlock of the locked amount (Unix Timestamp), empty to use the distributor lockup
+    #[serde(default)]
+    pub cliff_ts: Option<i64>,
+    /// seconds between two unlocks
+    #[serde(default)]
+    pub period: Option<u32>,
+    /// number of unlocks
+    #[serde(default)]
+    pub num_periods: Option<u32>,
 }
 
 impl CsvEntry {
@@ -49,5 +58,22 @@ mod tests {
         );
         assert_eq!(entries[0].amount, "1000");
         assert_eq!(entries[0].locked_amount, "10");
+        assert_eq!(entries[0].cliff_ts, None);
+    }
+
+    #[test]
+    fn test_csv_vesting_schedule_parsing() {
+        let path = PathBuf::from("./test_fixtures/test_csv_vesting.csv");
+        let entries = CsvEntry::new_from_file(&path).expect("Failed to parse CSV");
+
+        assert_eq!(entries.len(), 2);
+
+        assert_eq!(entries[0].cliff_ts, Some(1735689600));
+        assert_eq!(entries[0].period, Some(2592000));
+        assert_eq!(entries[0].num_periods, Some(12));
+
+        assert_eq!(entries[1].cliff_ts, None);
+        assert_eq!(entries[1].period, None);
+        assert_eq!(entries[1].num_periods, None);
     }
 }
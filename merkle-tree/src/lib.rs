@@ -1,6 +1,8 @@
 pub mod airdrop_merkle_tree;
+pub mod bonus;
 pub mod csv_entry;
 pub mod error;
 pub mod merkle_tree;
+pub mod redistribution;
 pub mod tree_node;
 pub mod utils;
@@ -1,21 +1,28 @@
 // https://github.com/jito-foundation/jito-solana/blob/v1.16.19-jito/merkle-tree/src/merkle_tree.rs
-use solana_program::hash::{hashv, Hash};
+use jito_merkle_verify::HashMode;
+use solana_program::hash::Hash;
 
 // We need to discern between leaf and intermediate nodes to prevent trivial second
-// pre-image attacks.
+// pre-image attacks, either with a prefix or by double hashing the leaves, see [HashMode].
 // https://flawed.net.nz/2018/02/21/attacking-merkle-trees-with-a-second-preimage-attack
-const LEAF_PREFIX: &[u8] = &[0];
-const INTERMEDIATE_PREFIX: &[u8] = &[1];
-
 macro_rules! hash_leaf {
-    {$d:ident} => {
-        hashv(&[LEAF_PREFIX, $d])
+    {$m:expr, $d:ident} => {
+        Hash::new_from_array($m.hash_leaf($d))
     }
 }
 
 macro_rules! hash_intermediate {
-    {$l:ident, $r:ident} => {
-        hashv(&[INTERMEDIATE_PREFIX, $l.as_ref(), $r.as_ref()])
+    {$m:expr, $l:ident, $r:ident} => {
+        Hash::new_from_array($m.hash_intermediate($l.as_ref(), $r.as_ref()))
+    }
+}
+
+// OpenZeppelin's `MerkleProof` hashes every pair sorted, keccak trees always do so
+fn hash_sorted_pair(hash_mode: HashMode, a: &Hash, b: &Hash) -> Hash {
+    if a <= b {
+        hash_intermediate!(hash_mode, a, b)
+    } else {
+        hash_intermediate!(hash_mode, b, a)
     }
 }
 
@@ -23,6 +30,9 @@ macro_rules! hash_intermediate {
 pub struct MerkleTree {
     leaf_count: usize,
     nodes: Vec<Hash>,
+    hash_mode: HashMode,
+    // position of each item in `nodes`, for [HashMode::Keccak] trees only
+    leaf_positions: Vec<usize>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -48,7 +58,7 @@ impl<'a> ProofEntry<'a> {
 }
 
 #[derive(Debug, Default, PartialEq, Eq)]
-pub struct Proof<'a>(Vec<ProofEntry<'a>>);
+pub struct Proof<'a>(Vec<ProofEntry<'a>>, HashMode);
 
 impl<'a> Proof<'a> {
     pub fn push(&mut self, entry: ProofEntry<'a>) {
@@ -59,7 +69,10 @@ impl<'a> Proof<'a> {
         let result = self.0.iter().try_fold(candidate, |candidate, pe| {
             let lsib = pe.1.unwrap_or(&candidate);
             let rsib = pe.2.unwrap_or(&candidate);
-            let hash = hash_intermediate!(lsib, rsib);
+            let hash = match self.1 {
+                HashMode::Sha256 => hash_intermediate!(self.1, lsib, rsib),
+                HashMode::Keccak => hash_sorted_pair(self.1, lsib, rsib),
+            };
 
             if hash == *pe.0 {
                 Some(hash)
@@ -109,15 +122,32 @@ impl MerkleTree {
     }
 
     pub fn new<T: AsRef<[u8]>>(items: &[T], sorted_hashes: bool) -> Self {
+        Self::new_with_hash_mode(items, sorted_hashes, HashMode::Sha256)
+    }
+
+    /// Builds the tree hashing its leaves and intermediate nodes with `hash_mode`.
+    /// [HashMode::Keccak] trees are laid out as OpenZeppelin's `StandardMerkleTree`,
+    /// see [MerkleTree::new_standard], and always hash sorted pairs.
+    pub fn new_with_hash_mode<T: AsRef<[u8]>>(
+        items: &[T],
+        sorted_hashes: bool,
+        hash_mode: HashMode,
+    ) -> Self {
+        if hash_mode == HashMode::Keccak {
+            return MerkleTree::new_standard(items, hash_mode);
+        }
+
         let cap = MerkleTree::calculate_vec_capacity(items.len());
         let mut mt = MerkleTree {
             leaf_count: items.len(),
             nodes: Vec::with_capacity(cap),
+            hash_mode,
+            leaf_positions: vec![],
         };
 
         for item in items {
             let item = item.as_ref();
-            let hash = hash_leaf!(item);
+            let hash = hash_leaf!(hash_mode, item);
             mt.nodes.push(hash);
         }
 
@@ -139,15 +169,15 @@ impl MerkleTree {
                 // tip-distribution verification uses sorted hashing
                 if sorted_hashes {
                     if lsib <= rsib {
-                        let hash = hash_intermediate!(lsib, rsib);
+                        let hash = hash_intermediate!(hash_mode, lsib, rsib);
                         mt.nodes.push(hash);
                     } else {
-                        let hash = hash_intermediate!(rsib, lsib);
+                        let hash = hash_intermediate!(hash_mode, rsib, lsib);
                         mt.nodes.push(hash);
                     }
                 } else {
                     // hashing for solana internals
-                    let hash = hash_intermediate!(lsib, rsib);
+                    let hash = hash_intermediate!(hash_mode, lsib, rsib);
                     mt.nodes.push(hash);
                 }
             }
@@ -160,8 +190,44 @@ impl MerkleTree {
         mt
     }
 
+    // https://github.com/OpenZeppelin/merkle-tree/blob/v1.0.6/src/core.ts
+    // The leaves are sorted and stored in reverse order at the end of a complete binary tree,
+    // the node at `i` being the parent of `2i + 1` and `2i + 2`, so no node is duplicated.
+    fn new_standard<T: AsRef<[u8]>>(items: &[T], hash_mode: HashMode) -> Self {
+        let mut leaves = items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let item = item.as_ref();
+                (hash_leaf!(hash_mode, item), i)
+            })
+            .collect::<Vec<_>>();
+        leaves.sort();
+
+        let tree_len = (2 * items.len()).saturating_sub(1);
+        let mut mt = MerkleTree {
+            leaf_count: items.len(),
+            nodes: vec![Hash::default(); tree_len],
+            hash_mode,
+            leaf_positions: vec![0; items.len()],
+        };
+        for (i, (hash, item_index)) in leaves.into_iter().enumerate() {
+            let position = tree_len - 1 - i;
+            mt.nodes[position] = hash;
+            mt.leaf_positions[item_index] = position;
+        }
+        for i in (0..tree_len.saturating_sub(items.len())).rev() {
+            mt.nodes[i] = hash_sorted_pair(hash_mode, &mt.nodes[2 * i + 1], &mt.nodes[2 * i + 2]);
+        }
+
+        mt
+    }
+
     pub fn get_root(&self) -> Option<&Hash> {
-        self.nodes.iter().last()
+        match self.hash_mode {
+            HashMode::Sha256 => self.nodes.iter().last(),
+            HashMode::Keccak => self.nodes.first(),
+        }
     }
 
     pub fn find_path(&self, index: usize) -> Option<Proof> {
@@ -169,9 +235,26 @@ impl MerkleTree {
             return None;
         }
 
+        if self.hash_mode == HashMode::Keccak {
+            let mut path = Proof(vec![], self.hash_mode);
+            let mut position = self.leaf_positions[index];
+            while position > 0 {
+                let parent = (position - 1) / 2;
+                // left children sit at odd positions
+                let entry = if position % 2 == 1 {
+                    ProofEntry::new(&self.nodes[parent], None, Some(&self.nodes[position + 1]))
+                } else {
+                    ProofEntry::new(&self.nodes[parent], Some(&self.nodes[position - 1]), None)
+                };
+                path.push(entry);
+                position = parent;
+            }
+            return Some(path);
+        }
+
         let mut level_len = self.leaf_count;
         let mut level_start = 0;
-        let mut path = Proof::default();
+        let mut path = Proof(vec![], self.hash_mode);
         let mut node_index = index;
         let mut lsib = None;
         let mut rsib = None;
@@ -222,7 +305,7 @@ mod tests {
     fn test_tree_from_one() {
         let input = b"test";
         let mt = MerkleTree::new(&[input], false);
-        let expected = hash_leaf!(input);
+        let expected = hash_leaf!(HashMode::Sha256, input);
         assert_eq!(mt.get_root(), Some(&expected));
     }
 
@@ -256,7 +339,7 @@ mod tests {
     fn test_path_verify_good() {
         let mt = MerkleTree::new(TEST, false);
         for (i, s) in TEST.iter().enumerate() {
-            let hash = hash_leaf!(s);
+            let hash = hash_leaf!(HashMode::Sha256, s);
             let path = mt.find_path(i).unwrap();
             assert!(path.verify(hash));
         }
@@ -266,12 +349,62 @@ mod tests {
     fn test_path_verify_bad() {
         let mt = MerkleTree::new(TEST, false);
         for (i, s) in BAD.iter().enumerate() {
-            let hash = hash_leaf!(s);
+            let hash = hash_leaf!(HashMode::Sha256, s);
             let path = mt.find_path(i).unwrap();
             assert!(!path.verify(hash));
         }
     }
 
+    #[test]
+    fn test_path_verify_keccak() {
+        let mt = MerkleTree::new_with_hash_mode(TEST, false, HashMode::Keccak);
+        assert_ne!(mt.get_root(), MerkleTree::new(TEST, false).get_root());
+        for (i, s) in TEST.iter().enumerate() {
+            let hash = hash_leaf!(HashMode::Keccak, s);
+            let path = mt.find_path(i).unwrap();
+            assert!(path.verify(hash));
+            assert!(!path.verify(hash_leaf!(HashMode::Sha256, s)));
+        }
+    }
+
+    #[test]
+    fn test_tree_keccak_standard_root() {
+        // keccak256(abi.encode(address, uint256)) of the OpenZeppelin merkle-tree README values
+        let encode = |address: u8, amount: u128| {
+            let mut words = [0u8; 64];
+            words[12..32].copy_from_slice(&[address; 20]);
+            words[48..].copy_from_slice(&amount.to_be_bytes());
+            solana_program::keccak::hash(&words).to_bytes()
+        };
+        let items = [
+            encode(0x11, 5_000_000_000_000_000_000),
+            encode(0x22, 2_500_000_000_000_000_000),
+            encode(0x33, 1_000_000_000_000_000_000),
+        ];
+
+        // Roots as computed by StandardMerkleTree.of(values, ["address", "uint256"])
+        for (len, root) in [
+            (
+                2,
+                "d4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77",
+            ),
+            (
+                3,
+                "1bc1c2bd927f8451332d9a50179032863d2cf0ffca6fcfec1b3e36e0f6260d3e",
+            ),
+        ] {
+            let mt = MerkleTree::new_with_hash_mode(&items[..len], true, HashMode::Keccak);
+            let expected = Hash::new(&hex::decode(root).unwrap());
+            assert_eq!(mt.get_root(), Some(&expected));
+            // a complete tree, no node is duplicated
+            assert_eq!(mt.nodes.len(), 2 * len - 1);
+            for (i, item) in items[..len].iter().enumerate() {
+                let hash = hash_leaf!(HashMode::Keccak, item);
+                assert!(mt.find_path(i).unwrap().verify(hash));
+            }
+        }
+    }
+
     #[test]
     fn test_proof_entry_instantiation_lsib_set() {
         ProofEntry::new(&Hash::default(), Some(&Hash::default()), None);
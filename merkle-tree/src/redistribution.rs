@@ -0,0 +1,125 @@
+use std::collections::BTreeMap;
+
+use solana_program::pubkey::Pubkey;
+
+use crate::{csv_entry::CsvEntry, tree_node::token_amount_to_ui_amount};
+
+/// Splits `total_amount` between the wallets pro rata to the amount they claimed.
+/// Claimed amounts of the same wallet are merged. Shares are rounded down and the remainder
+/// goes one token at a time to the largest fractional parts, so the allocations add up to
+/// `total_amount`. Wallets allocated nothing are left out, the result is sorted by wallet.
+pub fn pro_rata_allocations(
+    claimed_amounts: &[(Pubkey, u64)],
+    total_amount: u64,
+) -> Vec<(Pubkey, u64)> {
+    let mut claimed_by_wallet = BTreeMap::<Pubkey, u128>::new();
+    for (wallet, amount) in claimed_amounts.iter() {
+        *claimed_by_wallet.entry(*wallet).or_default() += *amount as u128;
+    }
+    let total_claimed: u128 = claimed_by_wallet.values().sum();
+    if total_claimed == 0 {
+        return vec![];
+    }
+
+    let mut allocations = Vec::with_capacity(claimed_by_wallet.len());
+    let mut remainders = Vec::with_capacity(claimed_by_wallet.len());
+    let mut total_allocated = 0u64;
+    for (index, (wallet, claimed)) in claimed_by_wallet.into_iter().enumerate() {
+        let share = claimed.checked_mul(total_amount as u128).unwrap();
+        let amount = (share / total_claimed) as u64;
+        allocations.push((wallet, amount));
+        remainders.push((share % total_claimed, index));
+        total_allocated += amount;
+    }
+
+    // less than one token left per wallet
+    let leftover = (total_amount - total_allocated) as usize;
+    remainders.sort_unstable_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    for (_, index) in remainders.into_iter().take(leftover) {
+        allocations[index].1 += 1;
+    }
+
+    allocations.retain(|(_, amount)| *amount > 0);
+    allocations
+}
+
+/// Unlocked [CsvEntry] of each allocation, in ui amounts of a mint with `decimals`
+pub fn allocations_to_csv_entries(allocations: &[(Pubkey, u64)], decimals: u32) -> Vec<CsvEntry> {
+    allocations
+        .iter()
+        .map(|(wallet, amount)| CsvEntry {
+            pubkey: wallet.to_string(),
+            amount: token_amount_to_ui_amount(*amount, decimals),
+            locked_amount: "0".to_string(),
+            cliff_ts: None,
+            period: None,
+            num_periods: None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{airdrop_merkle_tree::AirdropMerkleTree, tree_node::ui_amount_to_token_amount};
+
+    #[test]
+    fn test_pro_rata_allocations() {
+        let wallets: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let claimed_amounts = vec![(wallets[0], 100), (wallets[1], 300), (wallets[2], 600)];
+
+        let allocations = pro_rata_allocations(&claimed_amounts, 1_000);
+        let mut expected = vec![(wallets[0], 100), (wallets[1], 300), (wallets[2], 600)];
+        expected.sort_unstable();
+        assert_eq!(allocations, expected);
+    }
+
+    #[test]
+    fn test_pro_rata_allocations_remainder() {
+        let wallets: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let claimed_amounts = vec![(wallets[0], 1), (wallets[1], 1), (wallets[2], 1)];
+
+        let allocations = pro_rata_allocations(&claimed_amounts, 100);
+        assert_eq!(allocations.iter().map(|(_, a)| a).sum::<u64>(), 100);
+        assert_eq!(allocations.iter().filter(|(_, a)| *a == 34).count(), 1);
+        assert_eq!(allocations.iter().filter(|(_, a)| *a == 33).count(), 2);
+    }
+
+    #[test]
+    fn test_pro_rata_allocations_merge_and_skip() {
+        let wallets: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        // wallet 0 claimed in two versions, wallet 2 claimed too little to get a token
+        let claimed_amounts = vec![
+            (wallets[0], 500),
+            (wallets[1], 1_000),
+            (wallets[0], 500),
+            (wallets[2], 1),
+        ];
+
+        let allocations = pro_rata_allocations(&claimed_amounts, 10);
+        assert_eq!(allocations.len(), 2);
+        assert!(allocations.contains(&(wallets[0], 5)));
+        assert!(allocations.contains(&(wallets[1], 5)));
+
+        assert!(pro_rata_allocations(&[], 10).is_empty());
+        assert!(pro_rata_allocations(&[(wallets[0], 0)], 10).is_empty());
+    }
+
+    #[test]
+    fn test_allocations_to_merkle_tree() {
+        let wallets: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let claimed_amounts = vec![(wallets[0], 7), (wallets[1], 11), (wallets[2], 13)];
+        let total_amount = 123_456_789;
+        let decimals = 6;
+
+        let allocations = pro_rata_allocations(&claimed_amounts, total_amount);
+        let csv_entries = allocations_to_csv_entries(&allocations, decimals);
+        for (entry, (_, amount)) in csv_entries.iter().zip(allocations.iter()) {
+            assert_eq!(ui_amount_to_token_amount(&entry.amount, decimals), *amount);
+        }
+
+        let merkle_tree = AirdropMerkleTree::new_from_entries(csv_entries, 1, decimals).unwrap();
+        assert_eq!(merkle_tree.max_num_nodes, 3);
+        assert_eq!(merkle_tree.get_max_total_claim(), total_amount);
+    }
+}
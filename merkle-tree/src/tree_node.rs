@@ -1,11 +1,23 @@
 use std::str::FromStr;
 
-use crate::csv_entry::CsvEntry;
+use crate::{csv_entry::CsvEntry, error::MerkleTreeError};
+use jito_merkle_verify::HashMode;
 use rust_decimal::{prelude::FromPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
-use solana_program::{hash::hashv, pubkey::Pubkey};
+use solana_program::{hash::hashv, keccak, pubkey::Pubkey};
 use solana_sdk::hash::Hash;
 
+/// Cliff and step unlocks of the locked amount, see the program `VestingSchedule`.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct VestingSchedule {
+    /// Timestamp of the first unlock (Unix Timestamp)
+    pub cliff_ts: i64,
+    /// Seconds between two unlocks
+    pub period: u32,
+    /// Number of unlocks
+    pub num_periods: u32,
+}
+
 /// Represents the claim information for an account.
 #[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct TreeNode {
@@ -15,17 +27,69 @@ pub struct TreeNode {
     pub amount: u64,
     /// Locked amount
     pub locked_amount: u64,
+    /// Position of the leaf, set for trees claimed through a claim bitmap
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leaf_index: Option<u64>,
+    /// Vesting schedule of the locked amount, unset to use the distributor lockup
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vesting_schedule: Option<VestingSchedule>,
     /// Claimant's proof of inclusion in the Merkle Tree
     pub proof: Option<Vec<[u8; 32]>>,
 }
 
 impl TreeNode {
     pub fn hash(&self) -> Hash {
-        hashv(&[
-            &self.claimant.to_bytes(),
-            &self.amount.to_le_bytes(),
-            &self.locked_amount.to_le_bytes(),
-        ])
+        let leaf_index = self.leaf_index.map(u64::to_le_bytes);
+        let claimant = self.claimant.to_bytes();
+        let amount = self.amount.to_le_bytes();
+        let locked_amount = self.locked_amount.to_le_bytes();
+        let vesting_schedule = self.vesting_schedule.map(|s| {
+            (
+                s.cliff_ts.to_le_bytes(),
+                s.period.to_le_bytes(),
+                s.num_periods.to_le_bytes(),
+            )
+        });
+
+        let mut fields: Vec<&[u8]> = vec![];
+        if let Some(leaf_index) = &leaf_index {
+            fields.push(leaf_index);
+        }
+        fields.extend([&claimant[..], &amount, &locked_amount]);
+        if let Some((cliff_ts, period, num_periods)) = &vesting_schedule {
+            fields.extend([&cliff_ts[..], period, num_periods]);
+        }
+        hashv(&fields)
+    }
+
+    /// Hashes the leaf data for a tree of the given [HashMode].
+    /// Keccak leaves hash the `abi.encode` of the same fields, each as a 32 bytes word
+    /// (the claimant as `bytes32`, or an EVM address left padded with zeros, the amounts
+    /// and the schedule as `uint256`, the cliff timestamp as `int256`).
+    pub fn hash_with_mode(&self, hash_mode: HashMode) -> Hash {
+        match hash_mode {
+            HashMode::Sha256 => self.hash(),
+            HashMode::Keccak => {
+                let mut words: Vec<[u8; 32]> = vec![];
+                if let Some(leaf_index) = self.leaf_index {
+                    words.push(abi_encode_uint(leaf_index));
+                }
+                words.extend([
+                    self.claimant.to_bytes(),
+                    abi_encode_uint(self.amount),
+                    abi_encode_uint(self.locked_amount),
+                ]);
+                if let Some(s) = self.vesting_schedule {
+                    words.extend([
+                        abi_encode_int(s.cliff_ts),
+                        abi_encode_uint(s.period.into()),
+                        abi_encode_uint(s.num_periods.into()),
+                    ]);
+                }
+                let fields: Vec<&[u8]> = words.iter().map(|w| &w[..]).collect();
+                Hash::new_from_array(keccak::hashv(&fields).to_bytes())
+            }
+        }
     }
     /// Return total amount for this claimant
     pub fn total_amount(&self) -> u64 {
@@ -42,6 +106,20 @@ impl TreeNode {
     }
 }
 
+/// ABI encodes an unsigned integer as a big endian `uint256` word
+fn abi_encode_uint(value: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// ABI encodes a signed integer as a two's complement `int256` word
+fn abi_encode_int(value: i64) -> [u8; 32] {
+    let mut word = if value < 0 { [0xffu8; 32] } else { [0u8; 32] };
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
 /// Converts a ui amount to a token amount (with decimals)
 pub fn ui_amount_to_token_amount(amount: &str, decimals: u32) -> u64 {
     let amount = Decimal::from_str(amount).unwrap();
@@ -52,15 +130,36 @@ pub fn ui_amount_to_token_amount(amount: &str, decimals: u32) -> u64 {
     amount.try_into().unwrap()
 }
 
+/// Converts a token amount (with decimals) to a ui amount, the inverse of [ui_amount_to_token_amount]
+pub fn token_amount_to_ui_amount(amount: u64, decimals: u32) -> String {
+    Decimal::from_i128_with_scale(amount as i128, decimals).to_string()
+}
+
 impl TreeNode {
-    pub fn from_csv(entry: CsvEntry, decimals: u32) -> Self {
-        let node = Self {
+    /// Builds a node from a csv entry, erroring if the vesting schedule is only partially set
+    pub fn from_csv(entry: CsvEntry, decimals: u32) -> Result<Self, MerkleTreeError> {
+        let vesting_schedule = match (entry.cliff_ts, entry.period, entry.num_periods) {
+            (Some(cliff_ts), Some(period), Some(num_periods)) => Some(VestingSchedule {
+                cliff_ts,
+                period,
+                num_periods,
+            }),
+            (None, None, None) => None,
+            _ => {
+                return Err(MerkleTreeError::MerkleValidationError(format!(
+                    "incomplete vesting schedule for {}",
+                    entry.pubkey
+                )))
+            }
+        };
+        Ok(Self {
             claimant: Pubkey::from_str(entry.pubkey.as_str()).unwrap(),
             amount: ui_amount_to_token_amount(entry.amount.as_str(), decimals),
             locked_amount: ui_amount_to_token_amount(entry.locked_amount.as_str(), decimals),
+            leaf_index: None,
+            vesting_schedule,
             proof: None,
-        };
-        node
+        })
     }
 }
 
@@ -89,7 +188,7 @@ mod tests {
 
         let tree_nodes: Vec<TreeNode> = entries
             .into_iter()
-            .map(|x| TreeNode::from_csv(x, decimals))
+            .map(|x| TreeNode::from_csv(x, decimals).unwrap())
             .collect();
 
         assert_eq!(tree_nodes[0].amount, 1000123456);
@@ -99,4 +198,78 @@ mod tests {
         assert_eq!(tree_nodes[2].amount, 1500123456);
         assert_eq!(tree_nodes[2].locked_amount, 7123456);
     }
+
+    #[test]
+    fn test_csv_vesting_schedule() {
+        let path = PathBuf::from("./test_fixtures/test_csv_vesting.csv");
+        let entries = CsvEntry::new_from_file(&path).expect("Failed to parse CSV");
+
+        let tree_nodes: Vec<TreeNode> = entries
+            .into_iter()
+            .map(|x| TreeNode::from_csv(x, 0).unwrap())
+            .collect();
+
+        assert_eq!(
+            tree_nodes[0].vesting_schedule,
+            Some(VestingSchedule {
+                cliff_ts: 1735689600,
+                period: 2592000,
+                num_periods: 12,
+            })
+        );
+        assert_eq!(tree_nodes[1].vesting_schedule, None);
+
+        // the schedule is committed in the leaf
+        let mut node = tree_nodes[0].clone();
+        let hash = node.hash();
+        node.vesting_schedule = None;
+        assert_ne!(hash, node.hash());
+    }
+
+    #[test]
+    fn test_csv_partial_vesting_schedule() {
+        let entry = CsvEntry {
+            pubkey: Pubkey::new_unique().to_string(),
+            amount: "1".to_string(),
+            locked_amount: "1".to_string(),
+            cliff_ts: Some(1735689600),
+            period: None,
+            num_periods: Some(12),
+        };
+
+        assert!(matches!(
+            TreeNode::from_csv(entry, 0),
+            Err(MerkleTreeError::MerkleValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_abi_encode() {
+        assert_eq!(abi_encode_uint(1)[31], 1);
+        assert_eq!(abi_encode_uint(1)[..31], [0u8; 31]);
+        assert_eq!(abi_encode_int(-1), [0xffu8; 32]);
+        assert_eq!(abi_encode_int(-2)[31], 0xfe);
+        assert_eq!(abi_encode_int(i64::MAX)[..24], [0u8; 24]);
+    }
+
+    #[test]
+    fn test_keccak_leaf_hash() {
+        let node = TreeNode {
+            claimant: Pubkey::new_from_array([1u8; 32]),
+            amount: 100,
+            locked_amount: 0,
+            leaf_index: None,
+            vesting_schedule: None,
+            proof: None,
+        };
+        // keccak256(abi.encode(bytes32(0x0101..01), uint256(100), uint256(0)))
+        let mut encoded = vec![1u8; 32];
+        encoded.extend(abi_encode_uint(100));
+        encoded.extend([0u8; 32]);
+        assert_eq!(
+            node.hash_with_mode(HashMode::Keccak).to_bytes(),
+            keccak::hash(&encoded).to_bytes()
+        );
+        assert_eq!(node.hash_with_mode(HashMode::Sha256), node.hash());
+    }
 }
@@ -49,6 +49,13 @@ pub fn get_merkle_distributor_pda(
     )
 }
 
+pub fn get_campaign_pda(program_id: &Pubkey, base: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"Campaign".as_ref(), base.as_ref(), mint.as_ref()],
+        program_id,
+    )
+}
+
 pub fn get_claim_status_pda(
     program_id: &Pubkey,
     claimant: &Pubkey,
@@ -64,6 +71,68 @@ pub fn get_claim_status_pda(
     )
 }
 
+pub fn get_claim_revocation_pda(
+    program_id: &Pubkey,
+    claimant: &Pubkey,
+    distributor: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"ClaimRevocation".as_ref(),
+            claimant.to_bytes().as_ref(),
+            distributor.to_bytes().as_ref(),
+        ],
+        program_id,
+    )
+}
+
+pub fn get_claim_bitmap_pda(
+    program_id: &Pubkey,
+    distributor: &Pubkey,
+    chunk_index: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"ClaimBitmap".as_ref(),
+            distributor.to_bytes().as_ref(),
+            chunk_index.to_le_bytes().as_ref(),
+        ],
+        program_id,
+    )
+}
+
+pub fn get_claim_fee_pda(program_id: &Pubkey, distributor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"ClaimFee".as_ref(), distributor.to_bytes().as_ref()],
+        program_id,
+    )
+}
+
+pub fn get_lock_bonus_schedule_pda(program_id: &Pubkey, distributor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"LockBonusSchedule".as_ref(),
+            distributor.to_bytes().as_ref(),
+        ],
+        program_id,
+    )
+}
+
+pub fn get_claim_hook_pda(program_id: &Pubkey, distributor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"ClaimHook".as_ref(), distributor.to_bytes().as_ref()],
+        program_id,
+    )
+}
+
+/// Authority of the token accounts of `hook_program` receiving the claims of the distributor
+pub fn get_claim_hook_authority_pda(hook_program: &Pubkey, distributor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"ClaimHook".as_ref(), distributor.to_bytes().as_ref()],
+        hook_program,
+    )
+}
+
 #[derive(Debug)]
 pub struct MerkleValidationError {
     pub msg: String,
@@ -78,6 +147,8 @@ mod tests {
             claimant,
             amount,
             locked_amount,
+            leaf_index: None,
+            vesting_schedule: None,
             proof: None,
         }
     }
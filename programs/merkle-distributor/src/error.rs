@@ -59,4 +59,82 @@ pub enum ErrorCode {
     InvalidLocker,
     #[msg("Escrow is not max lock")]
     EscrowIsNotMaxLock,
+    #[msg("Unsupported token program")]
+    UnsupportedTokenProgram,
+    #[msg("Invalid claim bitmap")]
+    InvalidClaimBitmap,
+    #[msg("Leaf index is out of range")]
+    InvalidLeafIndex,
+    #[msg("Leaf is already claimed")]
+    LeafAlreadyClaimed,
+    #[msg("Invalid ed25519 instruction")]
+    InvalidEd25519Instruction,
+    #[msg("Invalid claim authorization")]
+    InvalidClaimAuthorization,
+    #[msg("Claim authorization expired")]
+    ClaimAuthorizationExpired,
+    #[msg("Invalid vesting schedule")]
+    InvalidVestingSchedule,
+    #[msg("Distributor is paused")]
+    DistributorPaused,
+    #[msg("Distributor pause state is unchanged")]
+    SamePauseState,
+    #[msg("No pending admin")]
+    NoPendingAdmin,
+    #[msg("Signer is not the pending admin")]
+    InvalidPendingAdmin,
+    #[msg("No pending clawback receiver")]
+    NoPendingClawbackReceiver,
+    #[msg("Invalid pending clawback receiver")]
+    InvalidPendingClawbackReceiver,
+    #[msg("Distributor has not been clawed back")]
+    DistributorNotClawedBack,
+    #[msg("Locked amount is not fully withdrawn")]
+    LockedAmountNotWithdrawn,
+    #[msg("Vault holds no surplus")]
+    NoSurplus,
+    #[msg("Clawback start can only be moved later")]
+    ClawbackStartNotExtended,
+    #[msg("Claim has been revoked")]
+    ClaimRevoked,
+    #[msg("Distributor is not revocable")]
+    DistributorNotRevocable,
+    #[msg("Vesting has already been revoked")]
+    VestingAlreadyRevoked,
+    #[msg("No unvested amount to revoke")]
+    NoUnvestedAmount,
+    #[msg("New owner is the current owner of the claim status")]
+    SameClaimStatusOwner,
+    #[msg("Claim voucher expired")]
+    ClaimVoucherExpired,
+    #[msg("Claim voucher is not for this distributor or claimant")]
+    InvalidClaimVoucher,
+    #[msg("Claim exceeds the voucher max amount")]
+    ClaimVoucherAmountExceeded,
+    #[msg("Campaign account is missing or invalid")]
+    InvalidCampaign,
+    #[msg("Distributor clawback starts after the campaign clawback")]
+    ClawbackAfterCampaign,
+    #[msg("Batch claim accounts do not match the claims")]
+    InvalidBatchClaim,
+    #[msg("Early exit penalty exceeds 10000 bps")]
+    InvalidEarlyExitPenalty,
+    #[msg("Distributor does not allow early exits")]
+    EarlyExitDisabled,
+    #[msg("No unvested amount to exit early")]
+    NoUnvestedAmountToExit,
+    #[msg("Invalid bonus curve")]
+    InvalidBonusCurve,
+    #[msg("Invalid claim fee")]
+    InvalidClaimFee,
+    #[msg("Claim fee account or fee receiver is missing or invalid")]
+    InvalidClaimFeeAccount,
+    #[msg("Claim instruction does not collect the claim fee of the distributor")]
+    ClaimFeeNotCollected,
+    #[msg("Hook program does not match the distributor")]
+    InvalidHookProgram,
+    #[msg("Lock bonus schedule or its account is invalid")]
+    InvalidLockBonusSchedule,
+    #[msg("Not supported for distributors drawing from a campaign vault")]
+    DistributorInCampaign,
 }
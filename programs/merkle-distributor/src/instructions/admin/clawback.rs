@@ -1,43 +1,53 @@
 // Instruction to clawback funds once they have expired
 
 use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
-use anchor_spl::{
-    token,
-    token::{Token, TokenAccount},
-};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
 
-use crate::{error::ErrorCode, state::merkle_distributor::MerkleDistributor};
+use crate::{
+    error::ErrorCode,
+    state::{claimed_event::ClawbackEvent, merkle_distributor::MerkleDistributor},
+};
 
 /// [merkle_distributor::clawback] accounts.
 #[derive(Accounts)]
 pub struct Clawback<'info> {
     /// The [MerkleDistributor].
-    #[account(mut, has_one = clawback_receiver)]
+    #[account(
+        mut,
+        has_one = clawback_receiver,
+        constraint = !distributor.load()?.in_campaign() @ ErrorCode::DistributorInCampaign,
+    )]
     pub distributor: AccountLoader<'info, MerkleDistributor>,
 
     /// Distributor ATA containing the tokens to distribute.
     #[account(
         mut,
-        associated_token::mint = distributor.load()?.mint,
-        associated_token::authority = distributor.key(),
+        token::mint = mint,
+        token::authority = distributor,
         address = distributor.load()?.token_vault
     )]
-    pub from: Account<'info, TokenAccount>,
+    pub from: InterfaceAccount<'info, TokenAccount>,
 
     /// The Clawback token account.
-    #[account(mut)]
-    pub clawback_receiver: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = mint)]
+    pub clawback_receiver: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.load()?.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
 
-    /// SPL [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 /// Claws back unclaimed tokens by:
 /// 1. Checking that the lockup has expired
 /// 2. Transferring remaining funds from the vault to the clawback receiver
 /// 3. Marking the distributor as clawed back
+/// Distributors of a campaign are clawed back with the campaign.
 /// CHECK:
 ///     1. The distributor has not already been clawed back
+///     2. The distributor is not part of a campaign
 #[allow(clippy::result_large_err)]
 pub fn handle_clawback(ctx: Context<Clawback>) -> Result<()> {
     let mut distributor = ctx.accounts.distributor.load_mut()?;
@@ -55,21 +65,30 @@ pub fn handle_clawback(ctx: Context<Clawback>) -> Result<()> {
 
     distributor.set_clawed_back();
 
+    emit!(ClawbackEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        clawback_receiver: distributor.clawback_receiver,
+        amount: ctx.accounts.from.amount,
+    });
+
     let signer = distributor.signer();
     drop(distributor);
     let seeds = signer.seeds();
 
-    token::transfer(
+    token_interface::transfer_checked(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
+            token_interface::TransferChecked {
                 from: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.clawback_receiver.to_account_info(),
                 authority: ctx.accounts.distributor.to_account_info(),
             },
         )
         .with_signer(&[&seeds[..]]),
         ctx.accounts.from.amount,
+        ctx.accounts.mint.decimals,
     )?;
 
     Ok(())
@@ -0,0 +1,81 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    state::{campaign::Campaign, claimed_event::CampaignClawbackEvent},
+};
+
+/// [merkle_distributor::clawback_campaign] accounts.
+#[derive(Accounts)]
+pub struct ClawbackCampaign<'info> {
+    /// The [Campaign].
+    #[account(mut, has_one = clawback_receiver, has_one = mint)]
+    pub campaign: AccountLoader<'info, Campaign>,
+
+    /// Campaign ATA containing the tokens of every distributor.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = campaign,
+        address = campaign.load()?.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// The Clawback token account.
+    #[account(mut, token::mint = mint)]
+    pub clawback_receiver: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint to distribute.
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Claws back the unclaimed tokens of every distributor of a [Campaign] at once.
+/// Claims of the campaign distributors fail afterwards.
+/// CHECK:
+///     1. The campaign has not already been clawed back
+///     2. The campaign clawback has started
+#[allow(clippy::result_large_err)]
+pub fn handle_clawback_campaign(ctx: Context<ClawbackCampaign>) -> Result<()> {
+    let mut campaign = ctx.accounts.campaign.load_mut()?;
+
+    require!(!campaign.clawed_back(), ErrorCode::ClawbackAlreadyClaimed);
+
+    let curr_ts = Clock::get()?.unix_timestamp;
+    require!(
+        curr_ts >= campaign.clawback_start_ts,
+        ErrorCode::ClawbackBeforeStart
+    );
+
+    campaign.clawed_back = 1;
+
+    emit!(CampaignClawbackEvent {
+        campaign: ctx.accounts.campaign.key(),
+        clawback_receiver: campaign.clawback_receiver,
+        amount: ctx.accounts.from.amount,
+    });
+
+    let signer = campaign.signer();
+    drop(campaign);
+    let seeds = signer.seeds();
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.clawback_receiver.to_account_info(),
+                authority: ctx.accounts.campaign.to_account_info(),
+            },
+        )
+        .with_signer(&[&seeds[..]]),
+        ctx.accounts.from.amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    Ok(())
+}
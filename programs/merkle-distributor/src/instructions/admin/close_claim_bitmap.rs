@@ -0,0 +1,57 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{campaign::Campaign, claim_bitmap::ClaimBitmap, claimed_event::CloseClaimBitmapEvent},
+    utils::distributor_claims_ended,
+};
+
+/// [merkle_distributor::close_claim_bitmap] accounts.
+#[derive(Accounts)]
+pub struct CloseClaimBitmap<'info> {
+    /// CHECK: The [crate::state::merkle_distributor::MerkleDistributor], clawed back or already closed
+    #[account(
+        constraint = distributor_claims_ended(&distributor, &campaign)? @ ErrorCode::DistributorNotClawedBack,
+    )]
+    pub distributor: UncheckedAccount<'info>,
+
+    /// Claim bitmap PDA
+    #[account(
+        mut,
+        has_one = distributor,
+        has_one = admin @ ErrorCode::Unauthorized,
+        close = admin,
+    )]
+    pub claim_bitmap: AccountLoader<'info, ClaimBitmap>,
+
+    /// Admin wallet who paid the rent of the bitmap, receives it back.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// The [Campaign] of the distributor, when it is part of one.
+    pub campaign: Option<AccountLoader<'info, Campaign>>,
+}
+
+/// Closes a [ClaimBitmap] chunk and returns its rent to the admin who created it.
+/// Only allowed once the distributor is clawed back, so the leaves can't be claimed twice.
+/// CHECK:
+///     1. The distributor or its campaign has been clawed back, or the distributor is closed
+///     2. The signer is the admin who created the bitmap
+#[allow(clippy::result_large_err)]
+pub fn handle_close_claim_bitmap(ctx: Context<CloseClaimBitmap>) -> Result<()> {
+    let claim_bitmap = ctx.accounts.claim_bitmap.load()?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "closed claim bitmap chunk {} of distributor {}",
+        claim_bitmap.chunk_index,
+        claim_bitmap.distributor
+    );
+
+    emit!(CloseClaimBitmapEvent {
+        distributor: claim_bitmap.distributor,
+        chunk_index: claim_bitmap.chunk_index,
+    });
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use anchor_lang::{account, context::Context, prelude::*, Accounts, Key, ToAccountInfo};
 
-use crate::{error::ErrorCode, state::claim_status::ClaimStatus};
+use crate::{
+    error::ErrorCode,
+    state::{claim_status::ClaimStatus, claimed_event::CloseClaimStatusEvent},
+};
 
 // Accounts for [merkle_distributor::close_claim_status].
 #[derive(Accounts)]
@@ -22,6 +25,10 @@ pub struct CloseClaimStatus<'info> {
 }
 
 #[allow(clippy::result_large_err)]
-pub fn handle_close_status(_ctx: Context<CloseClaimStatus>) -> Result<()> {
+pub fn handle_close_status(ctx: Context<CloseClaimStatus>) -> Result<()> {
+    emit!(CloseClaimStatusEvent {
+        distributor: ctx.accounts.claim_status.load()?.distributor,
+        claimant: ctx.accounts.claimant.key(),
+    });
     Ok(())
 }
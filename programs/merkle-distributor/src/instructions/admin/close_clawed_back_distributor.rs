@@ -0,0 +1,132 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, ToAccountInfo};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        campaign::Campaign, claimed_event::CloseDistributorEvent,
+        merkle_distributor::MerkleDistributor,
+    },
+    utils::{distributor_clawed_back, harvest_withheld_fees},
+};
+
+/// Accounts for [merkle_distributor::close_clawed_back_distributor].
+#[derive(Accounts)]
+pub struct CloseClawedBackDistributor<'info> {
+    /// [MerkleDistributor].
+    #[account(
+        mut,
+        has_one = admin @ ErrorCode::Unauthorized,
+        has_one = token_vault,
+        has_one = mint,
+        has_one = clawback_receiver,
+        constraint = distributor_clawed_back(&*distributor.load()?, &distributor.key(), &campaign)? @ ErrorCode::DistributorNotClawedBack,
+        close = admin
+    )]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Distributor ATA, emptied by the clawback. The vault of a campaign is shared and kept open.
+    #[account(
+        mut,
+        token::authority = distributor.load()?.token_vault_authority(&distributor.key())?,
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// The Clawback token account, receives any tokens sent to the vault after the clawback
+    #[account(mut, token::mint = mint)]
+    pub clawback_receiver: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint of the distributor, receives the transfer fees withheld on the vault
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Admin wallet, receives the rent of both accounts
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [Campaign] of the distributor, when it is part of one.
+    pub campaign: Option<AccountLoader<'info, Campaign>>,
+}
+
+/// Closes the token vault and the distributor once clawed back, returning the rent to the admin.
+/// Tokens sent to the vault after the clawback are transferred to the clawback receiver, and
+/// transfer fees withheld on a Token-2022 vault are harvested to the mint, before closing it.
+/// The token vault of a campaign distributor is shared with the rest of the campaign and left open.
+/// Claim statuses and claim bitmaps can still be closed against the closed distributor.
+/// CHECK:
+///     1. The distributor or its campaign has been clawed back
+#[allow(clippy::result_large_err)]
+pub fn handle_close_clawed_back_distributor(
+    ctx: Context<CloseClawedBackDistributor>,
+) -> Result<()> {
+    let distributor = ctx.accounts.distributor.load()?;
+    let in_campaign = distributor.in_campaign();
+
+    // nothing is owed anymore, whatever reached the vault since the clawback goes back too
+    let leftover_amount = if in_campaign {
+        0
+    } else {
+        ctx.accounts.token_vault.amount
+    };
+
+    emit!(CloseDistributorEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        amount: leftover_amount,
+    });
+
+    let signer = distributor.signer();
+    drop(distributor);
+    let seeds = signer.seeds();
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "closed clawed back distributor {}, leftover {}",
+        ctx.accounts.distributor.key(),
+        leftover_amount
+    );
+
+    if in_campaign {
+        return Ok(());
+    }
+
+    if leftover_amount > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.token_vault.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.clawback_receiver.to_account_info(),
+                    authority: ctx.accounts.distributor.to_account_info(),
+                },
+            )
+            .with_signer(&[&seeds[..]]),
+            leftover_amount,
+            ctx.accounts.mint.decimals,
+        )?;
+    }
+
+    harvest_withheld_fees(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_vault,
+    )?;
+
+    token_interface::close_account(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::CloseAccount {
+                account: ctx.accounts.token_vault.to_account_info(),
+                destination: ctx.accounts.admin.to_account_info(),
+                authority: ctx.accounts.distributor.to_account_info(),
+            },
+        )
+        .with_signer(&[&seeds[..]]),
+    )?;
+
+    Ok(())
+}
@@ -1,7 +1,10 @@
 use anchor_lang::{account, context::Context, prelude::*, Accounts, Key, ToAccountInfo};
-use anchor_spl::token::{self, Token, TokenAccount};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
 
-use crate::{error::ErrorCode, state::merkle_distributor::MerkleDistributor};
+use crate::{
+    error::ErrorCode,
+    state::{claimed_event::CloseDistributorEvent, merkle_distributor::MerkleDistributor},
+};
 
 // Accounts for [merkle_distributor::close_distributor].
 #[derive(Accounts)]
@@ -18,7 +21,7 @@ pub struct CloseDistributor<'info> {
 
     /// Clawback receiver token account
     #[account(mut)]
-    pub token_vault: Account<'info, TokenAccount>,
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
 
     /// Admin wallet, responsible for creating the distributor and paying for the transaction.
     /// Also has the authority to set the clawback receiver and change itself.
@@ -26,31 +29,44 @@ pub struct CloseDistributor<'info> {
     pub admin: Signer<'info>,
 
     /// account receive token back
-    #[account(mut)]
-    pub destination_token_account: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = mint)]
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.load()?.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
 
-    /// The [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 #[allow(clippy::result_large_err)]
 pub fn handle_close_distributor(ctx: Context<CloseDistributor>) -> Result<()> {
     let distributor = ctx.accounts.distributor.load()?;
+
+    emit!(CloseDistributorEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        amount: ctx.accounts.token_vault.amount,
+    });
+
     let signer = distributor.signer();
     drop(distributor);
     let seeds = signer.seeds();
 
-    token::transfer(
+    token_interface::transfer_checked(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
+            token_interface::TransferChecked {
                 from: ctx.accounts.token_vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.destination_token_account.to_account_info(),
                 authority: ctx.accounts.distributor.to_account_info(),
             },
         )
         .with_signer(&[&seeds[..]]),
         ctx.accounts.token_vault.amount,
+        ctx.accounts.mint.decimals,
     )?;
     Ok(())
 }
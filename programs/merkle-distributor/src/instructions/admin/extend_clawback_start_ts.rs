@@ -0,0 +1,62 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{claimed_event::ClawbackStartExtendedEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// Accounts for [merkle_distributor::extend_clawback_start_ts].
+#[derive(Accounts)]
+pub struct ExtendClawbackStartTs<'info> {
+    /// [MerkleDistributor].
+    #[account(
+        mut,
+        has_one = admin @ ErrorCode::Unauthorized,
+        constraint = !distributor.load()?.in_campaign() @ ErrorCode::DistributorInCampaign,
+    )]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Admin signer
+    pub admin: Signer<'info>,
+}
+
+/// Moves clawback_start_ts later, extending the claim window
+/// CHECK:
+///     1. The distributor has not been clawed back
+///     2. The new clawback start is after the current one
+///     3. The distributor is not part of a campaign, clawed back with its campaign
+#[allow(clippy::result_large_err)]
+pub fn handle_extend_clawback_start_ts(
+    ctx: Context<ExtendClawbackStartTs>,
+    clawback_start_ts: i64,
+) -> Result<()> {
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+
+    require!(
+        !distributor.clawed_back(),
+        ErrorCode::ClawbackAlreadyClaimed
+    );
+    require!(
+        clawback_start_ts > distributor.clawback_start_ts,
+        ErrorCode::ClawbackStartNotExtended
+    );
+
+    let old_clawback_start_ts = distributor.clawback_start_ts;
+    distributor.clawback_start_ts = clawback_start_ts;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "extended clawback start from {} to {}",
+        old_clawback_start_ts,
+        clawback_start_ts
+    );
+
+    emit!(ClawbackStartExtendedEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        old_clawback_start_ts,
+        new_clawback_start_ts: clawback_start_ts,
+    });
+
+    Ok(())
+}
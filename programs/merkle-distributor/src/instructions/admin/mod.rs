@@ -15,3 +15,29 @@ pub use set_admin::*;
 pub use set_clawback_receiver::*;
 pub mod set_operator;
 pub use set_operator::*;
+pub mod new_claim_bitmap;
+pub use new_claim_bitmap::*;
+pub mod close_claim_bitmap;
+pub use close_claim_bitmap::*;
+pub mod set_pause;
+pub use set_pause::*;
+pub mod transfer_admin;
+pub use transfer_admin::*;
+pub mod transfer_clawback_receiver;
+pub use transfer_clawback_receiver::*;
+pub mod close_clawed_back_distributor;
+pub use close_clawed_back_distributor::*;
+pub mod sweep_surplus;
+pub use sweep_surplus::*;
+pub mod extend_clawback_start_ts;
+pub use extend_clawback_start_ts::*;
+pub mod revoke_claim;
+pub use revoke_claim::*;
+pub mod revoke_vesting;
+pub use revoke_vesting::*;
+pub mod new_campaign;
+pub use new_campaign::*;
+pub mod new_campaign_distributor;
+pub use new_campaign_distributor::*;
+pub mod clawback_campaign;
+pub use clawback_campaign::*;
@@ -0,0 +1,102 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, ToAccountInfo};
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{campaign::Campaign, claimed_event::NewCampaignEvent},
+};
+
+/// Accounts for [merkle_distributor::new_campaign].
+#[derive(Accounts)]
+pub struct NewCampaign<'info> {
+    /// [Campaign].
+    #[account(
+        init,
+        seeds = [
+            b"Campaign".as_ref(),
+            base.key().to_bytes().as_ref(),
+            mint.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + Campaign::INIT_SPACE,
+        payer = admin
+    )]
+    pub campaign: AccountLoader<'info, Campaign>,
+
+    /// Base key of the campaign and of its distributors.
+    pub base: Signer<'info>,
+
+    /// Clawback receiver token account
+    #[account(mut, token::mint = mint)]
+    pub clawback_receiver: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint to distribute.
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Token vault shared by the distributors
+    /// Should create previously
+    #[account(
+        token::mint = mint,
+        token::authority = campaign,
+        address = get_associated_token_address_with_program_id(
+            &campaign.key(),
+            &mint.key(),
+            &token_program.key(),
+        ),
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Admin wallet, responsible for creating the campaign and its distributors.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Creates a new [Campaign], owning a single token vault for the distributors added
+/// with new_campaign_distributor. The token_vault should be seeded with the
+/// max_total_claim of the campaign once its distributors are created.
+/// CHECK:
+///     1. The clawback start is in the future
+#[allow(clippy::result_large_err)]
+pub fn handle_new_campaign(ctx: Context<NewCampaign>, clawback_start_ts: i64) -> Result<()> {
+    require!(
+        clawback_start_ts > Clock::get()?.unix_timestamp,
+        ErrorCode::TimestampsNotInFuture
+    );
+
+    let mut campaign = ctx.accounts.campaign.load_init()?;
+
+    campaign.bump = *ctx.bumps.get("campaign").unwrap();
+    campaign.base = ctx.accounts.base.key();
+    campaign.mint = ctx.accounts.mint.key();
+    campaign.token_vault = ctx.accounts.token_vault.key();
+    campaign.admin = ctx.accounts.admin.key();
+    campaign.clawback_receiver = ctx.accounts.clawback_receiver.key();
+    campaign.clawback_start_ts = clawback_start_ts;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "New campaign created with mint={}, vault={}, clawback_start: {}, clawback_receiver: {}",
+        campaign.mint,
+        campaign.token_vault,
+        campaign.clawback_start_ts,
+        campaign.clawback_receiver,
+    );
+
+    emit!(NewCampaignEvent {
+        campaign: ctx.accounts.campaign.key(),
+        mint: campaign.mint,
+        admin: campaign.admin,
+        clawback_start_ts: campaign.clawback_start_ts,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key};
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    error::ErrorCode,
+    instructions::NewDistributorParams,
+    state::{
+        campaign::Campaign,
+        claim_fee::ClaimFee,
+        claim_hook::ClaimHook,
+        claimed_event::NewDistributorEvent,
+        merkle_distributor::{ClaimType, MerkleDistributor},
+    },
+};
+
+/// Accounts for [merkle_distributor::new_campaign_distributor].
+#[derive(Accounts)]
+#[instruction(params: NewDistributorParams)]
+pub struct NewCampaignDistributor<'info> {
+    /// The [Campaign] owning the token vault.
+    #[account(
+        mut,
+        has_one = admin @ ErrorCode::Unauthorized,
+        has_one = base,
+        has_one = mint,
+    )]
+    pub campaign: AccountLoader<'info, Campaign>,
+
+    /// [MerkleDistributor].
+    #[account(
+        init,
+        seeds = [
+            b"MerkleDistributor".as_ref(),
+            base.key().to_bytes().as_ref(),
+            mint.key().to_bytes().as_ref(),
+            params.version.to_le_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + MerkleDistributor::INIT_SPACE,
+        payer = admin
+    )]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Base key of the campaign.
+    pub base: Signer<'info>,
+
+    /// The mint to distribute.
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Admin wallet of the campaign, also the admin of the distributor.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+
+    /// The [ClaimFee] of the distributor, when the params set one.
+    #[account(
+        init,
+        seeds = [
+            b"ClaimFee".as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + ClaimFee::INIT_SPACE,
+        payer = admin
+    )]
+    pub claim_fee: Option<AccountLoader<'info, ClaimFee>>,
+
+    /// The [ClaimHook] of the distributor, when the params set a hook program.
+    #[account(
+        init,
+        seeds = [
+            b"ClaimHook".as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + ClaimHook::INIT_SPACE,
+        payer = admin
+    )]
+    pub claim_hook: Option<AccountLoader<'info, ClaimHook>>,
+}
+
+/// Creates a new [MerkleDistributor] drawing from the token vault of a [Campaign].
+/// The max_total_claim of the distributor is added to the one of the campaign.
+/// CHECK:
+///     1. The params are valid, as in new_distributor
+///     2. The claim type is not a staking one, and the distributor is not revocable
+///     3. The distributor clawback does not start after the campaign clawback
+#[allow(clippy::result_large_err)]
+pub fn handle_new_campaign_distributor(
+    ctx: Context<NewCampaignDistributor>,
+    params: &NewDistributorParams,
+) -> Result<()> {
+    params.validate()?;
+
+    let claim_type =
+        ClaimType::try_from(params.claim_type).map_err(|_| ErrorCode::TypeCastedError)?;
+    require!(
+        !matches!(
+            claim_type,
+            ClaimType::PermissionlessWithStaking | ClaimType::PermissionedWithStaking
+        ),
+        ErrorCode::InvalidClaimType
+    );
+    // revoke_vesting pays out of the distributor own vault
+    require!(!params.revocable, ErrorCode::DistributorInCampaign);
+
+    let mut campaign = ctx.accounts.campaign.load_mut()?;
+    require!(!campaign.clawed_back(), ErrorCode::ClawbackAlreadyClaimed);
+    require!(
+        params.clawback_start_ts <= campaign.clawback_start_ts,
+        ErrorCode::ClawbackAfterCampaign
+    );
+
+    let mut distributor = ctx.accounts.distributor.load_init()?;
+
+    distributor.bump = *ctx.bumps.get("distributor").unwrap();
+    distributor.mint = campaign.mint;
+    distributor.token_vault = campaign.token_vault;
+    distributor.clawback_receiver = campaign.clawback_receiver;
+    distributor.admin = campaign.admin;
+    distributor.base = campaign.base;
+    distributor.in_campaign = 1;
+    distributor.campaign_bump = campaign.bump;
+    params.init_distributor(&mut distributor)?;
+    params.init_claim_fee(
+        &mut distributor,
+        ctx.accounts.distributor.key(),
+        &ctx.accounts.claim_fee,
+        ctx.bumps.get("claim_fee").copied(),
+    )?;
+    params.init_claim_hook(
+        ctx.accounts.distributor.key(),
+        &ctx.accounts.claim_hook,
+        ctx.bumps.get("claim_hook").copied(),
+    )?;
+
+    campaign.max_total_claim = campaign
+        .max_total_claim
+        .checked_add(distributor.max_total_claim)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    campaign.num_distributors = campaign
+        .num_distributors
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "New campaign distributor created with version = {}, max_total_claim={}, campaign max_total_claim={}",
+        distributor.version,
+        distributor.max_total_claim,
+        campaign.max_total_claim,
+    );
+
+    emit!(NewDistributorEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        mint: distributor.mint,
+        admin: distributor.admin,
+        root: distributor.root,
+        max_total_claim: distributor.max_total_claim,
+        max_num_nodes: distributor.max_num_nodes,
+        start_ts: distributor.start_ts,
+        end_ts: distributor.end_ts,
+        clawback_start_ts: distributor.clawback_start_ts,
+        activation_point: distributor.activation_point,
+        activation_type: distributor.activation_type,
+        claim_type: distributor.claim_type,
+        total_bonus: distributor.airdrop_bonus.total_bonus,
+        revocable: distributor.revocable(),
+        early_exit_penalty_bps: distributor.early_exit_penalty_bps,
+        early_exit_penalty_to_clawback: distributor.early_exit_penalty_to_clawback(),
+        bonus_curve_type: distributor.airdrop_bonus.curve_type,
+        bonus_num_steps: distributor.airdrop_bonus.num_steps,
+        has_claim_fee: distributor.has_claim_fee(),
+        has_lock_bonus_schedule: distributor.has_lock_bonus_schedule(),
+    });
+
+    Ok(())
+}
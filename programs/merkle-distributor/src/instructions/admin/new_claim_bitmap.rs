@@ -0,0 +1,79 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claim_bitmap::{ClaimBitmap, LEAVES_PER_CLAIM_BITMAP},
+        claimed_event::NewClaimBitmapEvent,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::new_claim_bitmap] accounts.
+#[derive(Accounts)]
+#[instruction(chunk_index: u64)]
+pub struct NewClaimBitmap<'info> {
+    /// The [MerkleDistributor].
+    #[account(has_one = admin @ ErrorCode::Unauthorized)]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Claim bitmap PDA
+    #[account(
+        init,
+        seeds = [
+            b"ClaimBitmap".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            chunk_index.to_le_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + ClaimBitmap::INIT_SPACE,
+        payer = admin,
+    )]
+    pub claim_bitmap: AccountLoader<'info, ClaimBitmap>,
+
+    /// Admin wallet, pays the rent of the bitmap so claimants don't have to.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a [ClaimBitmap] chunk for a bitmap [MerkleDistributor].
+/// CHECK:
+///     1. The distributor claim type is PermissionlessWithBitmap
+///     2. The chunk covers at least one leaf of the tree
+#[allow(clippy::result_large_err)]
+pub fn handle_new_claim_bitmap(ctx: Context<NewClaimBitmap>, chunk_index: u64) -> Result<()> {
+    let distributor = ctx.accounts.distributor.load()?;
+    distributor.authorize_claim_with_bitmap()?;
+
+    let first_leaf_index = chunk_index
+        .checked_mul(LEAVES_PER_CLAIM_BITMAP)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    require!(
+        first_leaf_index < distributor.max_num_nodes,
+        ErrorCode::InvalidClaimBitmap
+    );
+
+    let mut claim_bitmap = ctx.accounts.claim_bitmap.load_init()?;
+    claim_bitmap.distributor = ctx.accounts.distributor.key();
+    claim_bitmap.admin = ctx.accounts.admin.key();
+    claim_bitmap.chunk_index = chunk_index;
+    claim_bitmap.bump = *ctx.bumps.get("claim_bitmap").unwrap();
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Created claim bitmap chunk {} for distributor {}",
+        chunk_index,
+        claim_bitmap.distributor
+    );
+
+    emit!(NewClaimBitmapEvent {
+        distributor: claim_bitmap.distributor,
+        version: distributor.version,
+        chunk_index,
+    });
+
+    Ok(())
+}
@@ -1,11 +1,21 @@
 use crate::error::ErrorCode::ArithmeticError;
-use crate::state::merkle_distributor::{ActivationType, ClaimType};
+use crate::state::claimed_event::NewDistributorEvent;
+use crate::state::merkle_distributor::{ActivationType, BonusCurveType, ClaimType, MAX_BPS};
 use crate::{
     error::ErrorCode,
-    state::merkle_distributor::{AirdropBonus, MerkleDistributor},
+    state::{
+        claim_fee::ClaimFee,
+        claim_hook::ClaimHook,
+        lock_bonus_schedule::{LockBonusSchedule, MAX_LOCK_BONUS_TIERS},
+        merkle_distributor::{AirdropBonus, MerkleDistributor},
+    },
 };
 use anchor_lang::{account, context::Context, prelude::*, Accounts, Key, ToAccountInfo};
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token::Token,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
 
 #[cfg(feature = "localnet")]
 const SECONDS_PER_DAY: i64 = 0;
@@ -25,11 +35,35 @@ pub struct NewDistributorParams {
     pub activation_point: u64, // can be slot or timestamp
     pub activation_type: u8,
     pub closable: bool,
+    pub revocable: bool,
+    pub early_exit_penalty_bps: u16,
+    pub early_exit_penalty_to_clawback: bool,
     pub total_bonus: u64,
     pub bonus_vesting_duration: u64,
+    pub bonus_curve_type: u8,
+    pub bonus_num_steps: u16,
     pub claim_type: u8,
     pub operator: Pubkey,
     pub locker: Pubkey,
+    pub claim_fee: Option<ClaimFeeParams>,
+    pub hook_program: Option<Pubkey>,
+    #[max_len(8)]
+    pub lock_bonus_schedule: Vec<LockBonusTierParams>,
+}
+
+/// Fee charged on every claim, either `lamports` or `bps` of the claimed tokens
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy)]
+pub struct ClaimFeeParams {
+    pub fee_receiver: Pubkey,
+    pub lamports: u64,
+    pub bps: u16,
+}
+
+/// Share of the bonus of claim and stake escrows locked for at least `min_lock_duration` seconds
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy)]
+pub struct LockBonusTierParams {
+    pub min_lock_duration: u64,
+    pub bonus_bps: u16,
 }
 
 impl NewDistributorParams {
@@ -40,11 +74,123 @@ impl NewDistributorParams {
             .ok_or(ArithmeticError)?;
         Ok(max_total_claim)
     }
+    /// Seeds the fields of a new [MerkleDistributor] taken from the params
+    pub fn init_distributor(&self, distributor: &mut MerkleDistributor) -> Result<()> {
+        distributor.version = self.version;
+        distributor.root = self.root;
+        distributor.max_total_claim = self.get_max_total_claim()?;
+        distributor.max_num_nodes = self.max_num_nodes;
+        distributor.total_amount_claimed = 0;
+        distributor.num_nodes_claimed = 0;
+        distributor.start_ts = self.start_vesting_ts;
+        distributor.end_ts = self.end_vesting_ts;
+        distributor.clawback_start_ts = self.clawback_start_ts;
+        distributor.clawed_back = 0;
+        if self.closable {
+            distributor.closable = 1;
+        }
+        if self.revocable {
+            distributor.revocable = 1;
+        }
+        distributor.early_exit_penalty_bps = self.early_exit_penalty_bps;
+        if self.early_exit_penalty_to_clawback {
+            distributor.early_exit_penalty_to_clawback = 1;
+        }
+        distributor.airdrop_bonus = self.get_airdrop_bonus();
+        distributor.claim_type = self.claim_type;
+        distributor.activation_point = self.activation_point;
+        distributor.activation_type = self.activation_type;
+        distributor.operator = self.operator;
+        distributor.locker = self.locker;
+        Ok(())
+    }
+
+    /// Seeds the [ClaimFee] of a new [MerkleDistributor] when the params set one
+    pub fn init_claim_fee(
+        &self,
+        distributor: &mut MerkleDistributor,
+        distributor_key: Pubkey,
+        claim_fee: &Option<AccountLoader<ClaimFee>>,
+        bump: Option<u8>,
+    ) -> Result<()> {
+        let Some(params) = self.claim_fee else {
+            require!(claim_fee.is_none(), ErrorCode::InvalidClaimFeeAccount);
+            return Ok(());
+        };
+        let claim_fee = claim_fee
+            .as_ref()
+            .ok_or(ErrorCode::InvalidClaimFeeAccount)?;
+        let mut claim_fee = claim_fee.load_init()?;
+        claim_fee.distributor = distributor_key;
+        claim_fee.fee_receiver = params.fee_receiver;
+        claim_fee.lamports = params.lamports;
+        claim_fee.bps = params.bps;
+        claim_fee.bump = bump.ok_or(ErrorCode::InvalidClaimFeeAccount)?;
+        distributor.has_claim_fee = 1;
+        Ok(())
+    }
+
+    /// Seeds the [ClaimHook] of a new [MerkleDistributor] when the params set a hook program
+    pub fn init_claim_hook(
+        &self,
+        distributor_key: Pubkey,
+        claim_hook: &Option<AccountLoader<ClaimHook>>,
+        bump: Option<u8>,
+    ) -> Result<()> {
+        let Some(hook_program) = self.hook_program else {
+            require!(claim_hook.is_none(), ErrorCode::InvalidHookProgram);
+            return Ok(());
+        };
+        let claim_hook = claim_hook.as_ref().ok_or(ErrorCode::InvalidHookProgram)?;
+        let mut claim_hook = claim_hook.load_init()?;
+        claim_hook.distributor = distributor_key;
+        claim_hook.hook_program = hook_program;
+        claim_hook.bump = bump.ok_or(ErrorCode::InvalidHookProgram)?;
+        Ok(())
+    }
+
+    /// Seeds the [LockBonusSchedule] of a new [MerkleDistributor] when the params set tiers
+    pub fn init_lock_bonus_schedule(
+        &self,
+        distributor: &mut MerkleDistributor,
+        distributor_key: Pubkey,
+        lock_bonus_schedule: &Option<AccountLoader<LockBonusSchedule>>,
+        bump: Option<u8>,
+    ) -> Result<()> {
+        if self.lock_bonus_schedule.is_empty() {
+            require!(
+                lock_bonus_schedule.is_none(),
+                ErrorCode::InvalidLockBonusSchedule
+            );
+            return Ok(());
+        }
+        let lock_bonus_schedule = lock_bonus_schedule
+            .as_ref()
+            .ok_or(ErrorCode::InvalidLockBonusSchedule)?;
+        let mut lock_bonus_schedule = lock_bonus_schedule.load_init()?;
+        lock_bonus_schedule.distributor = distributor_key;
+        for (tier, params) in lock_bonus_schedule
+            .tiers
+            .iter_mut()
+            .zip(self.lock_bonus_schedule.iter())
+        {
+            tier.min_lock_duration = params.min_lock_duration;
+            tier.bonus_bps = params.bonus_bps;
+        }
+        lock_bonus_schedule.num_tiers = self.lock_bonus_schedule.len() as u8;
+        lock_bonus_schedule.bump = bump.ok_or(ErrorCode::InvalidLockBonusSchedule)?;
+        distributor.has_lock_bonus_schedule = 1;
+        Ok(())
+    }
+
     fn get_airdrop_bonus(&self) -> AirdropBonus {
         AirdropBonus {
             total_bonus: self.total_bonus,
             vesting_duration: self.bonus_vesting_duration,
             total_claimed_bonus: 0,
+            curve_type: self.bonus_curve_type,
+            num_steps: self.bonus_num_steps,
+            ..Default::default()
         }
     }
 
@@ -64,6 +210,39 @@ impl NewDistributorParams {
             ErrorCode::ClawbackDuringVesting
         );
 
+        require!(
+            u128::from(self.early_exit_penalty_bps) <= MAX_BPS,
+            ErrorCode::InvalidEarlyExitPenalty
+        );
+
+        match BonusCurveType::try_from(self.bonus_curve_type)
+            .map_err(|_| ErrorCode::InvalidBonusCurve)?
+        {
+            BonusCurveType::Linear => {}
+            BonusCurveType::Step => {
+                require!(self.bonus_num_steps > 0, ErrorCode::InvalidBonusCurve);
+            }
+            BonusCurveType::ExponentialDecay => {
+                // the vesting duration is the half-life of the bonus
+                require!(
+                    self.bonus_vesting_duration > 0,
+                    ErrorCode::InvalidBonusCurve
+                );
+            }
+        }
+
+        if let Some(claim_fee) = self.claim_fee {
+            // either a lamport fee or a token fee
+            require!(
+                (claim_fee.lamports > 0) != (claim_fee.bps > 0),
+                ErrorCode::InvalidClaimFee
+            );
+            require!(
+                u128::from(claim_fee.bps) <= MAX_BPS,
+                ErrorCode::InvalidClaimFee
+            );
+        }
+
         // New distributor parameters must all be set in the future
         require!(
             self.start_vesting_ts > curr_ts,
@@ -104,6 +283,58 @@ impl NewDistributorParams {
             ClaimType::PermissionedWithStaking => {
                 require!(self.locker != Pubkey::default(), ErrorCode::InvalidLocker);
             }
+            ClaimType::PermissionlessWithHook => {
+                require!(self.locker == Pubkey::default(), ErrorCode::InvalidLocker);
+                require!(
+                    self.hook_program
+                        .is_some_and(|hook_program| hook_program != Pubkey::default()),
+                    ErrorCode::InvalidHookProgram
+                );
+                require!(
+                    self.operator == Pubkey::default(),
+                    ErrorCode::InvalidOperator
+                );
+            }
+            ClaimType::PermissionlessWithBitmap | ClaimType::PermissionlessPush => {
+                require!(self.locker == Pubkey::default(), ErrorCode::InvalidLocker);
+                require!(
+                    self.operator == Pubkey::default(),
+                    ErrorCode::InvalidOperator
+                );
+            }
+        }
+
+        if claim_type_enum != ClaimType::PermissionlessWithHook {
+            require!(self.hook_program.is_none(), ErrorCode::InvalidHookProgram);
+        }
+
+        if !self.lock_bonus_schedule.is_empty() {
+            // only claim and stake locks tokens
+            require!(
+                matches!(
+                    claim_type_enum,
+                    ClaimType::PermissionlessWithStaking | ClaimType::PermissionedWithStaking
+                ),
+                ErrorCode::InvalidLockBonusSchedule
+            );
+            require!(
+                self.lock_bonus_schedule.len() <= MAX_LOCK_BONUS_TIERS,
+                ErrorCode::InvalidLockBonusSchedule
+            );
+            // longer locks never get a smaller share of the bonus
+            for tiers in self.lock_bonus_schedule.windows(2) {
+                require!(
+                    tiers[0].min_lock_duration < tiers[1].min_lock_duration
+                        && tiers[0].bonus_bps <= tiers[1].bonus_bps,
+                    ErrorCode::InvalidLockBonusSchedule
+                );
+            }
+            for tier in self.lock_bonus_schedule.iter() {
+                require!(
+                    u128::from(tier.bonus_bps) <= MAX_BPS,
+                    ErrorCode::InvalidLockBonusSchedule
+                );
+            }
         }
         Ok(())
     }
@@ -132,18 +363,23 @@ pub struct NewDistributor<'info> {
 
     /// Clawback receiver token account
     #[account(mut, token::mint = mint)]
-    pub clawback_receiver: Account<'info, TokenAccount>,
+    pub clawback_receiver: InterfaceAccount<'info, TokenAccount>,
 
     /// The mint to distribute.
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
     /// Token vault
     /// Should create previously
     #[account(
-        associated_token::mint = mint,
-        associated_token::authority=distributor,
+        token::mint = mint,
+        token::authority = distributor,
+        address = get_associated_token_address_with_program_id(
+            &distributor.key(),
+            &mint.key(),
+            &token_program.key(),
+        ),
     )]
-    pub token_vault: Account<'info, TokenAccount>,
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
 
     /// Admin wallet, responsible for creating the distributor and paying for the transaction.
     /// Also has the authority to set the clawback receiver and change itself.
@@ -153,8 +389,47 @@ pub struct NewDistributor<'info> {
     /// The [System] program.
     pub system_program: Program<'info, System>,
 
-    /// The [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [ClaimFee] of the distributor, when the params set one.
+    #[account(
+        init,
+        seeds = [
+            b"ClaimFee".as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + ClaimFee::INIT_SPACE,
+        payer = admin
+    )]
+    pub claim_fee: Option<AccountLoader<'info, ClaimFee>>,
+
+    /// The [LockBonusSchedule] of the distributor, when the params set one.
+    #[account(
+        init,
+        seeds = [
+            b"LockBonusSchedule".as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + LockBonusSchedule::INIT_SPACE,
+        payer = admin
+    )]
+    pub lock_bonus_schedule: Option<AccountLoader<'info, LockBonusSchedule>>,
+
+    /// The [ClaimHook] of the distributor, when the params set a hook program.
+    #[account(
+        init,
+        seeds = [
+            b"ClaimHook".as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + ClaimHook::INIT_SPACE,
+        payer = admin
+    )]
+    pub claim_hook: Option<AccountLoader<'info, ClaimHook>>,
 }
 
 /// Creates a new [MerkleDistributor].
@@ -165,6 +440,7 @@ pub struct NewDistributor<'info> {
 ///     2. The clawback timestamp is after the end timestamp
 ///     3. The start, end, and clawback_start timestamps are all in the future
 ///     4. The clawback start is at least one day after end timestamp
+///     5. Staking claim types use a mint owned by the SPL Token program
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::result_large_err)]
 pub fn handle_new_distributor(
@@ -173,33 +449,46 @@ pub fn handle_new_distributor(
 ) -> Result<()> {
     params.validate()?;
 
+    // the locker escrow only holds SPL Token accounts
+    let claim_type =
+        ClaimType::try_from(params.claim_type).map_err(|_| ErrorCode::TypeCastedError)?;
+    if matches!(
+        claim_type,
+        ClaimType::PermissionlessWithStaking | ClaimType::PermissionedWithStaking
+    ) {
+        require_keys_eq!(
+            ctx.accounts.token_program.key(),
+            Token::id(),
+            ErrorCode::UnsupportedTokenProgram
+        );
+    }
+
     let mut distributor = ctx.accounts.distributor.load_init()?;
 
     distributor.bump = *ctx.bumps.get("distributor").unwrap();
-    distributor.version = params.version;
-    distributor.root = params.root;
     distributor.mint = ctx.accounts.mint.key();
     distributor.token_vault = ctx.accounts.token_vault.key();
-    distributor.max_total_claim = params.get_max_total_claim()?;
-    distributor.max_num_nodes = params.max_num_nodes;
-    distributor.total_amount_claimed = 0;
-    distributor.num_nodes_claimed = 0;
-    distributor.start_ts = params.start_vesting_ts;
-    distributor.end_ts = params.end_vesting_ts;
-    distributor.clawback_start_ts = params.clawback_start_ts;
     distributor.clawback_receiver = ctx.accounts.clawback_receiver.key();
     distributor.admin = ctx.accounts.admin.key();
-    distributor.clawed_back = 0;
-    if params.closable {
-        distributor.closable = 1;
-    }
     distributor.base = ctx.accounts.base.key();
-    distributor.airdrop_bonus = params.get_airdrop_bonus();
-    distributor.claim_type = params.claim_type;
-    distributor.activation_point = params.activation_point;
-    distributor.activation_type = params.activation_type;
-    distributor.operator = params.operator;
-    distributor.locker = params.locker;
+    params.init_distributor(&mut distributor)?;
+    params.init_claim_fee(
+        &mut distributor,
+        ctx.accounts.distributor.key(),
+        &ctx.accounts.claim_fee,
+        ctx.bumps.get("claim_fee").copied(),
+    )?;
+    params.init_lock_bonus_schedule(
+        &mut distributor,
+        ctx.accounts.distributor.key(),
+        &ctx.accounts.lock_bonus_schedule,
+        ctx.bumps.get("lock_bonus_schedule").copied(),
+    )?;
+    params.init_claim_hook(
+        ctx.accounts.distributor.key(),
+        &ctx.accounts.claim_hook,
+        ctx.bumps.get("claim_hook").copied(),
+    )?;
 
     // Note: might get truncated, do not rely on
     msg! {
@@ -220,5 +509,29 @@ pub fn handle_new_distributor(
             distributor.claim_type,
     };
 
+    emit!(NewDistributorEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        mint: distributor.mint,
+        admin: distributor.admin,
+        root: distributor.root,
+        max_total_claim: distributor.max_total_claim,
+        max_num_nodes: distributor.max_num_nodes,
+        start_ts: distributor.start_ts,
+        end_ts: distributor.end_ts,
+        clawback_start_ts: distributor.clawback_start_ts,
+        activation_point: distributor.activation_point,
+        activation_type: distributor.activation_type,
+        claim_type: distributor.claim_type,
+        total_bonus: distributor.airdrop_bonus.total_bonus,
+        revocable: distributor.revocable(),
+        early_exit_penalty_bps: distributor.early_exit_penalty_bps,
+        early_exit_penalty_to_clawback: distributor.early_exit_penalty_to_clawback(),
+        bonus_curve_type: distributor.airdrop_bonus.curve_type,
+        bonus_num_steps: distributor.airdrop_bonus.num_steps,
+        has_claim_fee: distributor.has_claim_fee(),
+        has_lock_bonus_schedule: distributor.has_lock_bonus_schedule(),
+    });
+
     Ok(())
 }
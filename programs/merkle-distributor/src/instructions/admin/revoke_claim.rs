@@ -0,0 +1,140 @@
+use anchor_lang::{
+    context::Context, prelude::*, solana_program::hash::hashv, system_program::System, Accounts,
+    Key, Result,
+};
+use jito_merkle_verify::verify;
+
+use crate::{
+    error::ErrorCode,
+    instructions::LEAF_PREFIX,
+    state::{
+        claim_revocation::ClaimRevocation,
+        claim_status::VestingSchedule,
+        claimed_event::ClaimRevokedEvent,
+        merkle_distributor::{ClaimType, MerkleDistributor},
+    },
+};
+
+/// [merkle_distributor::revoke_claim] accounts.
+#[derive(Accounts)]
+pub struct RevokeClaim<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut, has_one = admin @ ErrorCode::Unauthorized)]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Claim revocation PDA
+    #[account(
+        init,
+        seeds = [
+            b"ClaimRevocation".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + ClaimRevocation::INIT_SPACE,
+        payer = admin,
+    )]
+    pub claim_revocation: AccountLoader<'info, ClaimRevocation>,
+
+    /// CHECK: Claim status PDA of the claimant, must not exist
+    #[account(
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = claim_status.data_is_empty() @ ErrorCode::LeafAlreadyClaimed,
+    )]
+    pub claim_status: UncheckedAccount<'info>,
+
+    /// CHECK: Claimant of the revoked leaf
+    pub claimant: UncheckedAccount<'info>,
+
+    /// Admin signer, pays for the claim revocation
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Revokes the unclaimed leaf of a claimant, so it can't be claimed anymore.
+/// The leaf amounts and their share of the bonus are removed from max_total_claim,
+/// which makes them sweepable by sweep_surplus.
+/// CHECK:
+///     1. The distributor has not been clawed back and doesn't track claims in a bitmap
+///     2. The leaf has not been claimed
+///     3. The merkle proof is valid, a leaf without vesting schedule passes a default one
+#[allow(clippy::result_large_err)]
+pub fn handle_revoke_claim(
+    ctx: Context<RevokeClaim>,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    vesting_schedule: VestingSchedule,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+
+    require!(!distributor.clawed_back(), ErrorCode::ClaimExpired);
+    require!(
+        distributor.claim_type != u8::from(ClaimType::PermissionlessWithBitmap),
+        ErrorCode::InvalidClaimType
+    );
+
+    let claimant = ctx.accounts.claimant.key();
+
+    // Verify the merkle proof.
+    let node = if vesting_schedule.is_set() {
+        hashv(&[
+            &claimant.to_bytes(),
+            &amount_unlocked.to_le_bytes(),
+            &amount_locked.to_le_bytes(),
+            &vesting_schedule.cliff_ts.to_le_bytes(),
+            &vesting_schedule.period.to_le_bytes(),
+            &vesting_schedule.num_periods.to_le_bytes(),
+        ])
+    } else {
+        hashv(&[
+            &claimant.to_bytes(),
+            &amount_unlocked.to_le_bytes(),
+            &amount_locked.to_le_bytes(),
+        ])
+    };
+
+    let node = hashv(&[LEAF_PREFIX, &node.to_bytes()]);
+
+    require!(
+        verify(proof, distributor.root, node.to_bytes()),
+        ErrorCode::InvalidProof
+    );
+
+    let leaf_amount = amount_unlocked
+        .checked_add(amount_locked)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    let revoked_amount = distributor.revoke_leaf(leaf_amount)?;
+
+    let mut claim_revocation = ctx.accounts.claim_revocation.load_init()?;
+    claim_revocation.distributor = ctx.accounts.distributor.key();
+    claim_revocation.claimant = claimant;
+    claim_revocation.revoked_amount = revoked_amount;
+    claim_revocation.revoked_at = Clock::get()?.unix_timestamp;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Revoked leaf of {} with locked {}, unlocked {}, max_total_claim reduced by {}",
+        claimant,
+        amount_locked,
+        amount_unlocked,
+        revoked_amount,
+    );
+
+    emit!(ClaimRevokedEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        claimant,
+        revoked_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claim_status::ClaimStatus, claimed_event::VestingRevokedEvent,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::revoke_vesting] accounts.
+#[derive(Accounts)]
+pub struct RevokeVesting<'info> {
+    /// The [MerkleDistributor].
+    #[account(
+        mut,
+        has_one = admin @ ErrorCode::Unauthorized,
+        has_one = clawback_receiver,
+    )]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Claim status of the terminated vesting position.
+    #[account(mut, has_one = distributor)]
+    pub claim_status: AccountLoader<'info, ClaimStatus>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = distributor,
+        address = distributor.load()?.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// The Clawback token account.
+    #[account(mut, token::mint = mint)]
+    pub clawback_receiver: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.load()?.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Admin signer
+    pub admin: Signer<'info>,
+
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Terminates the vesting of a [ClaimStatus].
+/// The locked amount vested so far stays withdrawable by the claimant,
+/// the unvested remainder is transferred to the clawback receiver.
+/// 1. Freezes claim_status.locked_amount at the amount vested at the current time
+/// 2. Increments total_amount_claimed by the unvested amount, keeping the bonus base unchanged
+/// CHECK:
+///     1. The distributor is revocable and has not been clawed back
+///     2. The claim status has not been revoked yet
+///     3. Some of the locked amount is still unvested
+#[allow(clippy::result_large_err)]
+pub fn handle_revoke_vesting(ctx: Context<RevokeVesting>) -> Result<()> {
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+    let mut claim_status = ctx.accounts.claim_status.load_mut()?;
+    let curr_ts = Clock::get()?.unix_timestamp;
+
+    require!(distributor.revocable(), ErrorCode::DistributorNotRevocable);
+    require!(!distributor.clawed_back(), ErrorCode::ClaimExpired);
+    require!(!claim_status.revoked(), ErrorCode::VestingAlreadyRevoked);
+
+    let vested_amount =
+        claim_status.unlocked_amount(curr_ts, distributor.start_ts, distributor.end_ts)?;
+    let revoked_amount = claim_status
+        .locked_amount
+        .checked_sub(vested_amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    require!(revoked_amount > 0, ErrorCode::NoUnvestedAmount);
+
+    claim_status.locked_amount = vested_amount;
+    claim_status.revoked = 1;
+
+    // the unvested amount leaves the vault, max_total_claim is the base of the bonus
+    distributor.release_amount(revoked_amount)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Revoked vesting of {} with vested {}, returned {} to the clawback receiver",
+        claim_status.claimant,
+        vested_amount,
+        revoked_amount,
+    );
+
+    emit!(VestingRevokedEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        claimant: claim_status.claimant,
+        vested_amount,
+        revoked_amount,
+    });
+
+    drop(claim_status);
+    let signer = distributor.signer();
+    drop(distributor);
+    let seeds = signer.seeds();
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.clawback_receiver.to_account_info(),
+                authority: ctx.accounts.distributor.to_account_info(),
+            },
+        )
+        .with_signer(&[&seeds[..]]),
+        revoked_amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use crate::state::merkle_distributor::MerkleDistributor;
+use crate::state::{claimed_event::SetActivationPointEvent, merkle_distributor::MerkleDistributor};
 use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
 /// Accounts for [merkle_distributor::set_activation_point].
 #[derive(Accounts)]
@@ -22,6 +22,14 @@ pub fn handle_set_activation_point(
     activation_point: u64,
 ) -> Result<()> {
     let mut distributor = ctx.accounts.distributor.load_mut()?;
+    let old_activation_point = distributor.activation_point;
     distributor.activation_point = activation_point;
+
+    emit!(SetActivationPointEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        old_activation_point,
+        new_activation_point: activation_point,
+    });
     Ok(())
 }
@@ -1,8 +1,11 @@
 use anchor_lang::{accounts::signer::Signer, context::Context, prelude::*, Accounts, Result};
 
-use crate::{error::ErrorCode, state::merkle_distributor::MerkleDistributor};
+use crate::{
+    error::ErrorCode,
+    state::{claimed_event::SetAdminEvent, merkle_distributor::MerkleDistributor},
+};
 
-/// [merkle_distributor::set_clawback_receiver] accounts.
+/// [merkle_distributor::set_admin] accounts.
 #[derive(Accounts)]
 pub struct SetAdmin<'info> {
     /// The [MerkleDistributor].
@@ -28,9 +31,18 @@ pub fn handle_set_admin(ctx: Context<SetAdmin>) -> Result<()> {
     );
 
     let mut distributor = ctx.accounts.distributor.load_mut()?;
+    let old_admin = distributor.admin;
     distributor.admin = ctx.accounts.new_admin.key();
+    distributor.pending_admin = Pubkey::default();
     // Note: might get truncated, do not rely on
     msg!("set new admin to {}", ctx.accounts.new_admin.key());
 
+    emit!(SetAdminEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        old_admin,
+        new_admin: distributor.admin,
+    });
+
     Ok(())
 }
@@ -1,12 +1,15 @@
 use anchor_lang::{
-    accounts::{account::Account, signer::Signer},
+    accounts::{interface_account::InterfaceAccount, signer::Signer},
     context::Context,
     prelude::*,
     Accounts, Result, ToAccountInfo,
 };
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::TokenAccount;
 
-use crate::{error::ErrorCode, state::merkle_distributor::MerkleDistributor};
+use crate::{
+    error::ErrorCode,
+    state::{claimed_event::SetClawbackReceiverEvent, merkle_distributor::MerkleDistributor},
+};
 
 /// [merkle_distributor::set_clawback_receiver] accounts.
 #[derive(Accounts)]
@@ -17,7 +20,7 @@ pub struct SetClawbackReceiver<'info> {
 
     /// New clawback account
     #[account(token::mint=distributor.load()?.mint)]
-    pub new_clawback_account: Account<'info, TokenAccount>,
+    pub new_clawback_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Admin signer
     pub admin: Signer<'info>,
@@ -37,7 +40,9 @@ pub fn handle_set_clawback_receiver(ctx: Context<SetClawbackReceiver>) -> Result
 
     let new_clawback_account = *ctx.accounts.new_clawback_account.to_account_info().key;
 
+    let old_clawback_receiver = distributor.clawback_receiver;
     distributor.clawback_receiver = new_clawback_account;
+    distributor.pending_clawback_receiver = Pubkey::default();
 
     // Note: might get truncated, do not rely on
     msg!(
@@ -46,5 +51,12 @@ pub fn handle_set_clawback_receiver(ctx: Context<SetClawbackReceiver>) -> Result
         ctx.accounts.new_clawback_account.owner
     );
 
+    emit!(SetClawbackReceiverEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        old_clawback_receiver,
+        new_clawback_receiver: new_clawback_account,
+    });
+
     Ok(())
 }
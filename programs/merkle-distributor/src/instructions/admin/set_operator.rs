@@ -1,6 +1,9 @@
 use crate::{
     error::ErrorCode,
-    state::merkle_distributor::{ClaimType, MerkleDistributor},
+    state::{
+        claimed_event::SetOperatorEvent,
+        merkle_distributor::{ClaimType, MerkleDistributor},
+    },
 };
 use anchor_lang::{
     accounts::signer::Signer, context::Context, prelude::*, Accounts, Result, ToAccountInfo,
@@ -33,6 +36,14 @@ pub fn handle_set_operator(ctx: Context<SetOperator>, new_operator: Pubkey) -> R
         ErrorCode::SameOperator
     );
 
+    let old_operator = distributor.operator;
     distributor.operator = new_operator;
+
+    emit!(SetOperatorEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        old_operator,
+        new_operator,
+    });
     Ok(())
 }
@@ -0,0 +1,62 @@
+use anchor_lang::{accounts::signer::Signer, context::Context, prelude::*, Accounts, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claimed_event::{PausedEvent, UnpausedEvent},
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::pause] and [merkle_distributor::unpause] accounts.
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut, has_one = admin @ ErrorCode::Unauthorized)]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Admin signer
+    pub admin: Signer<'info>,
+}
+
+/// Pauses all claims from the distributor until it is unpaused
+/// CHECK:
+///     1. The distributor is not already paused
+#[allow(clippy::result_large_err)]
+pub fn handle_pause(ctx: Context<SetPause>) -> Result<()> {
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+    require!(!distributor.paused(), ErrorCode::SamePauseState);
+
+    distributor.set_paused(true);
+    // Note: might get truncated, do not rely on
+    msg!("paused distributor {}", ctx.accounts.distributor.key());
+
+    emit!(PausedEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
+
+/// Resumes claims from a paused distributor
+/// CHECK:
+///     1. The distributor is paused
+#[allow(clippy::result_large_err)]
+pub fn handle_unpause(ctx: Context<SetPause>) -> Result<()> {
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+    require!(distributor.paused(), ErrorCode::SamePauseState);
+
+    distributor.set_paused(false);
+    // Note: might get truncated, do not rely on
+    msg!("unpaused distributor {}", ctx.accounts.distributor.key());
+
+    emit!(UnpausedEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Key, Result};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    state::{claimed_event::SweepSurplusEvent, merkle_distributor::MerkleDistributor},
+};
+
+/// [merkle_distributor::sweep_surplus] accounts.
+#[derive(Accounts)]
+pub struct SweepSurplus<'info> {
+    /// The [MerkleDistributor].
+    #[account(
+        has_one = admin @ ErrorCode::Unauthorized,
+        has_one = clawback_receiver,
+        constraint = !distributor.load()?.in_campaign() @ ErrorCode::DistributorInCampaign,
+    )]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = distributor,
+        address = distributor.load()?.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// The Clawback token account.
+    #[account(mut, token::mint = mint)]
+    pub clawback_receiver: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.load()?.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Admin signer
+    pub admin: Signer<'info>,
+
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Transfers the vault balance above what is still owed to claimants to the clawback receiver.
+/// Owed amount is max_total_claim - total_amount_claimed.
+/// CHECK:
+///     1. The vault holds more than the owed amount
+///     2. The distributor is not part of a campaign, whose vault is shared
+#[allow(clippy::result_large_err)]
+pub fn handle_sweep_surplus(ctx: Context<SweepSurplus>) -> Result<()> {
+    let distributor = ctx.accounts.distributor.load()?;
+
+    let owed_amount = distributor
+        .max_total_claim
+        .checked_sub(distributor.total_amount_claimed)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    let surplus = ctx.accounts.from.amount.saturating_sub(owed_amount);
+    require!(surplus > 0, ErrorCode::NoSurplus);
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "sweep surplus {} from vault holding {}, owed {}",
+        surplus,
+        ctx.accounts.from.amount,
+        owed_amount
+    );
+
+    emit!(SweepSurplusEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        clawback_receiver: distributor.clawback_receiver,
+        amount: surplus,
+    });
+
+    let signer = distributor.signer();
+    drop(distributor);
+    let seeds = signer.seeds();
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.clawback_receiver.to_account_info(),
+                authority: ctx.accounts.distributor.to_account_info(),
+            },
+        )
+        .with_signer(&[&seeds[..]]),
+        surplus,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use anchor_lang::{accounts::signer::Signer, context::Context, prelude::*, Accounts, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claimed_event::{SetAdminEvent, SetPendingAdminEvent},
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::propose_admin] accounts.
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut, has_one = admin @ ErrorCode::Unauthorized)]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Admin signer
+    pub admin: Signer<'info>,
+
+    /// Nominated admin account
+    /// CHECK: this can be any new account, it has to sign accept_admin
+    pub new_admin: AccountInfo<'info>,
+}
+
+/// [merkle_distributor::accept_admin] accounts.
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// The [MerkleDistributor].
+    #[account(
+        mut,
+        constraint = distributor.load()?.pending_admin == pending_admin.key() @ ErrorCode::InvalidPendingAdmin
+    )]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Pending admin signer
+    pub pending_admin: Signer<'info>,
+}
+
+/// [merkle_distributor::cancel_pending_admin] accounts.
+#[derive(Accounts)]
+pub struct CancelPendingAdmin<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut, has_one = admin @ ErrorCode::Unauthorized)]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Admin signer
+    pub admin: Signer<'info>,
+}
+
+/// Nominates a new admin, which only takes over once it signs accept_admin.
+/// A previous nomination is replaced.
+/// CHECK:
+///     1. The new admin is not the same as the old one
+#[allow(clippy::result_large_err)]
+pub fn handle_propose_admin(ctx: Context<ProposeAdmin>) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key != &ctx.accounts.new_admin.key(),
+        ErrorCode::SameAdmin
+    );
+
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+    let old_pending_admin = distributor.pending_admin;
+    distributor.pending_admin = ctx.accounts.new_admin.key();
+    // Note: might get truncated, do not rely on
+    msg!("proposed new admin {}", ctx.accounts.new_admin.key());
+
+    emit!(SetPendingAdminEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        old_pending_admin,
+        new_pending_admin: distributor.pending_admin,
+    });
+
+    Ok(())
+}
+
+/// Makes the pending admin the admin of the distributor
+#[allow(clippy::result_large_err)]
+pub fn handle_accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+    require!(
+        distributor.pending_admin != Pubkey::default(),
+        ErrorCode::NoPendingAdmin
+    );
+
+    let old_admin = distributor.admin;
+    distributor.admin = distributor.pending_admin;
+    distributor.pending_admin = Pubkey::default();
+    // Note: might get truncated, do not rely on
+    msg!("accepted new admin {}", distributor.admin);
+
+    emit!(SetAdminEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        old_admin,
+        new_admin: distributor.admin,
+    });
+
+    Ok(())
+}
+
+/// Clears the pending admin
+/// CHECK:
+///     1. There is a pending admin
+#[allow(clippy::result_large_err)]
+pub fn handle_cancel_pending_admin(ctx: Context<CancelPendingAdmin>) -> Result<()> {
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+    require!(
+        distributor.pending_admin != Pubkey::default(),
+        ErrorCode::NoPendingAdmin
+    );
+
+    // Note: might get truncated, do not rely on
+    msg!("cancelled pending admin {}", distributor.pending_admin);
+    let old_pending_admin = distributor.pending_admin;
+    distributor.pending_admin = Pubkey::default();
+
+    emit!(SetPendingAdminEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        old_pending_admin,
+        new_pending_admin: distributor.pending_admin,
+    });
+
+    Ok(())
+}
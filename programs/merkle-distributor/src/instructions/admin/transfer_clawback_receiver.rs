@@ -0,0 +1,153 @@
+use anchor_lang::{
+    accounts::{interface_account::InterfaceAccount, signer::Signer},
+    context::Context,
+    prelude::*,
+    Accounts, Result,
+};
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claimed_event::{SetClawbackReceiverEvent, SetPendingClawbackReceiverEvent},
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::propose_clawback_receiver] accounts.
+#[derive(Accounts)]
+pub struct ProposeClawbackReceiver<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut, has_one = admin @ ErrorCode::Unauthorized)]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Nominated clawback account
+    #[account(token::mint = distributor.load()?.mint)]
+    pub new_clawback_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Admin signer
+    pub admin: Signer<'info>,
+}
+
+/// [merkle_distributor::accept_clawback_receiver] accounts.
+#[derive(Accounts)]
+pub struct AcceptClawbackReceiver<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Pending clawback account
+    #[account(
+        address = distributor.load()?.pending_clawback_receiver @ ErrorCode::InvalidPendingClawbackReceiver,
+        token::authority = owner,
+    )]
+    pub new_clawback_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Owner of the pending clawback account
+    pub owner: Signer<'info>,
+}
+
+/// [merkle_distributor::cancel_pending_clawback_receiver] accounts.
+#[derive(Accounts)]
+pub struct CancelPendingClawbackReceiver<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut, has_one = admin @ ErrorCode::Unauthorized)]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Admin signer
+    pub admin: Signer<'info>,
+}
+
+/// Nominates a new clawback receiver token account, which only takes over once
+/// its owner signs accept_clawback_receiver. A previous nomination is replaced.
+/// CHECK:
+///     1. The new clawback receiver is not the same as the old one
+#[allow(clippy::result_large_err)]
+pub fn handle_propose_clawback_receiver(ctx: Context<ProposeClawbackReceiver>) -> Result<()> {
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+
+    let new_clawback_account = ctx.accounts.new_clawback_account.key();
+    require!(
+        distributor.clawback_receiver != new_clawback_account,
+        ErrorCode::SameClawbackReceiver
+    );
+
+    let old_pending_clawback_receiver = distributor.pending_clawback_receiver;
+    distributor.pending_clawback_receiver = new_clawback_account;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "proposed new clawback receiver ATA {}, owned by {}",
+        new_clawback_account,
+        ctx.accounts.new_clawback_account.owner
+    );
+
+    emit!(SetPendingClawbackReceiverEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        old_pending_clawback_receiver,
+        new_pending_clawback_receiver: new_clawback_account,
+    });
+
+    Ok(())
+}
+
+/// Makes the pending clawback receiver the clawback receiver of the distributor
+#[allow(clippy::result_large_err)]
+pub fn handle_accept_clawback_receiver(ctx: Context<AcceptClawbackReceiver>) -> Result<()> {
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+    require!(
+        distributor.pending_clawback_receiver != Pubkey::default(),
+        ErrorCode::NoPendingClawbackReceiver
+    );
+
+    let old_clawback_receiver = distributor.clawback_receiver;
+    distributor.clawback_receiver = distributor.pending_clawback_receiver;
+    distributor.pending_clawback_receiver = Pubkey::default();
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "accepted new clawback receiver ATA {}",
+        distributor.clawback_receiver
+    );
+
+    emit!(SetClawbackReceiverEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        old_clawback_receiver,
+        new_clawback_receiver: distributor.clawback_receiver,
+    });
+
+    Ok(())
+}
+
+/// Clears the pending clawback receiver
+/// CHECK:
+///     1. There is a pending clawback receiver
+#[allow(clippy::result_large_err)]
+pub fn handle_cancel_pending_clawback_receiver(
+    ctx: Context<CancelPendingClawbackReceiver>,
+) -> Result<()> {
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+    require!(
+        distributor.pending_clawback_receiver != Pubkey::default(),
+        ErrorCode::NoPendingClawbackReceiver
+    );
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "cancelled pending clawback receiver {}",
+        distributor.pending_clawback_receiver
+    );
+    let old_pending_clawback_receiver = distributor.pending_clawback_receiver;
+    distributor.pending_clawback_receiver = Pubkey::default();
+
+    emit!(SetPendingClawbackReceiverEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        old_pending_clawback_receiver,
+        new_pending_clawback_receiver: distributor.pending_clawback_receiver,
+    });
+
+    Ok(())
+}
@@ -1,18 +1,25 @@
 use anchor_lang::{
-    accounts::{account::Account, program::Program, signer::Signer},
+    accounts::{interface::Interface, interface_account::InterfaceAccount, signer::Signer},
     context::{Context, CpiContext},
     emit,
     prelude::*,
     Accounts, Result, ToAccountInfo,
 };
-use anchor_spl::token::{self, Token, TokenAccount};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
 
 use crate::{
     error::ErrorCode,
     state::{
-        claim_status::ClaimStatus, claimed_event::ClaimedEvent,
+        campaign::Campaign,
+        claim_fee::ClaimFee,
+        claim_status::ClaimStatus,
+        claimed_event::{ClaimFeeEvent, ClaimedEvent},
         merkle_distributor::MerkleDistributor,
     },
+    utils::{
+        calculate_transfer_fee, claim_fee_of, release_with_claim_fee, token_vault_authority,
+        transfer_lamport_fee,
+    },
 };
 
 /// [merkle_distributor::claim_locked] accounts.
@@ -33,29 +40,57 @@ pub struct ClaimLocked<'info> {
     /// Distributor ATA containing the tokens to distribute.
     #[account(
         mut,
-        associated_token::mint = distributor.load()?.mint,
-        associated_token::authority = distributor.key(),
+        token::mint = mint,
+        token::authority = distributor.load()?.token_vault_authority(&distributor.key())?,
         address = distributor.load()?.token_vault,
     )]
-    pub from: Account<'info, TokenAccount>,
+    pub from: InterfaceAccount<'info, TokenAccount>,
 
     /// Account to send the claimed tokens to.
-    #[account(mut)]
-    pub to: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = mint)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.load()?.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
 
-    /// Who is claiming the tokens.
+    /// Who is claiming the tokens, paying a lamport claim fee.
+    #[account(mut)]
     pub claimant: Signer<'info>,
 
     /// operator
     pub operator: Option<Signer<'info>>,
 
-    /// SPL [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [Campaign] owning the token vault, when the distributor is part of one.
+    #[account(address = distributor.load()?.token_vault_authority(&distributor.key())?)]
+    pub campaign: Option<AccountLoader<'info, Campaign>>,
+
+    /// The [ClaimFee] of the distributor, when it charges one.
+    #[account(
+        seeds = [
+            b"ClaimFee".as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump = claim_fee.load()?.bump,
+    )]
+    pub claim_fee: Option<AccountLoader<'info, ClaimFee>>,
+
+    /// CHECK: Receiver of the claim fee, or its token account for a token fee
+    #[account(mut)]
+    pub fee_receiver: Option<UncheckedAccount<'info>>,
+
+    /// The [System] program, paying a lamport claim fee.
+    pub system_program: Option<Program<'info, System>>,
 }
 
 /// Claim locked tokens as they become unlocked.
+/// The claim fee of the distributor is collected on every withdrawal, lamports from the
+/// claimant or tokens out of the withdrawn amount.
 /// Check:
-///     1. The claim window has not expired and the distributor has not been clawed back
+///     1. The claim window has not expired and the distributor has not been clawed back or paused
 ///     2. The withdraw-able amount is greater than 0
 ///     3. The locked amount withdrawn is ≤ than the locked amount
 ///     4. The distributor amount claimed is ≤ than the max total claim
@@ -67,6 +102,7 @@ pub fn handle_claim_locked(ctx: Context<ClaimLocked>) -> Result<()> {
     let curr_ts = Clock::get()?.unix_timestamp;
 
     require!(!distributor.clawed_back(), ErrorCode::ClaimExpired);
+    require!(!distributor.paused(), ErrorCode::DistributorPaused);
 
     // check operator
     distributor.authorize_claim(&ctx.accounts.operator)?;
@@ -89,52 +125,111 @@ pub fn handle_claim_locked(ctx: Context<ClaimLocked>) -> Result<()> {
         ErrorCode::ExceededMaxClaim
     );
 
-    distributor.total_amount_claimed = distributor
-        .total_amount_claimed
-        .checked_add(amount)
-        .ok_or(ErrorCode::ArithmeticError)?;
-
-    require!(
-        distributor.total_amount_claimed <= distributor.max_total_claim,
-        ErrorCode::ExceededMaxClaim
-    );
-
-    let remaining_seconds = match curr_ts < distributor.end_ts {
-        true => distributor.end_ts - curr_ts,
+    let lockup_end_ts = claim_status.lockup_end_ts(distributor.end_ts)?;
+    let remaining_seconds = match curr_ts < lockup_end_ts {
+        true => lockup_end_ts - curr_ts,
         false => 0,
     };
 
     let days = remaining_seconds / (24 * 60 * 60); // number of days
     let seconds_after_days = remaining_seconds % (24 * 60 * 60); // Remaining seconds after subtracting full days
 
+    let claim_fee = claim_fee_of(
+        &distributor,
+        &ctx.accounts.claim_fee,
+        &ctx.accounts.fee_receiver,
+        &ctx.accounts.token_program.key(),
+    )?;
+    let (fee_amount, amount_to_claimant) = release_with_claim_fee(
+        &mut distributor,
+        claim_fee.as_ref().map(|(claim_fee, _)| claim_fee),
+        amount,
+    )?;
+    let transfer_fee = calculate_transfer_fee(&ctx.accounts.mint, amount_to_claimant)?;
+
     // Note: might get truncated, do not rely on
     msg!(
-        "Withdrew amount {} with {} days and {} seconds left in lockup",
+        "Withdrew amount {} (transfer fee {}) with {} days and {} seconds left in lockup",
         amount,
+        transfer_fee,
         days,
         seconds_after_days,
     );
 
-    let signer = distributor.signer();
+    emit!(ClaimedEvent {
+        claimant: ctx.accounts.claimant.key(),
+        amount,
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        locked_amount_withdrawn: claim_status.locked_amount_withdrawn,
+    });
+
+    if let Some((claim_fee, fee_receiver)) = &claim_fee {
+        emit!(ClaimFeeEvent {
+            distributor: ctx.accounts.distributor.key(),
+            version: distributor.version,
+            claimant: ctx.accounts.claimant.key(),
+            fee_receiver: fee_receiver.key(),
+            lamports: claim_fee.lamports,
+            amount: fee_amount,
+        });
+    }
+
+    let vault_authority = token_vault_authority(
+        &distributor,
+        &ctx.accounts.distributor,
+        &ctx.accounts.campaign,
+    )?;
+    let signer = distributor.vault_signer();
     drop(distributor);
     let seeds = signer.seeds();
 
-    token::transfer(
+    token_interface::transfer_checked(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
+            token_interface::TransferChecked {
                 from: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.to.to_account_info(),
-                authority: ctx.accounts.distributor.to_account_info(),
+                authority: vault_authority.clone(),
             },
         )
         .with_signer(&[&seeds[..]]),
-        amount,
+        amount_to_claimant,
+        ctx.accounts.mint.decimals,
     )?;
 
-    emit!(ClaimedEvent {
-        claimant: ctx.accounts.claimant.key(),
-        amount,
-    });
+    if let Some((claim_fee, fee_receiver)) = claim_fee {
+        if fee_amount > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    token_interface::TransferChecked {
+                        from: ctx.accounts.from.to_account_info(),
+                        mint: ctx.accounts.mint.to_account_info(),
+                        to: fee_receiver.clone(),
+                        authority: vault_authority,
+                    },
+                )
+                .with_signer(&[&seeds[..]]),
+                fee_amount,
+                ctx.accounts.mint.decimals,
+            )?;
+        }
+        if claim_fee.lamports > 0 {
+            let system_program = ctx
+                .accounts
+                .system_program
+                .as_ref()
+                .ok_or(ErrorCode::InvalidClaimFeeAccount)?;
+            transfer_lamport_fee(
+                ctx.accounts.claimant.to_account_info(),
+                fee_receiver,
+                system_program.to_account_info(),
+                claim_fee.lamports,
+            )?;
+        }
+    }
+
     Ok(())
 }
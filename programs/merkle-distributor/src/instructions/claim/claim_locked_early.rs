@@ -0,0 +1,188 @@
+use anchor_lang::{
+    accounts::{interface::Interface, interface_account::InterfaceAccount, signer::Signer},
+    context::{Context, CpiContext},
+    emit,
+    prelude::*,
+    Accounts, Result, ToAccountInfo,
+};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        campaign::Campaign, claim_status::ClaimStatus, claimed_event::EarlyExitEvent,
+        merkle_distributor::MerkleDistributor,
+    },
+    utils::{calculate_transfer_fee, token_vault_authority},
+};
+
+/// [merkle_distributor::claim_locked_early] accounts.
+#[derive(Accounts)]
+pub struct ClaimLockedEarly<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut, has_one = clawback_receiver)]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Claim Status PDA
+    #[account(
+        mut,
+        has_one = distributor,
+        has_one = claimant,
+    )]
+    pub claim_status: AccountLoader<'info, ClaimStatus>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = distributor.load()?.token_vault_authority(&distributor.key())?,
+        address = distributor.load()?.token_vault,
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to.
+    #[account(mut, token::mint = mint)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// The Clawback token account, receiving the penalty when the distributor routes it there.
+    #[account(mut, token::mint = mint)]
+    pub clawback_receiver: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.load()?.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Who is claiming the tokens.
+    pub claimant: Signer<'info>,
+
+    /// operator
+    pub operator: Option<Signer<'info>>,
+
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [Campaign] owning the token vault, when the distributor is part of one.
+    #[account(address = distributor.load()?.token_vault_authority(&distributor.key())?)]
+    pub campaign: Option<AccountLoader<'info, Campaign>>,
+}
+
+/// Withdraws the whole remaining locked amount before the end of the lockup.
+/// The vested amount is withdrawn in full, the unvested amount minus the early exit penalty.
+/// 1. Freezes claim_status.locked_amount at the amount withdrawn, penalty excluded
+/// 2. Increments total_amount_claimed by the amount transferred to the claimant and the penalty,
+///    which goes to the clawback receiver or stays in the vault as surplus
+/// CHECK:
+///     1. The claim window has not expired and the distributor has not been clawed back or paused
+///     2. The distributor allows early exits
+///     3. Some of the locked amount is still unvested
+#[allow(clippy::result_large_err)]
+pub fn handle_claim_locked_early(ctx: Context<ClaimLockedEarly>) -> Result<()> {
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+
+    let mut claim_status = ctx.accounts.claim_status.load_mut()?;
+    let curr_ts = Clock::get()?.unix_timestamp;
+
+    require!(!distributor.clawed_back(), ErrorCode::ClaimExpired);
+    require!(!distributor.paused(), ErrorCode::DistributorPaused);
+    distributor.check_no_claim_fee()?;
+    require!(
+        distributor.early_exit_penalty_bps > 0,
+        ErrorCode::EarlyExitDisabled
+    );
+
+    // check operator
+    distributor.authorize_claim(&ctx.accounts.operator)?;
+
+    let activation_handler = distributor.get_activation_handler()?;
+    activation_handler.validate_claim()?;
+
+    let vested_amount =
+        claim_status.unlocked_amount(curr_ts, distributor.start_ts, distributor.end_ts)?;
+    let unvested_amount = claim_status
+        .locked_amount
+        .checked_sub(vested_amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    require!(unvested_amount > 0, ErrorCode::NoUnvestedAmountToExit);
+
+    let penalty = distributor.early_exit_penalty(
+        unvested_amount,
+        curr_ts,
+        claim_status.lockup_start_ts(distributor.start_ts),
+        claim_status.lockup_end_ts(distributor.end_ts)?,
+    )?;
+
+    let amount = claim_status.exit_early(penalty)?;
+
+    distributor.total_amount_claimed = distributor
+        .total_amount_claimed
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // the penalty is no longer owed to anyone, max_total_claim is the base of the bonus
+    distributor.release_amount(penalty)?;
+
+    let penalty_to_clawback = distributor.early_exit_penalty_to_clawback();
+    let transfer_fee = calculate_transfer_fee(&ctx.accounts.mint, amount)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Exited early with amount {} (transfer fee {}) and penalty {}",
+        amount,
+        transfer_fee,
+        penalty,
+    );
+
+    emit!(EarlyExitEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        claimant: ctx.accounts.claimant.key(),
+        amount,
+        penalty,
+        penalty_to_clawback,
+    });
+
+    let vault_authority = token_vault_authority(
+        &distributor,
+        &ctx.accounts.distributor,
+        &ctx.accounts.campaign,
+    )?;
+    let signer = distributor.vault_signer();
+    drop(distributor);
+    let seeds = signer.seeds();
+
+    if amount > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.from.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.to.to_account_info(),
+                    authority: vault_authority.clone(),
+                },
+            )
+            .with_signer(&[&seeds[..]]),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+    }
+
+    if penalty_to_clawback && penalty > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.from.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.clawback_receiver.to_account_info(),
+                    authority: vault_authority,
+                },
+            )
+            .with_signer(&[&seeds[..]]),
+            penalty,
+            ctx.accounts.mint.decimals,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use anchor_lang::{
+    accounts::{interface::Interface, interface_account::InterfaceAccount, signer::Signer},
+    context::{Context, CpiContext},
+    emit,
+    prelude::*,
+    solana_program::sysvar,
+    Accounts, Result, ToAccountInfo,
+};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    instructions::ClaimVoucher,
+    state::{
+        campaign::Campaign, claim_status::ClaimStatus, claimed_event::ClaimedEvent,
+        merkle_distributor::MerkleDistributor,
+    },
+    utils::{calculate_transfer_fee, token_vault_authority},
+};
+
+/// [merkle_distributor::claim_locked_with_voucher] accounts.
+#[derive(Accounts)]
+pub struct ClaimLockedWithVoucher<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Claim Status PDA
+    #[account(
+        mut,
+        has_one = distributor,
+        has_one = claimant,
+    )]
+    pub claim_status: AccountLoader<'info, ClaimStatus>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = distributor.load()?.token_vault_authority(&distributor.key())?,
+        address = distributor.load()?.token_vault,
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to.
+    #[account(mut, token::mint = mint)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.load()?.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Who is claiming the tokens.
+    pub claimant: Signer<'info>,
+
+    /// CHECK: Instructions sysvar, to load the ed25519 instruction
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [Campaign] owning the token vault, when the distributor is part of one.
+    #[account(address = distributor.load()?.token_vault_authority(&distributor.key())?)]
+    pub campaign: Option<AccountLoader<'info, Campaign>>,
+}
+
+/// Claim locked tokens as they become unlocked, from a permissioned [MerkleDistributor]
+/// with a [ClaimVoucher] of the operator. The voucher max amount caps everything transferred over
+/// the claim, so replaying a voucher never withdraws more than it allows.
+/// Check:
+///     1. The operator signed the voucher for this distributor and claimant, and it has not expired
+///     2. The claim window has not expired and the distributor has not been clawed back or paused
+///     3. The withdraw-able amount is greater than 0
+///     4. The locked amount withdrawn is ≤ than the locked amount
+///     5. The distributor amount claimed is ≤ than the max total claim
+///     6. The amount transferred over the claim is < than the voucher max amount
+#[allow(clippy::result_large_err)]
+pub fn handle_claim_locked_with_voucher(
+    ctx: Context<ClaimLockedWithVoucher>,
+    voucher: ClaimVoucher,
+) -> Result<()> {
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+
+    let mut claim_status = ctx.accounts.claim_status.load_mut()?;
+    let curr_ts = Clock::get()?.unix_timestamp;
+
+    require!(!distributor.clawed_back(), ErrorCode::ClaimExpired);
+    require!(!distributor.paused(), ErrorCode::DistributorPaused);
+    distributor.check_no_claim_fee()?;
+
+    voucher.verify(
+        &ctx.accounts.instructions_sysvar,
+        &distributor,
+        &ctx.accounts.distributor.key(),
+        &ctx.accounts.claimant.key(),
+        curr_ts,
+    )?;
+
+    let activation_handler = distributor.get_activation_handler()?;
+    activation_handler.validate_claim()?;
+
+    let amount = voucher.cap_locked_amount(
+        &claim_status,
+        claim_status.amount_withdrawable(curr_ts, distributor.start_ts, distributor.end_ts)?,
+    )?;
+
+    require!(amount > 0, ErrorCode::InsufficientUnlockedTokens);
+
+    claim_status.locked_amount_withdrawn = claim_status
+        .locked_amount_withdrawn
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        claim_status.locked_amount_withdrawn <= claim_status.locked_amount,
+        ErrorCode::ExceededMaxClaim
+    );
+
+    distributor.total_amount_claimed = distributor
+        .total_amount_claimed
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        distributor.total_amount_claimed <= distributor.max_total_claim,
+        ErrorCode::ExceededMaxClaim
+    );
+
+    let transfer_fee = calculate_transfer_fee(&ctx.accounts.mint, amount)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Withdrew amount {} (transfer fee {}) with voucher expiring at {}",
+        amount,
+        transfer_fee,
+        voucher.expiry,
+    );
+
+    emit!(ClaimedEvent {
+        claimant: ctx.accounts.claimant.key(),
+        amount,
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        locked_amount_withdrawn: claim_status.locked_amount_withdrawn,
+    });
+
+    let vault_authority = token_vault_authority(
+        &distributor,
+        &ctx.accounts.distributor,
+        &ctx.accounts.campaign,
+    )?;
+    let signer = distributor.vault_signer();
+    drop(distributor);
+    let seeds = signer.seeds();
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.to.to_account_info(),
+                authority: vault_authority,
+            },
+        )
+        .with_signer(&[&seeds[..]]),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::{context::Context, prelude::*, Accounts, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{campaign::Campaign, claim_status::ClaimStatus, claimed_event::CloseClaimStatusEvent},
+    utils::distributor_claims_ended,
+};
+
+/// [merkle_distributor::close_withdrawn_claim_status] accounts.
+#[derive(Accounts)]
+pub struct CloseWithdrawnClaimStatus<'info> {
+    /// CHECK: The [crate::state::merkle_distributor::MerkleDistributor], clawed back or already closed
+    #[account(
+        constraint = distributor_claims_ended(&distributor, &campaign)? @ ErrorCode::DistributorNotClawedBack,
+    )]
+    pub distributor: UncheckedAccount<'info>,
+
+    /// Claim Status PDA
+    #[account(
+        mut,
+        has_one = distributor,
+        has_one = claimant,
+        close = claimant,
+    )]
+    pub claim_status: AccountLoader<'info, ClaimStatus>,
+
+    /// Who claimed the tokens, receives the rent back.
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// The [Campaign] of the distributor, when it is part of one.
+    pub campaign: Option<AccountLoader<'info, Campaign>>,
+}
+
+/// Closes the claim status of a claimant who withdrew the whole locked amount.
+/// Only allowed once the distributor is clawed back, so the claim status can't be
+/// created again to claim twice. Once the distributor is closed, any claim status can be closed,
+/// nothing being left to withdraw.
+/// CHECK:
+///     1. The distributor or its campaign has been clawed back, or the distributor is closed
+///     2. The locked amount has been fully withdrawn, unless the distributor is closed
+#[allow(clippy::result_large_err)]
+pub fn handle_close_withdrawn_claim_status(ctx: Context<CloseWithdrawnClaimStatus>) -> Result<()> {
+    let claim_status = ctx.accounts.claim_status.load()?;
+
+    require!(
+        ctx.accounts.distributor.data_is_empty()
+            || claim_status.locked_amount_withdrawn >= claim_status.locked_amount,
+        ErrorCode::LockedAmountNotWithdrawn
+    );
+
+    // Note: might get truncated, do not rely on
+    msg!("closed claim status of {}", ctx.accounts.claimant.key());
+
+    emit!(CloseClaimStatusEvent {
+        distributor: claim_status.distributor,
+        claimant: claim_status.claimant,
+    });
+
+    Ok(())
+}
@@ -2,3 +2,25 @@ pub use claim_locked::*;
 pub use new_claim::*;
 pub mod claim_locked;
 pub mod new_claim;
+pub mod new_claim_with_bitmap;
+pub use new_claim_with_bitmap::*;
+pub mod new_claim_with_signature;
+pub use new_claim_with_signature::*;
+pub mod new_claim_with_schedule;
+pub use new_claim_with_schedule::*;
+pub mod close_withdrawn_claim_status;
+pub use close_withdrawn_claim_status::*;
+pub mod transfer_claim_status;
+pub use transfer_claim_status::*;
+pub mod new_claim_with_voucher;
+pub use new_claim_with_voucher::*;
+pub mod claim_locked_with_voucher;
+pub use claim_locked_with_voucher::*;
+pub mod new_claim_batch;
+pub use new_claim_batch::*;
+pub mod push_claim;
+pub use push_claim::*;
+pub mod claim_locked_early;
+pub use claim_locked_early::*;
+pub mod new_claim_with_hook;
+pub use new_claim_with_hook::*;
@@ -1,18 +1,15 @@
-use anchor_lang::{
-    context::Context, prelude::*, solana_program::hash::hashv, system_program::System, Accounts,
-    Key, Result,
-};
-use anchor_spl::{
-    token,
-    token::{Token, TokenAccount},
-};
-use jito_merkle_verify::verify;
+use anchor_lang::{context::Context, prelude::*, system_program::System, Accounts, Key, Result};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
     error::ErrorCode,
     state::{
-        claim_status::ClaimStatus, claimed_event::NewClaimEvent,
-        merkle_distributor::MerkleDistributor,
+        campaign::Campaign, claim_fee::ClaimFee, claim_status::ClaimStatus,
+        claimed_event::ClaimFeeEvent, merkle_distributor::MerkleDistributor,
+    },
+    utils::{
+        calculate_transfer_fee, claim_fee_of, emit_new_claim, seed_new_claim,
+        token_vault_authority, transfer_from_vault, transfer_lamport_fee, ClaimLeaf,
     },
 };
 
@@ -42,18 +39,34 @@ pub struct NewClaim<'info> {
     )]
     pub claim_status: AccountLoader<'info, ClaimStatus>,
 
+    /// CHECK: Claim revocation PDA of the claimant, must not exist
+    #[account(
+        seeds = [
+            b"ClaimRevocation".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = claim_revocation.data_is_empty() @ ErrorCode::ClaimRevoked,
+    )]
+    pub claim_revocation: UncheckedAccount<'info>,
+
     /// Distributor ATA containing the tokens to distribute.
     #[account(
         mut,
-        associated_token::mint = distributor.load()?.mint,
-        associated_token::authority = distributor.key(),
+        token::mint = mint,
+        token::authority = distributor.load()?.token_vault_authority(&distributor.key())?,
         address = distributor.load()?.token_vault
     )]
-    pub from: Account<'info, TokenAccount>,
+    pub from: InterfaceAccount<'info, TokenAccount>,
 
     /// Account to send the claimed tokens to.
-    #[account(mut)]
-    pub to: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = mint)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.load()?.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
 
     /// Who is claiming the tokens.
     #[account(mut)]
@@ -62,11 +75,29 @@ pub struct NewClaim<'info> {
     /// operator
     pub operator: Option<Signer<'info>>,
 
-    /// SPL [Token] program.
-    pub token_program: Program<'info, Token>,
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// The [System] program.
     pub system_program: Program<'info, System>,
+
+    /// The [Campaign] owning the token vault, when the distributor is part of one.
+    #[account(address = distributor.load()?.token_vault_authority(&distributor.key())?)]
+    pub campaign: Option<AccountLoader<'info, Campaign>>,
+
+    /// The [ClaimFee] of the distributor, when it charges one.
+    #[account(
+        seeds = [
+            b"ClaimFee".as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump = claim_fee.load()?.bump,
+    )]
+    pub claim_fee: Option<AccountLoader<'info, ClaimFee>>,
+
+    /// CHECK: Receiver of the claim fee, or its token account for a token fee
+    #[account(mut)]
+    pub fee_receiver: Option<UncheckedAccount<'info>>,
 }
 
 /// Initializes a new claim from the [MerkleDistributor].
@@ -74,11 +105,15 @@ pub struct NewClaim<'info> {
 /// 2. Initializes claim_status
 /// 3. Transfers claim_status.unlocked_amount to the claimant
 /// 4. Increments total_amount_claimed by claim_status.unlocked_amount
+///    (the gross amount leaving the vault, any Token-2022 transfer fee is withheld from the claimant)
+/// 5. Collects the claim fee of the distributor, lamports from the claimant or tokens
+///    out of the claimed amount
 /// CHECK:
-///     1. The claim window has not expired and the distributor has not been clawed back
+///     1. The claim window has not expired and the distributor has not been clawed back or paused
 ///     2. The claimant is the owner of the to account
 ///     3. Num nodes claimed is less than max_num_nodes
 ///     4. The merkle proof is valid
+///     5. The leaf of the claimant has not been revoked
 #[allow(clippy::result_large_err)]
 pub fn handle_new_claim(
     ctx: Context<NewClaim>,
@@ -89,6 +124,7 @@ pub fn handle_new_claim(
     let mut distributor = ctx.accounts.distributor.load_mut()?;
 
     require!(!distributor.clawed_back(), ErrorCode::ClaimExpired);
+    require!(!distributor.paused(), ErrorCode::DistributorPaused);
 
     // check operator
     distributor.authorize_claim(&ctx.accounts.operator)?;
@@ -96,93 +132,89 @@ pub fn handle_new_claim(
     let activation_handler = distributor.get_activation_handler()?;
     activation_handler.validate_claim()?;
 
-    distributor.num_nodes_claimed = distributor
-        .num_nodes_claimed
-        .checked_add(1)
-        .ok_or(ErrorCode::ArithmeticError)?;
-
-    require!(
-        distributor.num_nodes_claimed <= distributor.max_num_nodes,
-        ErrorCode::MaxNodesExceeded
-    );
+    let claim_fee = claim_fee_of(
+        &distributor,
+        &ctx.accounts.claim_fee,
+        &ctx.accounts.fee_receiver,
+        &ctx.accounts.token_program.key(),
+    )?;
 
     let claimant_account = &ctx.accounts.claimant;
-
-    // Verify the merkle proof.
-    let node = hashv(&[
-        &claimant_account.key().to_bytes(),
-        &amount_unlocked.to_le_bytes(),
-        &amount_locked.to_le_bytes(),
-    ]);
-
-    let node = hashv(&[LEAF_PREFIX, &node.to_bytes()]);
-
-    require!(
-        verify(proof, distributor.root, node.to_bytes()),
-        ErrorCode::InvalidProof
-    );
-
     let mut claim_status = ctx.accounts.claim_status.load_init()?;
-
-    // Seed initial values
-    claim_status.distributor = ctx.accounts.distributor.key();
-    claim_status.claimant = claimant_account.key();
-    claim_status.locked_amount = amount_locked;
-    claim_status.locked_amount_withdrawn = 0;
-    claim_status.closable = distributor.closable;
-    claim_status.admin = distributor.admin;
-
-    claim_status.unlocked_amount = amount_unlocked;
-    claim_status.bonus_amount =
-        distributor.get_bonus_for_a_claimaint(amount_unlocked, &activation_handler)?;
-
-    let amount_with_bonus = claim_status.get_total_unlocked_amount()?;
-
-    distributor.total_amount_claimed = distributor
-        .total_amount_claimed
-        .checked_add(amount_with_bonus)
-        .ok_or(ErrorCode::ArithmeticError)?;
-
-    distributor.accumulate_bonus(claim_status.bonus_amount)?;
-
-    require!(
-        distributor.total_amount_claimed <= distributor.max_total_claim,
-        ErrorCode::ExceededMaxClaim
-    );
+    let (fee_amount, amount_to_claimant) = seed_new_claim(
+        &mut distributor,
+        ctx.accounts.distributor.key(),
+        &mut claim_status,
+        &ClaimLeaf::new(claimant_account.key(), amount_unlocked, amount_locked),
+        proof,
+        &activation_handler,
+        claim_fee.as_ref().map(|(claim_fee, _)| claim_fee),
+    )?;
+    let transfer_fee = calculate_transfer_fee(&ctx.accounts.mint, amount_to_claimant)?;
 
     // Note: might get truncated, do not rely on
     msg!(
-        "Created new claim with locked {}, unlocked {} and bonus {} with lockup start:{} end:{}, activation_point {} current_point {}",
+        "Created new claim with locked {}, unlocked {}, bonus {} and transfer fee {} with lockup start:{} end:{}, activation_point {} current_point {}",
         claim_status.locked_amount,
         claim_status.unlocked_amount,
         claim_status.bonus_amount,
+        transfer_fee,
         distributor.start_ts,
         distributor.end_ts,
         activation_handler.activation_point,
         activation_handler.curr_point,
     );
 
-    let signer = distributor.signer();
+    emit_new_claim(&distributor, &claim_status)?;
+
+    if let Some((claim_fee, fee_receiver)) = &claim_fee {
+        emit!(ClaimFeeEvent {
+            distributor: ctx.accounts.distributor.key(),
+            version: distributor.version,
+            claimant: claimant_account.key(),
+            fee_receiver: fee_receiver.key(),
+            lamports: claim_fee.lamports,
+            amount: fee_amount,
+        });
+    }
+
+    let vault_authority = token_vault_authority(
+        &distributor,
+        &ctx.accounts.distributor,
+        &ctx.accounts.campaign,
+    )?;
+    let signer = distributor.vault_signer();
     drop(distributor);
-    let seeds = signer.seeds();
-
-    token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
-                from: ctx.accounts.from.to_account_info(),
-                to: ctx.accounts.to.to_account_info(),
-                authority: ctx.accounts.distributor.to_account_info(),
-            },
-        )
-        .with_signer(&[&seeds[..]]),
-        amount_with_bonus,
+
+    transfer_from_vault(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.from.to_account_info(),
+        &ctx.accounts.mint,
+        ctx.accounts.to.to_account_info(),
+        vault_authority.clone(),
+        &signer,
+        amount_to_claimant,
     )?;
 
-    emit!(NewClaimEvent {
-        claimant: claimant_account.key(),
-        timestamp: Clock::get()?.unix_timestamp
-    });
+    if let Some((claim_fee, fee_receiver)) = claim_fee {
+        if fee_amount > 0 {
+            transfer_from_vault(
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.from.to_account_info(),
+                &ctx.accounts.mint,
+                fee_receiver.clone(),
+                vault_authority,
+                &signer,
+                fee_amount,
+            )?;
+        }
+        transfer_lamport_fee(
+            claimant_account.to_account_info(),
+            fee_receiver,
+            ctx.accounts.system_program.to_account_info(),
+            claim_fee.lamports,
+        )?;
+    }
 
     Ok(())
 }
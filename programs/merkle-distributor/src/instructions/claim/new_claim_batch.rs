@@ -0,0 +1,489 @@
+use anchor_lang::{
+    context::Context,
+    prelude::*,
+    system_program::{self, System},
+    Accounts, Key, Result,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    state::{campaign::Campaign, claim_status::ClaimStatus, merkle_distributor::MerkleDistributor},
+    utils::{
+        calculate_transfer_fee, emit_new_claim, seed_new_claim, token_vault_authority,
+        transfer_from_vault, ClaimLeaf,
+    },
+};
+
+/// Number of remaining accounts passed for each claim of a batch:
+/// distributor, claim_status, claim_revocation, from, to, mint, the token vault authority
+/// (the distributor itself, or its [Campaign]) and the token program of the mint.
+pub const BATCH_CLAIM_ACCOUNTS: usize = 8;
+
+/// Leaf of a claimant in one of the distributors of a batch claim.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BatchClaim {
+    pub amount_unlocked: u64,
+    pub amount_locked: u64,
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// [merkle_distributor::new_claim_batch] accounts.
+/// The accounts of every claim follow in `remaining_accounts`, see [BATCH_CLAIM_ACCOUNTS].
+#[derive(Accounts)]
+pub struct NewClaimBatch<'info> {
+    /// Who is claiming the tokens.
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// operator, shared by the permissioned distributors of the batch
+    pub operator: Option<Signer<'info>>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Initializes a claim in each distributor of the batch, as [crate::handle_new_claim] does.
+/// Mints of the batch can belong to SPL Token or Token-2022, each claim passes its token program.
+/// Any failing claim fails the whole batch.
+/// CHECK:
+///     1. The number of remaining accounts matches the number of claims
+///     2. No distributor appears twice in the batch
+///     3. Each group of remaining accounts is valid for its distributor, see
+///        [check_batch_claim_accounts]
+///     4. Each claim passes the checks of [crate::handle_new_claim]
+#[allow(clippy::result_large_err)]
+pub fn handle_new_claim_batch<'info>(
+    ctx: Context<'_, '_, '_, 'info, NewClaimBatch<'info>>,
+    claims: Vec<BatchClaim>,
+) -> Result<()> {
+    require!(!claims.is_empty(), ErrorCode::InvalidBatchClaim);
+    require!(
+        ctx.remaining_accounts.len() == claims.len() * BATCH_CLAIM_ACCOUNTS,
+        ErrorCode::InvalidBatchClaim
+    );
+    check_unique_distributors(ctx.remaining_accounts)?;
+
+    for (claim, accounts) in claims
+        .into_iter()
+        .zip(ctx.remaining_accounts.chunks(BATCH_CLAIM_ACCOUNTS))
+    {
+        new_claim_in_batch(&ctx, claim, accounts)?;
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::result_large_err)]
+fn new_claim_in_batch<'info>(
+    ctx: &Context<'_, '_, '_, 'info, NewClaimBatch<'info>>,
+    claim: BatchClaim,
+    accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let distributor_account = AccountLoader::<MerkleDistributor>::try_from(&accounts[0])?;
+    let claim_status_info = &accounts[1];
+    let from = InterfaceAccount::<TokenAccount>::try_from(&accounts[3])?;
+    let to = InterfaceAccount::<TokenAccount>::try_from(&accounts[4])?;
+    let mint = InterfaceAccount::<Mint>::try_from(&accounts[5])?;
+    let authority_info = &accounts[6];
+    let token_program = Interface::<TokenInterface>::try_from(&accounts[7])?;
+
+    let distributor_key = distributor_account.key();
+    let claimant_account = &ctx.accounts.claimant;
+    let mut distributor = distributor_account.load_mut()?;
+
+    require!(!distributor.clawed_back(), ErrorCode::ClaimExpired);
+    require!(!distributor.paused(), ErrorCode::DistributorPaused);
+    distributor.check_no_claim_fee()?;
+
+    // check operator
+    distributor.authorize_claim(&ctx.accounts.operator)?;
+
+    check_batch_claim_accounts(
+        &distributor,
+        &distributor_key,
+        &claimant_account.key(),
+        accounts,
+        &to.mint,
+    )?;
+
+    let activation_handler = distributor.get_activation_handler()?;
+    activation_handler.validate_claim()?;
+
+    create_claim_status(ctx, claim_status_info, &distributor_key)?;
+    let claim_status_account =
+        AccountLoader::<ClaimStatus>::try_from_unchecked(&crate::ID, claim_status_info)?;
+    let mut claim_status = claim_status_account.load_init()?;
+
+    let (_, amount_with_bonus) = seed_new_claim(
+        &mut distributor,
+        distributor_key,
+        &mut claim_status,
+        &ClaimLeaf::new(
+            claimant_account.key(),
+            claim.amount_unlocked,
+            claim.amount_locked,
+        ),
+        claim.proof,
+        &activation_handler,
+        None,
+    )?;
+    let transfer_fee = calculate_transfer_fee(&mint, amount_with_bonus)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Created new claim in distributor version {} with locked {}, unlocked {}, bonus {} and transfer fee {}",
+        distributor.version,
+        claim_status.locked_amount,
+        claim_status.unlocked_amount,
+        claim_status.bonus_amount,
+        transfer_fee,
+    );
+
+    emit_new_claim(&distributor, &claim_status)?;
+
+    drop(claim_status);
+    // write the discriminator of the new claim status
+    claim_status_account.exit(&crate::ID)?;
+
+    let campaign = if distributor.in_campaign() {
+        Some(AccountLoader::<Campaign>::try_from(authority_info)?)
+    } else {
+        None
+    };
+    let vault_authority = token_vault_authority(&distributor, &distributor_account, &campaign)?;
+    let signer = distributor.vault_signer();
+    drop(distributor);
+
+    transfer_from_vault(
+        token_program.to_account_info(),
+        from.to_account_info(),
+        &mint,
+        to.to_account_info(),
+        vault_authority,
+        &signer,
+        amount_with_bonus,
+    )?;
+
+    Ok(())
+}
+
+/// Checks the accounts of a claim of the batch against its distributor
+/// 1. claim_status and claim_revocation are the PDAs of the claimant, and the leaf is not revoked
+/// 2. from is the token vault, to and mint match the distributor mint
+/// 3. The vault authority is the distributor, or its campaign
+/// 4. The token program owns the mint
+#[allow(clippy::result_large_err)]
+fn check_batch_claim_accounts(
+    distributor: &MerkleDistributor,
+    distributor_key: &Pubkey,
+    claimant: &Pubkey,
+    accounts: &[AccountInfo],
+    to_mint: &Pubkey,
+) -> Result<()> {
+    let (claim_status, _bump) = claim_status_pda(claimant, distributor_key);
+    require_keys_eq!(
+        accounts[1].key(),
+        claim_status,
+        ErrorCode::InvalidBatchClaim
+    );
+
+    let (claim_revocation, _bump) = claim_revocation_pda(claimant, distributor_key);
+    require_keys_eq!(
+        accounts[2].key(),
+        claim_revocation,
+        ErrorCode::InvalidBatchClaim
+    );
+    require!(accounts[2].data_is_empty(), ErrorCode::ClaimRevoked);
+
+    require_keys_eq!(
+        accounts[3].key(),
+        distributor.token_vault,
+        ErrorCode::InvalidBatchClaim
+    );
+    require_keys_eq!(*to_mint, distributor.mint, ErrorCode::InvalidBatchClaim);
+    require_keys_eq!(
+        accounts[5].key(),
+        distributor.mint,
+        ErrorCode::InvalidBatchClaim
+    );
+    require_keys_eq!(
+        accounts[6].key(),
+        distributor.token_vault_authority(distributor_key)?,
+        ErrorCode::InvalidCampaign
+    );
+    require_keys_eq!(
+        accounts[7].key(),
+        *accounts[5].owner,
+        ErrorCode::InvalidBatchClaim
+    );
+    Ok(())
+}
+
+/// Rejects a batch claiming twice in the same distributor
+#[allow(clippy::result_large_err)]
+fn check_unique_distributors(remaining_accounts: &[AccountInfo]) -> Result<()> {
+    let distributors = remaining_accounts
+        .iter()
+        .step_by(BATCH_CLAIM_ACCOUNTS)
+        .map(|distributor| distributor.key())
+        .collect::<Vec<_>>();
+    for (i, distributor) in distributors.iter().enumerate() {
+        require!(
+            !distributors[..i].contains(distributor),
+            ErrorCode::InvalidBatchClaim
+        );
+    }
+    Ok(())
+}
+
+fn claim_status_pda(claimant: &Pubkey, distributor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"ClaimStatus".as_ref(),
+            claimant.to_bytes().as_ref(),
+            distributor.to_bytes().as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+fn claim_revocation_pda(claimant: &Pubkey, distributor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"ClaimRevocation".as_ref(),
+            claimant.to_bytes().as_ref(),
+            distributor.to_bytes().as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Creates the claim status PDA of the claimant, paid by the claimant.
+/// Mirrors the `init` constraint, including for an address already holding lamports.
+#[allow(clippy::result_large_err)]
+fn create_claim_status<'info>(
+    ctx: &Context<'_, '_, '_, 'info, NewClaimBatch<'info>>,
+    claim_status: &AccountInfo<'info>,
+    distributor: &Pubkey,
+) -> Result<()> {
+    let claimant = &ctx.accounts.claimant;
+    let (_, bump) = claim_status_pda(&claimant.key(), distributor);
+
+    let claimant_key = claimant.key();
+    let seeds = [
+        b"ClaimStatus".as_ref(),
+        claimant_key.as_ref(),
+        distributor.as_ref(),
+        &[bump],
+    ];
+    let space = 8 + ClaimStatus::INIT_SPACE;
+    let rent = Rent::get()?.minimum_balance(space);
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    let lamports = claim_status.lamports();
+    if lamports == 0 {
+        system_program::create_account(
+            CpiContext::new(
+                system_program,
+                system_program::CreateAccount {
+                    from: claimant.to_account_info(),
+                    to: claim_status.clone(),
+                },
+            )
+            .with_signer(&[&seeds[..]]),
+            rent,
+            space as u64,
+            &crate::ID,
+        )?;
+    } else {
+        require_keys_eq!(
+            *claim_status.owner,
+            system_program.key(),
+            ErrorCode::InvalidBatchClaim
+        );
+        let top_up = rent.saturating_sub(lamports);
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: claimant.to_account_info(),
+                        to: claim_status.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Allocate {
+                    account_to_allocate: claim_status.clone(),
+                },
+            )
+            .with_signer(&[&seeds[..]]),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new(
+                system_program,
+                system_program::Assign {
+                    account_to_assign: claim_status.clone(),
+                },
+            )
+            .with_signer(&[&seeds[..]]),
+            &crate::ID,
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::bpf_loader;
+    use anchor_spl::{token, token_2022};
+
+    struct TestAccount {
+        key: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+        owner: Pubkey,
+    }
+
+    impl TestAccount {
+        fn new(key: Pubkey, owner: Pubkey) -> Self {
+            Self {
+                key,
+                lamports: 0,
+                data: vec![],
+                owner,
+            }
+        }
+
+        fn info(&mut self) -> AccountInfo<'_> {
+            AccountInfo::new(
+                &self.key,
+                false,
+                true,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                false,
+                0,
+            )
+        }
+    }
+
+    fn batch_distributor() -> MerkleDistributor {
+        MerkleDistributor {
+            token_vault: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            ..Default::default()
+        }
+    }
+
+    /// Valid accounts of a claim of `claimant` in the distributor
+    fn claim_accounts(
+        distributor: &MerkleDistributor,
+        distributor_key: &Pubkey,
+        claimant: &Pubkey,
+    ) -> Vec<TestAccount> {
+        vec![
+            TestAccount::new(*distributor_key, crate::ID),
+            TestAccount::new(
+                claim_status_pda(claimant, distributor_key).0,
+                system_program::ID,
+            ),
+            TestAccount::new(
+                claim_revocation_pda(claimant, distributor_key).0,
+                system_program::ID,
+            ),
+            TestAccount::new(distributor.token_vault, token::ID),
+            TestAccount::new(Pubkey::new_unique(), token::ID),
+            TestAccount::new(distributor.mint, token::ID),
+            TestAccount::new(*distributor_key, crate::ID),
+            TestAccount::new(token::ID, bpf_loader::ID),
+        ]
+    }
+
+    fn check(
+        distributor: &MerkleDistributor,
+        distributor_key: &Pubkey,
+        claimant: &Pubkey,
+        accounts: &mut [TestAccount],
+    ) -> Result<()> {
+        let infos = accounts.iter_mut().map(|a| a.info()).collect::<Vec<_>>();
+        check_batch_claim_accounts(
+            distributor,
+            distributor_key,
+            claimant,
+            &infos,
+            &distributor.mint,
+        )
+    }
+
+    #[test]
+    fn test_check_batch_claim_accounts() {
+        let distributor = batch_distributor();
+        let distributor_key = Pubkey::new_unique();
+        let claimant = Pubkey::new_unique();
+
+        let mut accounts = claim_accounts(&distributor, &distributor_key, &claimant);
+        assert!(check(&distributor, &distributor_key, &claimant, &mut accounts).is_ok());
+
+        // vault of another distributor
+        let mut accounts = claim_accounts(&distributor, &distributor_key, &claimant);
+        accounts[3].key = Pubkey::new_unique();
+        let err = check(&distributor, &distributor_key, &claimant, &mut accounts).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidBatchClaim.into());
+
+        // claim status of the claimant in another distributor
+        let mut accounts = claim_accounts(&distributor, &distributor_key, &claimant);
+        accounts[1].key = claim_status_pda(&claimant, &Pubkey::new_unique()).0;
+        let err = check(&distributor, &distributor_key, &claimant, &mut accounts).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidBatchClaim.into());
+
+        // the mint belongs to SPL Token, not Token-2022
+        let mut accounts = claim_accounts(&distributor, &distributor_key, &claimant);
+        accounts[7].key = token_2022::ID;
+        let err = check(&distributor, &distributor_key, &claimant, &mut accounts).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidBatchClaim.into());
+    }
+
+    #[test]
+    fn test_check_batch_claim_accounts_revoked_leaf() {
+        let distributor = batch_distributor();
+        let distributor_key = Pubkey::new_unique();
+        let claimant = Pubkey::new_unique();
+
+        let mut accounts = claim_accounts(&distributor, &distributor_key, &claimant);
+        accounts[2].data = vec![0; 8];
+        accounts[2].owner = crate::ID;
+        let err = check(&distributor, &distributor_key, &claimant, &mut accounts).unwrap_err();
+        assert_eq!(err, ErrorCode::ClaimRevoked.into());
+    }
+
+    #[test]
+    fn test_check_unique_distributors() {
+        let claimant = Pubkey::new_unique();
+        let first = batch_distributor();
+        let first_key = Pubkey::new_unique();
+        let second = batch_distributor();
+        let second_key = Pubkey::new_unique();
+
+        let mut accounts = claim_accounts(&first, &first_key, &claimant);
+        accounts.extend(claim_accounts(&second, &second_key, &claimant));
+        let infos = accounts.iter_mut().map(|a| a.info()).collect::<Vec<_>>();
+        assert!(check_unique_distributors(&infos).is_ok());
+
+        // the same distributor twice
+        let mut accounts = claim_accounts(&first, &first_key, &claimant);
+        accounts.extend(claim_accounts(&second, &second_key, &claimant));
+        accounts.extend(claim_accounts(&first, &first_key, &claimant));
+        let infos = accounts.iter_mut().map(|a| a.info()).collect::<Vec<_>>();
+        let err = check_unique_distributors(&infos).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidBatchClaim.into());
+    }
+}
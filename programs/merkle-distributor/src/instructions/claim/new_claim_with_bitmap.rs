@@ -0,0 +1,191 @@
+use anchor_lang::{
+    context::Context, prelude::*, solana_program::hash::hashv, Accounts, Key, Result,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use jito_merkle_verify::verify;
+
+use crate::{
+    error::ErrorCode,
+    instructions::LEAF_PREFIX,
+    state::{
+        campaign::Campaign, claim_bitmap::ClaimBitmap, claimed_event::NewClaimEvent,
+        merkle_distributor::MerkleDistributor,
+    },
+    utils::{calculate_transfer_fee, token_vault_authority, transfer_from_vault},
+};
+
+/// [merkle_distributor::new_claim_with_bitmap] accounts.
+#[derive(Accounts)]
+#[instruction(leaf_index: u64)]
+pub struct NewClaimWithBitmap<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Claim bitmap chunk holding the bit of `leaf_index`
+    #[account(
+        mut,
+        seeds = [
+            b"ClaimBitmap".as_ref(),
+            distributor.key().to_bytes().as_ref(),
+            ClaimBitmap::chunk_index_of(leaf_index).to_le_bytes().as_ref()
+        ],
+        bump = claim_bitmap.load()?.bump,
+    )]
+    pub claim_bitmap: AccountLoader<'info, ClaimBitmap>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = distributor.load()?.token_vault_authority(&distributor.key())?,
+        address = distributor.load()?.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to.
+    #[account(mut, token::mint = mint)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.load()?.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Who is claiming the tokens.
+    pub claimant: Signer<'info>,
+
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [Campaign] owning the token vault, when the distributor is part of one.
+    #[account(address = distributor.load()?.token_vault_authority(&distributor.key())?)]
+    pub campaign: Option<AccountLoader<'info, Campaign>>,
+}
+
+/// Claims the unlocked amount of a leaf from a bitmap [MerkleDistributor].
+/// The leaf is marked in the [ClaimBitmap] instead of creating a ClaimStatus,
+/// so the claimant pays no rent.
+/// 1. Increments num_nodes_claimed by 1
+/// 2. Sets the leaf bit in claim_bitmap
+/// 3. Transfers amount_unlocked and its bonus to the claimant
+/// 4. Increments total_amount_claimed by the transferred amount
+/// CHECK:
+///     1. The claim window has not expired and the distributor has not been clawed back or paused
+///     2. The distributor claim type is PermissionlessWithBitmap
+///     3. The leaf index is within max_num_nodes and has not been claimed
+///     4. The merkle proof is valid
+#[allow(clippy::result_large_err)]
+pub fn handle_new_claim_with_bitmap(
+    ctx: Context<NewClaimWithBitmap>,
+    leaf_index: u64,
+    amount_unlocked: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+
+    require!(!distributor.clawed_back(), ErrorCode::ClaimExpired);
+    require!(!distributor.paused(), ErrorCode::DistributorPaused);
+    distributor.check_no_claim_fee()?;
+
+    distributor.authorize_claim_with_bitmap()?;
+
+    let activation_handler = distributor.get_activation_handler()?;
+    activation_handler.validate_claim()?;
+
+    require!(
+        leaf_index < distributor.max_num_nodes,
+        ErrorCode::InvalidLeafIndex
+    );
+
+    distributor.num_nodes_claimed = distributor
+        .num_nodes_claimed
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        distributor.num_nodes_claimed <= distributor.max_num_nodes,
+        ErrorCode::MaxNodesExceeded
+    );
+
+    let claimant_account = &ctx.accounts.claimant;
+
+    // Verify the merkle proof, bitmap leaves carry no locked amount.
+    let node = hashv(&[
+        &leaf_index.to_le_bytes(),
+        &claimant_account.key().to_bytes(),
+        &amount_unlocked.to_le_bytes(),
+        &0u64.to_le_bytes(),
+    ]);
+
+    let node = hashv(&[LEAF_PREFIX, &node.to_bytes()]);
+
+    require!(
+        verify(proof, distributor.root, node.to_bytes()),
+        ErrorCode::InvalidProof
+    );
+
+    ctx.accounts
+        .claim_bitmap
+        .load_mut()?
+        .set_claimed(leaf_index)?;
+
+    let bonus_amount =
+        distributor.get_bonus_for_a_claimaint(amount_unlocked, &activation_handler)?;
+    let amount_with_bonus = amount_unlocked
+        .checked_add(bonus_amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    let transfer_fee = calculate_transfer_fee(&ctx.accounts.mint, amount_with_bonus)?;
+
+    distributor.total_amount_claimed = distributor
+        .total_amount_claimed
+        .checked_add(amount_with_bonus)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    distributor.accumulate_bonus(bonus_amount)?;
+
+    require!(
+        distributor.total_amount_claimed <= distributor.max_total_claim,
+        ErrorCode::ExceededMaxClaim
+    );
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Claimed leaf {} with unlocked {}, bonus {} and transfer fee {}, activation_point {} current_point {}",
+        leaf_index,
+        amount_unlocked,
+        bonus_amount,
+        transfer_fee,
+        activation_handler.activation_point,
+        activation_handler.curr_point,
+    );
+
+    emit!(NewClaimEvent {
+        claimant: claimant_account.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        unlocked_amount: amount_unlocked,
+        locked_amount: 0,
+        bonus_amount,
+    });
+
+    let vault_authority = token_vault_authority(
+        &distributor,
+        &ctx.accounts.distributor,
+        &ctx.accounts.campaign,
+    )?;
+    let signer = distributor.vault_signer();
+    drop(distributor);
+
+    transfer_from_vault(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.from.to_account_info(),
+        &ctx.accounts.mint,
+        ctx.accounts.to.to_account_info(),
+        vault_authority,
+        &signer,
+        amount_with_bonus,
+    )?;
+
+    Ok(())
+}
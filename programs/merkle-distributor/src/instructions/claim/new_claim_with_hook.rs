@@ -0,0 +1,211 @@
+use anchor_lang::{context::Context, prelude::*, system_program::System, Accounts, Key, Result};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        campaign::Campaign, claim_hook::ClaimHook, claim_status::ClaimStatus,
+        claimed_event::ClaimHookEvent, merkle_distributor::MerkleDistributor,
+    },
+    utils::{
+        calculate_transfer_fee, emit_new_claim, invoke_claim_hook, seed_new_claim,
+        token_vault_authority, transfer_from_vault, ClaimLeaf,
+    },
+};
+
+/// [merkle_distributor::new_claim_with_hook] accounts.
+/// The accounts of the hook follow in `remaining_accounts`, see [crate::utils::HOOK_ON_CLAIM_IX].
+#[derive(Accounts)]
+pub struct NewClaimWithHook<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Claim status PDA
+    #[account(
+        init,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + ClaimStatus::INIT_SPACE,
+        payer = claimant,
+    )]
+    pub claim_status: AccountLoader<'info, ClaimStatus>,
+
+    /// CHECK: Claim revocation PDA of the claimant, must not exist
+    #[account(
+        seeds = [
+            b"ClaimRevocation".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = claim_revocation.data_is_empty() @ ErrorCode::ClaimRevoked,
+    )]
+    pub claim_revocation: UncheckedAccount<'info>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = distributor.load()?.token_vault_authority(&distributor.key())?,
+        address = distributor.load()?.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// The [ClaimHook] of the distributor, signing the hook call.
+    #[account(
+        seeds = [
+            b"ClaimHook".as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump = claim_hook.load()?.bump,
+    )]
+    pub claim_hook: AccountLoader<'info, ClaimHook>,
+
+    /// Token account of the hook program receiving the claimed tokens.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = claim_hook.load()?.hook_authority(),
+    )]
+    pub hook_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.load()?.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Who is claiming the tokens.
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// CHECK: Hook program of the distributor
+    #[account(
+        executable,
+        address = claim_hook.load()?.hook_program @ ErrorCode::InvalidHookProgram,
+    )]
+    pub hook_program: UncheckedAccount<'info>,
+
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+
+    /// The [Campaign] owning the token vault, when the distributor is part of one.
+    #[account(address = distributor.load()?.token_vault_authority(&distributor.key())?)]
+    pub campaign: Option<AccountLoader<'info, Campaign>>,
+}
+
+/// Claims the unlocked amount of a leaf and routes it to the hook program of the [MerkleDistributor].
+/// 1. Increments num_nodes_claimed by 1
+/// 2. Initializes claim_status
+/// 3. Transfers amount_unlocked and its bonus to the hook token account
+/// 4. Increments total_amount_claimed by the transferred amount
+/// 5. Calls the hook program with the claimant, the transferred amount and the remaining accounts,
+///    signed by the claim hook PDA, which owns no tokens
+/// CHECK:
+///     1. The claim window has not expired and the distributor has not been clawed back or paused
+///     2. The distributor claim type is PermissionlessWithHook
+///     3. The hook token account is owned by the hook authority, a PDA of the hook program
+///     4. Num nodes claimed is less than max_num_nodes
+///     5. The merkle proof is valid
+///     6. The leaf of the claimant has not been revoked
+#[allow(clippy::result_large_err)]
+pub fn handle_new_claim_with_hook<'info>(
+    ctx: Context<'_, '_, '_, 'info, NewClaimWithHook<'info>>,
+    amount_unlocked: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+
+    require!(!distributor.clawed_back(), ErrorCode::ClaimExpired);
+    require!(!distributor.paused(), ErrorCode::DistributorPaused);
+    distributor.check_no_claim_fee()?;
+
+    distributor.authorize_claim_with_hook()?;
+
+    let activation_handler = distributor.get_activation_handler()?;
+    activation_handler.validate_claim()?;
+
+    let claimant_account = &ctx.accounts.claimant;
+    let claim_hook = *ctx.accounts.claim_hook.load()?;
+
+    // hook leaves carry no locked amount
+    let mut claim_status = ctx.accounts.claim_status.load_init()?;
+    let (_, amount_with_bonus) = seed_new_claim(
+        &mut distributor,
+        ctx.accounts.distributor.key(),
+        &mut claim_status,
+        &ClaimLeaf::new(claimant_account.key(), amount_unlocked, 0),
+        proof,
+        &activation_handler,
+        None,
+    )?;
+    let transfer_fee = calculate_transfer_fee(&ctx.accounts.mint, amount_with_bonus)?;
+
+    // the hook receives what reaches its token account
+    let amount_to_hook = amount_with_bonus
+        .checked_sub(transfer_fee)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Created new claim with unlocked {}, bonus {} and transfer fee {} routed to hook {}, activation_point {} current_point {}",
+        claim_status.unlocked_amount,
+        claim_status.bonus_amount,
+        transfer_fee,
+        claim_hook.hook_program,
+        activation_handler.activation_point,
+        activation_handler.curr_point,
+    );
+
+    emit_new_claim(&distributor, &claim_status)?;
+
+    emit!(ClaimHookEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        claimant: claimant_account.key(),
+        hook_program: claim_hook.hook_program,
+        hook_token_account: ctx.accounts.hook_token_account.key(),
+        amount: amount_to_hook,
+    });
+
+    let vault_authority = token_vault_authority(
+        &distributor,
+        &ctx.accounts.distributor,
+        &ctx.accounts.campaign,
+    )?;
+    let vault_signer = distributor.vault_signer();
+    let signer = claim_hook.signer();
+    drop(distributor);
+    drop(claim_status);
+    let seeds = signer.seeds();
+
+    transfer_from_vault(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.from.to_account_info(),
+        &ctx.accounts.mint,
+        ctx.accounts.hook_token_account.to_account_info(),
+        vault_authority,
+        &vault_signer,
+        amount_with_bonus,
+    )?;
+
+    invoke_claim_hook(
+        ctx.accounts.hook_program.to_account_info(),
+        ctx.accounts.claim_hook.to_account_info(),
+        ctx.accounts.distributor.to_account_info(),
+        ctx.accounts.claimant.to_account_info(),
+        ctx.accounts.hook_token_account.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.remaining_accounts,
+        amount_to_hook,
+        &seeds[..],
+    )?;
+
+    Ok(())
+}
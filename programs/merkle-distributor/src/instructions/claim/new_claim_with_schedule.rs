@@ -0,0 +1,173 @@
+use anchor_lang::{context::Context, prelude::*, system_program::System, Accounts, Key, Result};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        campaign::Campaign,
+        claim_status::{ClaimStatus, VestingSchedule},
+        merkle_distributor::MerkleDistributor,
+    },
+    utils::{
+        calculate_transfer_fee, emit_new_claim, seed_new_claim, token_vault_authority,
+        transfer_from_vault, ClaimLeaf,
+    },
+};
+
+/// [merkle_distributor::new_claim_with_schedule] accounts.
+#[derive(Accounts)]
+pub struct NewClaimWithSchedule<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Claim status PDA
+    #[account(
+        init,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + ClaimStatus::INIT_SPACE,
+        payer = claimant,
+    )]
+    pub claim_status: AccountLoader<'info, ClaimStatus>,
+
+    /// CHECK: Claim revocation PDA of the claimant, must not exist
+    #[account(
+        seeds = [
+            b"ClaimRevocation".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = claim_revocation.data_is_empty() @ ErrorCode::ClaimRevoked,
+    )]
+    pub claim_revocation: UncheckedAccount<'info>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = distributor.load()?.token_vault_authority(&distributor.key())?,
+        address = distributor.load()?.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to.
+    #[account(mut, token::mint = mint)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.load()?.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Who is claiming the tokens.
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// operator
+    pub operator: Option<Signer<'info>>,
+
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+
+    /// The [Campaign] owning the token vault, when the distributor is part of one.
+    #[account(address = distributor.load()?.token_vault_authority(&distributor.key())?)]
+    pub campaign: Option<AccountLoader<'info, Campaign>>,
+}
+
+/// Initializes a new claim whose locked amount follows the leaf [VestingSchedule]
+/// instead of the distributor lockup.
+/// 1. Increments num_nodes_claimed by 1
+/// 2. Initializes claim_status
+/// 3. Transfers claim_status.unlocked_amount to the claimant
+/// 4. Increments total_amount_claimed by claim_status.unlocked_amount
+///    (the gross amount leaving the vault, any Token-2022 transfer fee is withheld from the claimant)
+/// CHECK:
+///     1. The claim window has not expired and the distributor has not been clawed back or paused
+///     2. The claimant is the owner of the to account
+///     3. Num nodes claimed is less than max_num_nodes
+///     4. The merkle proof is valid
+///     5. The vesting schedule ends before the distributor lockup end
+///     6. The leaf of the claimant has not been revoked
+#[allow(clippy::result_large_err)]
+pub fn handle_new_claim_with_schedule(
+    ctx: Context<NewClaimWithSchedule>,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    vesting_schedule: VestingSchedule,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+
+    vesting_schedule.validate(distributor.end_ts)?;
+
+    require!(!distributor.clawed_back(), ErrorCode::ClaimExpired);
+    require!(!distributor.paused(), ErrorCode::DistributorPaused);
+    distributor.check_no_claim_fee()?;
+
+    // check operator
+    distributor.authorize_claim(&ctx.accounts.operator)?;
+
+    let activation_handler = distributor.get_activation_handler()?;
+    activation_handler.validate_claim()?;
+
+    let claimant_account = &ctx.accounts.claimant;
+    let mut claim_status = ctx.accounts.claim_status.load_init()?;
+    let leaf = ClaimLeaf {
+        vesting_schedule,
+        ..ClaimLeaf::new(claimant_account.key(), amount_unlocked, amount_locked)
+    };
+    let (_, amount_with_bonus) = seed_new_claim(
+        &mut distributor,
+        ctx.accounts.distributor.key(),
+        &mut claim_status,
+        &leaf,
+        proof,
+        &activation_handler,
+        None,
+    )?;
+    let transfer_fee = calculate_transfer_fee(&ctx.accounts.mint, amount_with_bonus)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Created new claim with locked {}, unlocked {}, bonus {} and transfer fee {} with cliff:{} period:{} num_periods:{}, activation_point {} current_point {}",
+        claim_status.locked_amount,
+        claim_status.unlocked_amount,
+        claim_status.bonus_amount,
+        transfer_fee,
+        vesting_schedule.cliff_ts,
+        vesting_schedule.period,
+        vesting_schedule.num_periods,
+        activation_handler.activation_point,
+        activation_handler.curr_point,
+    );
+
+    emit_new_claim(&distributor, &claim_status)?;
+
+    let vault_authority = token_vault_authority(
+        &distributor,
+        &ctx.accounts.distributor,
+        &ctx.accounts.campaign,
+    )?;
+    let signer = distributor.vault_signer();
+    drop(distributor);
+
+    transfer_from_vault(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.from.to_account_info(),
+        &ctx.accounts.mint,
+        ctx.accounts.to.to_account_info(),
+        vault_authority,
+        &signer,
+        amount_with_bonus,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,222 @@
+use anchor_lang::{
+    context::Context, prelude::*, solana_program::sysvar, system_program::System, Accounts, Key,
+    Result,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    state::{campaign::Campaign, claim_status::ClaimStatus, merkle_distributor::MerkleDistributor},
+    utils::{
+        calculate_transfer_fee, emit_new_claim, seed_new_claim, token_vault_authority,
+        transfer_from_vault, verify_ed25519_signature, ClaimLeaf,
+    },
+};
+
+/// Domain tag prefixed to the [ClaimAuthorization] messages, so no other message signed by the
+/// claimant can pass for one
+pub const CLAIM_AUTHORIZATION_DOMAIN: &[u8] = b"merkle-distributor:claim-authorization:";
+
+/// Claim parameters signed off-chain by the claimant.
+/// Replay is prevented by the claim status, which can only be created once per claimant.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ClaimAuthorization {
+    /// The [MerkleDistributor] to claim from
+    pub distributor: Pubkey,
+    /// Unlocked amount of the claimant leaf
+    pub amount_unlocked: u64,
+    /// Locked amount of the claimant leaf
+    pub amount_locked: u64,
+    /// Token account receiving the unlocked tokens
+    pub destination: Pubkey,
+    /// Unix timestamp after which the authorization can't be submitted
+    pub expiry: i64,
+    /// Arbitrary value picked by the claimant, to tell authorizations apart
+    pub nonce: u64,
+}
+
+impl ClaimAuthorization {
+    /// Message signed by the claimant, the domain tag followed by the authorization
+    pub fn to_message(&self) -> Vec<u8> {
+        [CLAIM_AUTHORIZATION_DOMAIN, &self.try_to_vec().unwrap()].concat()
+    }
+}
+
+/// [merkle_distributor::new_claim_with_signature] accounts.
+#[derive(Accounts)]
+pub struct NewClaimWithSignature<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Claim status PDA
+    #[account(
+        init,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + ClaimStatus::INIT_SPACE,
+        payer = payer,
+    )]
+    pub claim_status: AccountLoader<'info, ClaimStatus>,
+
+    /// CHECK: Claim revocation PDA of the claimant, must not exist
+    #[account(
+        seeds = [
+            b"ClaimRevocation".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = claim_revocation.data_is_empty() @ ErrorCode::ClaimRevoked,
+    )]
+    pub claim_revocation: UncheckedAccount<'info>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = distributor.load()?.token_vault_authority(&distributor.key())?,
+        address = distributor.load()?.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to, as signed in the authorization.
+    #[account(mut, token::mint = mint)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.load()?.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Who is claiming the tokens, verified against the ed25519 instruction
+    pub claimant: UncheckedAccount<'info>,
+
+    /// Relayer paying for the claim status and the transaction.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// operator
+    pub operator: Option<Signer<'info>>,
+
+    /// CHECK: Instructions sysvar, to load the ed25519 instruction
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+
+    /// The [Campaign] owning the token vault, when the distributor is part of one.
+    #[account(address = distributor.load()?.token_vault_authority(&distributor.key())?)]
+    pub campaign: Option<AccountLoader<'info, Campaign>>,
+}
+
+/// Initializes a new claim on behalf of a claimant who signed a [ClaimAuthorization] off-chain.
+/// The signature is verified by an ed25519 program instruction placed right before this one,
+/// so any relayer can submit the claim and pay for it.
+/// 1. Increments num_nodes_claimed by 1
+/// 2. Initializes claim_status
+/// 3. Transfers claim_status.unlocked_amount to the destination
+/// 4. Increments total_amount_claimed by claim_status.unlocked_amount
+/// CHECK:
+///     1. The claimant signed the authorization and it has not expired
+///     2. The authorization is for this distributor and the to account
+///     3. The claim window has not expired and the distributor has not been clawed back or paused
+///     4. Num nodes claimed is less than max_num_nodes
+///     5. The merkle proof is valid
+///     6. The leaf of the claimant has not been revoked
+#[allow(clippy::result_large_err)]
+pub fn handle_new_claim_with_signature(
+    ctx: Context<NewClaimWithSignature>,
+    authorization: ClaimAuthorization,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let claimant = ctx.accounts.claimant.key();
+
+    verify_ed25519_signature(
+        &ctx.accounts.instructions_sysvar,
+        &claimant,
+        &authorization.to_message(),
+    )?;
+    require!(
+        authorization.expiry >= Clock::get()?.unix_timestamp,
+        ErrorCode::ClaimAuthorizationExpired
+    );
+    require_keys_eq!(
+        authorization.distributor,
+        ctx.accounts.distributor.key(),
+        ErrorCode::InvalidClaimAuthorization
+    );
+    require_keys_eq!(
+        authorization.destination,
+        ctx.accounts.to.key(),
+        ErrorCode::InvalidClaimAuthorization
+    );
+
+    let amount_unlocked = authorization.amount_unlocked;
+    let amount_locked = authorization.amount_locked;
+
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+
+    require!(!distributor.clawed_back(), ErrorCode::ClaimExpired);
+    require!(!distributor.paused(), ErrorCode::DistributorPaused);
+    distributor.check_no_claim_fee()?;
+
+    // check operator
+    distributor.authorize_claim(&ctx.accounts.operator)?;
+
+    let activation_handler = distributor.get_activation_handler()?;
+    activation_handler.validate_claim()?;
+
+    let mut claim_status = ctx.accounts.claim_status.load_init()?;
+
+    let (_, amount_with_bonus) = seed_new_claim(
+        &mut distributor,
+        ctx.accounts.distributor.key(),
+        &mut claim_status,
+        &ClaimLeaf::new(claimant, amount_unlocked, amount_locked),
+        proof,
+        &activation_handler,
+        None,
+    )?;
+    let transfer_fee = calculate_transfer_fee(&ctx.accounts.mint, amount_with_bonus)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Created new claim relayed by {} with locked {}, unlocked {}, bonus {} and transfer fee {}, nonce {}",
+        ctx.accounts.payer.key(),
+        claim_status.locked_amount,
+        claim_status.unlocked_amount,
+        claim_status.bonus_amount,
+        transfer_fee,
+        authorization.nonce,
+    );
+
+    emit_new_claim(&distributor, &claim_status)?;
+
+    let vault_authority = token_vault_authority(
+        &distributor,
+        &ctx.accounts.distributor,
+        &ctx.accounts.campaign,
+    )?;
+    let signer = distributor.vault_signer();
+    drop(distributor);
+
+    transfer_from_vault(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.from.to_account_info(),
+        &ctx.accounts.mint,
+        ctx.accounts.to.to_account_info(),
+        vault_authority,
+        &signer,
+        amount_with_bonus,
+    )?;
+
+    Ok(())
+}
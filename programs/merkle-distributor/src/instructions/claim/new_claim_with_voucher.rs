@@ -0,0 +1,459 @@
+use anchor_lang::{
+    context::Context, prelude::*, solana_program::sysvar, system_program::System, Accounts, Key,
+    Result,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::ErrorCode,
+    state::{campaign::Campaign, claim_status::ClaimStatus, merkle_distributor::MerkleDistributor},
+    utils::{
+        calculate_transfer_fee, emit_new_claim, seed_new_claim, token_vault_authority,
+        transfer_from_vault, verify_ed25519_signature, ClaimLeaf,
+    },
+};
+
+/// Domain tag prefixed to a serialized [ClaimVoucher], so the operator signature can't be
+/// replayed as another signed payload.
+pub const CLAIM_VOUCHER_DOMAIN: &[u8] = b"merkle-distributor:claim-voucher:";
+
+/// Permission to claim from a permissioned distributor, signed off-chain by the operator.
+/// A voucher can be used by the claimant any number of times until it expires, within its max amount.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ClaimVoucher {
+    /// The [MerkleDistributor] to claim from
+    pub distributor: Pubkey,
+    /// Claimant allowed to claim
+    pub claimant: Pubkey,
+    /// Unix timestamp after which the voucher can't be used
+    pub expiry: i64,
+    /// Maximum amount transferred to the claimant over the whole claim, if any
+    pub max_amount: Option<u64>,
+}
+
+impl ClaimVoucher {
+    /// Message signed by the operator
+    pub fn to_message(&self) -> Vec<u8> {
+        [CLAIM_VOUCHER_DOMAIN, &self.try_to_vec().unwrap()].concat()
+    }
+
+    /// Checks that the voucher was signed by the operator of `distributor`, in the
+    /// ed25519 program instruction preceding the current one, for this claimant,
+    /// and has not expired at `curr_ts`.
+    #[allow(clippy::result_large_err)]
+    pub fn verify(
+        &self,
+        instructions_sysvar: &AccountInfo,
+        distributor: &MerkleDistributor,
+        distributor_key: &Pubkey,
+        claimant: &Pubkey,
+        curr_ts: i64,
+    ) -> Result<()> {
+        distributor.authorize_claim_with_voucher()?;
+
+        verify_ed25519_signature(
+            instructions_sysvar,
+            &distributor.operator,
+            &self.to_message(),
+        )?;
+        require!(self.expiry >= curr_ts, ErrorCode::ClaimVoucherExpired);
+        require_keys_eq!(
+            self.distributor,
+            *distributor_key,
+            ErrorCode::InvalidClaimVoucher
+        );
+        require_keys_eq!(self.claimant, *claimant, ErrorCode::InvalidClaimVoucher);
+        Ok(())
+    }
+
+    /// Caps `amount` withdrawn from the locked amount of `claim_status` to what is left of the
+    /// max amount, counting everything transferred over the claim
+    #[allow(clippy::result_large_err)]
+    pub fn cap_locked_amount(&self, claim_status: &ClaimStatus, amount: u64) -> Result<u64> {
+        match self.max_amount {
+            Some(max_amount) => {
+                let amount_transferred = claim_status
+                    .get_total_unlocked_amount()?
+                    .checked_add(claim_status.locked_amount_withdrawn)
+                    .ok_or(ErrorCode::ArithmeticError)?;
+                require!(
+                    amount_transferred < max_amount,
+                    ErrorCode::ClaimVoucherAmountExceeded
+                );
+                Ok(amount.min(max_amount - amount_transferred))
+            }
+            None => Ok(amount),
+        }
+    }
+}
+
+/// [merkle_distributor::new_claim_with_voucher] accounts.
+#[derive(Accounts)]
+pub struct NewClaimWithVoucher<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Claim status PDA
+    #[account(
+        init,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + ClaimStatus::INIT_SPACE,
+        payer = claimant,
+    )]
+    pub claim_status: AccountLoader<'info, ClaimStatus>,
+
+    /// CHECK: Claim revocation PDA of the claimant, must not exist
+    #[account(
+        seeds = [
+            b"ClaimRevocation".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = claim_revocation.data_is_empty() @ ErrorCode::ClaimRevoked,
+    )]
+    pub claim_revocation: UncheckedAccount<'info>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = distributor.load()?.token_vault_authority(&distributor.key())?,
+        address = distributor.load()?.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Account to send the claimed tokens to.
+    #[account(mut, token::mint = mint)]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.load()?.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Who is claiming the tokens.
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// CHECK: Instructions sysvar, to load the ed25519 instruction
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+
+    /// The [Campaign] owning the token vault, when the distributor is part of one.
+    #[account(address = distributor.load()?.token_vault_authority(&distributor.key())?)]
+    pub campaign: Option<AccountLoader<'info, Campaign>>,
+}
+
+/// Initializes a new claim from a permissioned [MerkleDistributor] with a [ClaimVoucher]
+/// of the operator instead of its signature on the transaction.
+/// The voucher signature is verified by an ed25519 program instruction placed right before this one.
+/// 1. Increments num_nodes_claimed by 1
+/// 2. Initializes claim_status
+/// 3. Transfers claim_status.unlocked_amount to the claimant
+/// 4. Increments total_amount_claimed by claim_status.unlocked_amount
+/// CHECK:
+///     1. The operator signed the voucher for this distributor and claimant, and it has not expired
+///     2. The claim window has not expired and the distributor has not been clawed back or paused
+///     3. Num nodes claimed is less than max_num_nodes
+///     4. The merkle proof is valid
+///     5. The leaf of the claimant has not been revoked
+///     6. The transferred amount is within the voucher max amount
+#[allow(clippy::result_large_err)]
+pub fn handle_new_claim_with_voucher(
+    ctx: Context<NewClaimWithVoucher>,
+    amount_unlocked: u64,
+    amount_locked: u64,
+    voucher: ClaimVoucher,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+
+    require!(!distributor.clawed_back(), ErrorCode::ClaimExpired);
+    require!(!distributor.paused(), ErrorCode::DistributorPaused);
+    distributor.check_no_claim_fee()?;
+
+    let claimant_account = &ctx.accounts.claimant;
+
+    voucher.verify(
+        &ctx.accounts.instructions_sysvar,
+        &distributor,
+        &ctx.accounts.distributor.key(),
+        &claimant_account.key(),
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    let activation_handler = distributor.get_activation_handler()?;
+    activation_handler.validate_claim()?;
+
+    let mut claim_status = ctx.accounts.claim_status.load_init()?;
+
+    let (_, amount_with_bonus) = seed_new_claim(
+        &mut distributor,
+        ctx.accounts.distributor.key(),
+        &mut claim_status,
+        &ClaimLeaf::new(claimant_account.key(), amount_unlocked, amount_locked),
+        proof,
+        &activation_handler,
+        None,
+    )?;
+    if let Some(max_amount) = voucher.max_amount {
+        require!(
+            amount_with_bonus <= max_amount,
+            ErrorCode::ClaimVoucherAmountExceeded
+        );
+    }
+    let transfer_fee = calculate_transfer_fee(&ctx.accounts.mint, amount_with_bonus)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Created new claim with voucher expiring at {}, locked {}, unlocked {}, bonus {} and transfer fee {}",
+        voucher.expiry,
+        claim_status.locked_amount,
+        claim_status.unlocked_amount,
+        claim_status.bonus_amount,
+        transfer_fee,
+    );
+
+    emit_new_claim(&distributor, &claim_status)?;
+
+    let vault_authority = token_vault_authority(
+        &distributor,
+        &ctx.accounts.distributor,
+        &ctx.accounts.campaign,
+    )?;
+    let signer = distributor.vault_signer();
+    drop(distributor);
+
+    transfer_from_vault(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.from.to_account_info(),
+        &ctx.accounts.mint,
+        ctx.accounts.to.to_account_info(),
+        vault_authority,
+        &signer,
+        amount_with_bonus,
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        state::merkle_distributor::ClaimType,
+        utils::ed25519::tests::{ed25519_ix, offsets},
+    };
+    use anchor_lang::solana_program::sysvar::instructions::{
+        construct_instructions_data, store_current_index, BorrowedInstruction,
+    };
+
+    fn voucher_distributor() -> MerkleDistributor {
+        MerkleDistributor {
+            claim_type: ClaimType::Permissioned.into(),
+            operator: Pubkey::new_unique(),
+            ..Default::default()
+        }
+    }
+
+    fn voucher(distributor_key: Pubkey, claimant: Pubkey) -> ClaimVoucher {
+        ClaimVoucher {
+            distributor: distributor_key,
+            claimant,
+            expiry: 100,
+            max_amount: Some(1_000),
+        }
+    }
+
+    /// Verifies `voucher` in a claim preceded by the ed25519 instruction of `signer` over `signed`
+    fn verify(
+        voucher: &ClaimVoucher,
+        signer: &Pubkey,
+        signed: &ClaimVoucher,
+        distributor: &MerkleDistributor,
+        distributor_key: &Pubkey,
+        claimant: &Pubkey,
+        curr_ts: i64,
+    ) -> Result<()> {
+        let message = signed.to_message();
+        let ed25519 = ed25519_ix(signer, &message, offsets(&message));
+        let mut data = construct_instructions_data(&[
+            BorrowedInstruction {
+                program_id: &ed25519.program_id,
+                accounts: vec![],
+                data: &ed25519.data,
+            },
+            BorrowedInstruction {
+                program_id: &crate::ID,
+                accounts: vec![],
+                data: &[],
+            },
+        ]);
+        store_current_index(&mut data, 1);
+
+        let mut lamports = 0;
+        let instructions_sysvar = AccountInfo::new(
+            &sysvar::instructions::ID,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &sysvar::ID,
+            false,
+            0,
+        );
+        voucher.verify(
+            &instructions_sysvar,
+            distributor,
+            distributor_key,
+            claimant,
+            curr_ts,
+        )
+    }
+
+    #[test]
+    fn test_verify_voucher_expiry() {
+        let distributor = voucher_distributor();
+        let distributor_key = Pubkey::new_unique();
+        let claimant = Pubkey::new_unique();
+        let voucher = voucher(distributor_key, claimant);
+        let verify_at = |curr_ts| {
+            verify(
+                &voucher,
+                &distributor.operator,
+                &voucher,
+                &distributor,
+                &distributor_key,
+                &claimant,
+                curr_ts,
+            )
+        };
+
+        assert!(verify_at(100).is_ok());
+        assert_eq!(
+            verify_at(101).unwrap_err(),
+            ErrorCode::ClaimVoucherExpired.into()
+        );
+    }
+
+    #[test]
+    fn test_verify_voucher_of_other_claim() {
+        let distributor = voucher_distributor();
+        let distributor_key = Pubkey::new_unique();
+        let claimant = Pubkey::new_unique();
+
+        // signed by the operator, but for another claimant or distributor
+        for voucher in [
+            voucher(distributor_key, Pubkey::new_unique()),
+            voucher(Pubkey::new_unique(), claimant),
+        ] {
+            assert_eq!(
+                verify(
+                    &voucher,
+                    &distributor.operator,
+                    &voucher,
+                    &distributor,
+                    &distributor_key,
+                    &claimant,
+                    0,
+                )
+                .unwrap_err(),
+                ErrorCode::InvalidClaimVoucher.into()
+            );
+        }
+
+        // signed by someone else than the operator, or over another voucher
+        let voucher = voucher(distributor_key, claimant);
+        let other_voucher = ClaimVoucher {
+            max_amount: None,
+            ..voucher.clone()
+        };
+        for (signer, signed) in [
+            (Pubkey::new_unique(), &voucher),
+            (distributor.operator, &other_voucher),
+        ] {
+            assert_eq!(
+                verify(
+                    &voucher,
+                    &signer,
+                    signed,
+                    &distributor,
+                    &distributor_key,
+                    &claimant,
+                    0,
+                )
+                .unwrap_err(),
+                ErrorCode::InvalidEd25519Instruction.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_cap_locked_amount() {
+        let voucher = ClaimVoucher {
+            max_amount: Some(700),
+            ..voucher(Pubkey::new_unique(), Pubkey::new_unique())
+        };
+        // 300 transferred by new_claim_with_voucher
+        let mut claim_status = ClaimStatus {
+            unlocked_amount: 300,
+            locked_amount: 1_000,
+            ..Default::default()
+        };
+        let (start_ts, end_ts) = (0, 100);
+
+        // repeated claim_locked_with_voucher, the last withdrawal is clamped to the 400 left
+        let mut withdrawn = vec![];
+        for curr_ts in [25, 50, 75] {
+            let amount = claim_status
+                .amount_withdrawable(curr_ts, start_ts, end_ts)
+                .unwrap();
+            match voucher.cap_locked_amount(&claim_status, amount) {
+                Ok(amount) => {
+                    claim_status.locked_amount_withdrawn += amount;
+                    withdrawn.push(amount);
+                }
+                Err(err) => {
+                    assert_eq!(err, ErrorCode::ClaimVoucherAmountExceeded.into());
+                    break;
+                }
+            }
+        }
+        assert_eq!(withdrawn, vec![250, 150]);
+        assert_eq!(claim_status.locked_amount_withdrawn, 400);
+
+        // the cap holds until the end of the lockup
+        let amount = claim_status
+            .amount_withdrawable(end_ts, start_ts, end_ts)
+            .unwrap();
+        assert_eq!(amount, 600);
+        assert_eq!(
+            voucher
+                .cap_locked_amount(&claim_status, amount)
+                .unwrap_err(),
+            ErrorCode::ClaimVoucherAmountExceeded.into()
+        );
+
+        // without max amount, everything vested can be withdrawn
+        let voucher = ClaimVoucher {
+            max_amount: None,
+            ..voucher
+        };
+        assert_eq!(
+            voucher.cap_locked_amount(&claim_status, amount).unwrap(),
+            600
+        );
+    }
+}
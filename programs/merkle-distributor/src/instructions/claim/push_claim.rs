@@ -0,0 +1,168 @@
+use anchor_lang::{context::Context, prelude::*, system_program::System, Accounts, Key, Result};
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    error::ErrorCode,
+    state::{campaign::Campaign, claim_status::ClaimStatus, merkle_distributor::MerkleDistributor},
+    utils::{
+        calculate_transfer_fee, emit_new_claim, seed_new_claim, token_vault_authority,
+        transfer_from_vault, ClaimLeaf,
+    },
+};
+
+/// [merkle_distributor::push_claim] accounts.
+#[derive(Accounts)]
+pub struct PushClaim<'info> {
+    /// The [MerkleDistributor].
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Claim status PDA
+    #[account(
+        init,
+        seeds = [
+            b"ClaimStatus".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + ClaimStatus::INIT_SPACE,
+        payer = payer,
+    )]
+    pub claim_status: AccountLoader<'info, ClaimStatus>,
+
+    /// CHECK: Claim revocation PDA of the claimant, must not exist
+    #[account(
+        seeds = [
+            b"ClaimRevocation".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = claim_revocation.data_is_empty() @ ErrorCode::ClaimRevoked,
+    )]
+    pub claim_revocation: UncheckedAccount<'info>,
+
+    /// Distributor ATA containing the tokens to distribute.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = distributor.load()?.token_vault_authority(&distributor.key())?,
+        address = distributor.load()?.token_vault
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// Associated token account of the claimant, receiving the claimed tokens.
+    #[account(
+        mut,
+        token::mint = mint,
+        address = get_associated_token_address_with_program_id(
+            &claimant.key(),
+            &mint.key(),
+            &token_program.key(),
+        ),
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint to distribute.
+    #[account(address = distributor.load()?.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Owner of the leaf, does not need to sign
+    pub claimant: UncheckedAccount<'info>,
+
+    /// Whoever pushes the claim, paying the rent of the claim status.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// SPL Token or Token-2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+
+    /// The [Campaign] owning the token vault, when the distributor is part of one.
+    #[account(address = distributor.load()?.token_vault_authority(&distributor.key())?)]
+    pub campaign: Option<AccountLoader<'info, Campaign>>,
+}
+
+/// Pushes the claim of a leaf to the associated token account of its claimant.
+/// Anyone can submit it, the claimant does not sign, so push leaves carry no locked amount.
+/// 1. Increments num_nodes_claimed by 1
+/// 2. Initializes claim_status, owned by the claimant
+/// 3. Transfers claim_status.unlocked_amount to the claimant ATA
+/// 4. Increments total_amount_claimed by claim_status.unlocked_amount
+/// CHECK:
+///     1. The claim window has not expired and the distributor has not been clawed back or paused
+///     2. The distributor claim type is PermissionlessPush
+///     3. The to account is the associated token account of the claimant
+///     4. Num nodes claimed is less than max_num_nodes
+///     5. The merkle proof of the leaf, with a locked amount of 0, is valid
+///     6. The leaf of the claimant has not been revoked
+#[allow(clippy::result_large_err)]
+pub fn handle_push_claim(
+    ctx: Context<PushClaim>,
+    amount_unlocked: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let mut distributor = ctx.accounts.distributor.load_mut()?;
+
+    require!(!distributor.clawed_back(), ErrorCode::ClaimExpired);
+    require!(!distributor.paused(), ErrorCode::DistributorPaused);
+    distributor.check_no_claim_fee()?;
+
+    distributor.authorize_push_claim()?;
+
+    let activation_handler = distributor.get_activation_handler()?;
+    activation_handler.validate_claim()?;
+
+    let claimant_account = &ctx.accounts.claimant;
+
+    // push leaves carry no locked amount, the claimant never signs to withdraw one
+    let mut claim_status = ctx.accounts.claim_status.load_init()?;
+    let (_, amount_with_bonus) = seed_new_claim(
+        &mut distributor,
+        ctx.accounts.distributor.key(),
+        &mut claim_status,
+        &ClaimLeaf::new(claimant_account.key(), amount_unlocked, 0),
+        proof,
+        &activation_handler,
+        None,
+    )?;
+    let transfer_fee = calculate_transfer_fee(&ctx.accounts.mint, amount_with_bonus)?;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "Pushed claim of {} by {} with unlocked {}, bonus {} and transfer fee {}",
+        claimant_account.key(),
+        ctx.accounts.payer.key(),
+        claim_status.unlocked_amount,
+        claim_status.bonus_amount,
+        transfer_fee,
+    );
+
+    emit_new_claim(&distributor, &claim_status)?;
+
+    let vault_authority = token_vault_authority(
+        &distributor,
+        &ctx.accounts.distributor,
+        &ctx.accounts.campaign,
+    )?;
+    let signer = distributor.vault_signer();
+    drop(distributor);
+
+    transfer_from_vault(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.from.to_account_info(),
+        &ctx.accounts.mint,
+        ctx.accounts.to.to_account_info(),
+        vault_authority,
+        &signer,
+        amount_with_bonus,
+    )?;
+
+    Ok(())
+}
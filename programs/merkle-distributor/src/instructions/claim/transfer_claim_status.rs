@@ -0,0 +1,62 @@
+use anchor_lang::{accounts::signer::Signer, context::Context, prelude::*, Accounts, Result};
+
+use crate::{
+    error::ErrorCode,
+    state::{
+        claim_status::ClaimStatus, claimed_event::ClaimStatusTransferredEvent,
+        merkle_distributor::MerkleDistributor,
+    },
+};
+
+/// [merkle_distributor::transfer_claim_status] accounts.
+#[derive(Accounts)]
+pub struct TransferClaimStatus<'info> {
+    /// The [MerkleDistributor].
+    pub distributor: AccountLoader<'info, MerkleDistributor>,
+
+    /// Claim Status PDA, still derived from the claimant of the leaf
+    #[account(
+        mut,
+        has_one = distributor,
+        has_one = claimant,
+    )]
+    pub claim_status: AccountLoader<'info, ClaimStatus>,
+
+    /// Current owner of the claim status.
+    pub claimant: Signer<'info>,
+
+    /// New owner of the claim status
+    /// CHECK: this can be any new account
+    pub new_claimant: AccountInfo<'info>,
+}
+
+/// Moves a [ClaimStatus] to a new owner, who withdraws the remaining locked amount
+/// and closes the claim status from then on.
+/// CHECK:
+///     1. The new owner is not the same as the current one
+#[allow(clippy::result_large_err)]
+pub fn handle_transfer_claim_status(ctx: Context<TransferClaimStatus>) -> Result<()> {
+    let old_claimant = ctx.accounts.claimant.key();
+    let new_claimant = ctx.accounts.new_claimant.key();
+    require_keys_neq!(old_claimant, new_claimant, ErrorCode::SameClaimStatusOwner);
+
+    let mut claim_status = ctx.accounts.claim_status.load_mut()?;
+    claim_status.claimant = new_claimant;
+
+    // Note: might get truncated, do not rely on
+    msg!(
+        "transferred claim status from {} to {}",
+        old_claimant,
+        new_claimant
+    );
+
+    emit!(ClaimStatusTransferredEvent {
+        distributor: ctx.accounts.distributor.key(),
+        version: ctx.accounts.distributor.load()?.version,
+        claim_status: ctx.accounts.claim_status.key(),
+        old_claimant,
+        new_claimant,
+    });
+
+    Ok(())
+}
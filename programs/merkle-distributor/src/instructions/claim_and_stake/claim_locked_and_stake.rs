@@ -68,15 +68,18 @@ pub struct ClaimLockedAndStake<'info> {
 
 /// Claim locked tokens as they become unlocked.
 /// Check:
-///     1. The claim window has not expired and the distributor has not been clawed back
+///     1. The claim window has not expired and the distributor has not been clawed back or paused
 ///     2. The withdraw-able amount is greater than 0
 ///     3. The locked amount withdrawn is ≤ than the locked amount
 ///     4. The distributor amount claimed is ≤ than the max total claim
+///     5. The escrow is a max lock, unless the distributor has a lock bonus schedule
 #[allow(clippy::result_large_err)]
 pub fn handle_claim_locked_and_stake(ctx: Context<ClaimLockedAndStake>) -> Result<()> {
     let mut distributor = ctx.accounts.distributor.load_mut()?;
 
     require!(!distributor.clawed_back(), ErrorCode::ClaimExpired);
+    require!(!distributor.paused(), ErrorCode::DistributorPaused);
+    distributor.check_no_claim_fee()?;
 
     // check operator
     distributor.authorize_claim_and_stake(&ctx.accounts.operator)?;
@@ -86,7 +89,7 @@ pub fn handle_claim_locked_and_stake(ctx: Context<ClaimLockedAndStake>) -> Resul
     let curr_ts = Clock::get()?.unix_timestamp;
 
     let escrow = &ctx.accounts.escrow;
-    require!(escrow.is_max_lock, ErrorCode::EscrowIsNotMaxLock);
+    distributor.check_escrow_lock(escrow.is_max_lock)?;
 
     require!(!distributor.clawed_back(), ErrorCode::ClaimExpired);
 
@@ -134,6 +137,14 @@ pub fn handle_claim_locked_and_stake(ctx: Context<ClaimLockedAndStake>) -> Resul
         seconds_after_days,
     );
 
+    emit!(ClaimedEvent {
+        claimant: ctx.accounts.claimant.key(),
+        amount,
+        distributor: ctx.accounts.distributor.key(),
+        version: distributor.version,
+        locked_amount_withdrawn: claim_status.locked_amount_withdrawn,
+    });
+
     let signer = distributor.signer();
     drop(distributor);
     let seeds = signer.seeds();
@@ -156,9 +167,5 @@ pub fn handle_claim_locked_and_stake(ctx: Context<ClaimLockedAndStake>) -> Resul
 
     voter::cpi::increase_locked_amount(cpi_ctx, amount)?;
 
-    emit!(ClaimedEvent {
-        claimant: ctx.accounts.claimant.key(),
-        amount,
-    });
     Ok(())
 }
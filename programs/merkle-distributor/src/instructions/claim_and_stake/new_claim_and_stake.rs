@@ -1,17 +1,16 @@
-use crate::LEAF_PREFIX;
 use crate::{
     error::ErrorCode,
     state::{
-        claim_status::ClaimStatus, claimed_event::NewClaimEvent,
-        merkle_distributor::MerkleDistributor,
+        claim_fee::ClaimFee,
+        claim_status::ClaimStatus,
+        claimed_event::ClaimFeeEvent,
+        lock_bonus_schedule::LockBonusSchedule,
+        merkle_distributor::{MerkleDistributor, MAX_BPS},
     },
+    utils::{claim_fee_of, emit_new_claim, seed_new_claim, transfer_lamport_fee, ClaimLeaf},
 };
-use anchor_lang::{
-    context::Context, prelude::*, solana_program::hash::hashv, system_program::System, Accounts,
-    Key, Result,
-};
-use anchor_spl::token::{Token, TokenAccount};
-use jito_merkle_verify::verify;
+use anchor_lang::{context::Context, prelude::*, system_program::System, Accounts, Key, Result};
+use anchor_spl::token::{self, Token, TokenAccount};
 
 use locked_voter::program::LockedVoter as Voter;
 use locked_voter::{self as voter, Escrow, Locker};
@@ -37,6 +36,18 @@ pub struct NewClaimAndStake<'info> {
     )]
     pub claim_status: AccountLoader<'info, ClaimStatus>,
 
+    /// CHECK: Claim revocation PDA of the claimant, must not exist
+    #[account(
+        seeds = [
+            b"ClaimRevocation".as_ref(),
+            claimant.key().to_bytes().as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump,
+        constraint = claim_revocation.data_is_empty() @ ErrorCode::ClaimRevoked,
+    )]
+    pub claim_revocation: UncheckedAccount<'info>,
+
     /// Distributor ATA containing the tokens to distribute.
     #[account(
         mut,
@@ -73,6 +84,30 @@ pub struct NewClaimAndStake<'info> {
     /// CHECK: escrow_tokens
     #[account(mut)]
     pub escrow_tokens: UncheckedAccount<'info>,
+
+    /// The [ClaimFee] of the distributor, when it charges one.
+    #[account(
+        seeds = [
+            b"ClaimFee".as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump = claim_fee.load()?.bump,
+    )]
+    pub claim_fee: Option<AccountLoader<'info, ClaimFee>>,
+
+    /// CHECK: Receiver of the claim fee, or its token account for a token fee
+    #[account(mut)]
+    pub fee_receiver: Option<UncheckedAccount<'info>>,
+
+    /// The [LockBonusSchedule] of the distributor, when it has one.
+    #[account(
+        seeds = [
+            b"LockBonusSchedule".as_ref(),
+            distributor.key().to_bytes().as_ref()
+        ],
+        bump = lock_bonus_schedule.load()?.bump,
+    )]
+    pub lock_bonus_schedule: Option<AccountLoader<'info, LockBonusSchedule>>,
 }
 
 /// Initializes a new claim from the [MerkleDistributor].
@@ -80,11 +115,17 @@ pub struct NewClaimAndStake<'info> {
 /// 2. Initializes claim_status
 /// 3. Transfers claim_status.unlocked_amount to the claimant
 /// 4. Increments total_amount_claimed by claim_status.unlocked_amount
+/// 5. Collects the claim fee of the distributor, lamports from the claimant or tokens
+///    out of the staked amount
+/// 6. Scales the bonus by the share of the [LockBonusSchedule] tier reached by the escrow lock,
+///    when the distributor has one
 /// CHECK:
-///     1. The claim window has not expired and the distributor has not been clawed back
+///     1. The claim window has not expired and the distributor has not been clawed back or paused
 ///     2. The claimant is the owner of the to account
 ///     3. Num nodes claimed is less than max_num_nodes
 ///     4. The merkle proof is valid
+///     5. The leaf of the claimant has not been revoked
+///     6. The escrow is a max lock, unless the distributor has a [LockBonusSchedule]
 #[allow(clippy::result_large_err)]
 pub fn handle_new_claim_and_stake(
     ctx: Context<NewClaimAndStake>,
@@ -95,80 +136,78 @@ pub fn handle_new_claim_and_stake(
     let mut distributor = ctx.accounts.distributor.load_mut()?;
 
     require!(!distributor.clawed_back(), ErrorCode::ClaimExpired);
+    require!(!distributor.paused(), ErrorCode::DistributorPaused);
 
     // check operator
     distributor.authorize_claim_and_stake(&ctx.accounts.operator)?;
 
     let escrow = &ctx.accounts.escrow;
-    require!(escrow.is_max_lock, ErrorCode::EscrowIsNotMaxLock);
-
-    let activation_handler = distributor.get_activation_handler()?;
+    distributor.check_escrow_lock(escrow.is_max_lock)?;
+    let lock_bonus_bps = if distributor.has_lock_bonus_schedule() {
+        // shorter locks get the share of the bonus of the tier their lock reaches
+        let lock_bonus_schedule = ctx
+            .accounts
+            .lock_bonus_schedule
+            .as_ref()
+            .ok_or(ErrorCode::InvalidLockBonusSchedule)?
+            .load()?;
+        let lock_duration = escrow
+            .escrow_ends_at
+            .saturating_sub(Clock::get()?.unix_timestamp)
+            .max(0) as u64;
+        lock_bonus_schedule.bonus_bps(lock_duration, escrow.is_max_lock)
+    } else {
+        MAX_BPS as u64
+    };
+
+    let activation_handler = distributor
+        .get_activation_handler()?
+        .with_lock_bonus_bps(lock_bonus_bps);
     activation_handler.validate_claim()?;
 
-    distributor.num_nodes_claimed = distributor
-        .num_nodes_claimed
-        .checked_add(1)
-        .ok_or(ErrorCode::ArithmeticError)?;
-
-    require!(
-        distributor.num_nodes_claimed <= distributor.max_num_nodes,
-        ErrorCode::MaxNodesExceeded
-    );
+    let claim_fee = claim_fee_of(
+        &distributor,
+        &ctx.accounts.claim_fee,
+        &ctx.accounts.fee_receiver,
+        &ctx.accounts.token_program.key(),
+    )?;
 
     let claimant_account = &ctx.accounts.claimant;
-
-    // Verify the merkle proof.
-    let node = hashv(&[
-        &claimant_account.key().to_bytes(),
-        &amount_unlocked.to_le_bytes(),
-        &amount_locked.to_le_bytes(),
-    ]);
-
-    let node = hashv(&[LEAF_PREFIX, &node.to_bytes()]);
-
-    require!(
-        verify(proof, distributor.root, node.to_bytes()),
-        ErrorCode::InvalidProof
-    );
-
     let mut claim_status = ctx.accounts.claim_status.load_init()?;
-
-    // Seed initial values
-    claim_status.distributor = ctx.accounts.distributor.key();
-    claim_status.claimant = claimant_account.key();
-    claim_status.locked_amount = amount_locked;
-    claim_status.unlocked_amount = amount_unlocked;
-    claim_status.locked_amount_withdrawn = 0;
-    claim_status.closable = distributor.closable;
-    claim_status.admin = distributor.admin;
-
-    claim_status.bonus_amount =
-        distributor.get_bonus_for_a_claimaint(amount_unlocked, &activation_handler)?;
-
-    let amount_with_bonus = claim_status.get_total_unlocked_amount()?;
-
-    distributor.total_amount_claimed = distributor
-        .total_amount_claimed
-        .checked_add(amount_with_bonus)
-        .ok_or(ErrorCode::ArithmeticError)?;
-
-    distributor.accumulate_bonus(claim_status.bonus_amount)?;
-
-    require!(
-        distributor.total_amount_claimed <= distributor.max_total_claim,
-        ErrorCode::ExceededMaxClaim
-    );
+    let (fee_amount, amount_to_stake) = seed_new_claim(
+        &mut distributor,
+        ctx.accounts.distributor.key(),
+        &mut claim_status,
+        &ClaimLeaf::new(claimant_account.key(), amount_unlocked, amount_locked),
+        proof,
+        &activation_handler,
+        claim_fee.as_ref().map(|(claim_fee, _)| claim_fee),
+    )?;
 
     // Note: might get truncated, do not rely on
     msg!(
-        "Created new claim with locked {}, unlocked {} and bonus {} with lockup start:{} end:{}",
+        "Created new claim with locked {}, unlocked {} and bonus {} ({} bps of the lock bonus) with lockup start:{} end:{}",
         claim_status.locked_amount,
         claim_status.unlocked_amount,
         claim_status.bonus_amount,
+        lock_bonus_bps,
         distributor.start_ts,
         distributor.end_ts,
     );
 
+    emit_new_claim(&distributor, &claim_status)?;
+
+    if let Some((claim_fee, fee_receiver)) = &claim_fee {
+        emit!(ClaimFeeEvent {
+            distributor: ctx.accounts.distributor.key(),
+            version: distributor.version,
+            claimant: claimant_account.key(),
+            fee_receiver: fee_receiver.key(),
+            lamports: claim_fee.lamports,
+            amount: fee_amount,
+        });
+    }
+
     let signer = distributor.signer();
     drop(distributor);
     let seeds = signer.seeds();
@@ -188,12 +227,30 @@ pub fn handle_new_claim_and_stake(
         },
     )
     .with_signer(seeds);
-    voter::cpi::increase_locked_amount(cpi_ctx, amount_with_bonus)?;
-
-    emit!(NewClaimEvent {
-        claimant: claimant_account.key(),
-        timestamp: Clock::get()?.unix_timestamp
-    });
+    voter::cpi::increase_locked_amount(cpi_ctx, amount_to_stake)?;
+
+    if let Some((claim_fee, fee_receiver)) = claim_fee {
+        if fee_amount > 0 {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: ctx.accounts.from.to_account_info(),
+                        to: fee_receiver.clone(),
+                        authority: ctx.accounts.distributor.to_account_info(),
+                    },
+                )
+                .with_signer(seeds),
+                fee_amount,
+            )?;
+        }
+        transfer_lamport_fee(
+            ctx.accounts.claimant.to_account_info(),
+            fee_receiver,
+            ctx.accounts.system_program.to_account_info(),
+            claim_fee.lamports,
+        )?;
+    }
 
     Ok(())
 }
@@ -13,11 +13,13 @@
 #![allow(clippy::too_many_arguments)]
 use anchor_lang::prelude::*;
 use instructions::*;
+use state::claim_status::VestingSchedule;
 
 pub mod error;
 pub mod instructions;
 pub mod math;
 pub mod state;
+pub mod utils;
 use solana_security_txt::security_txt;
 declare_id!("BZuXaMhhTG4cpHkgUHzz6pKhQrV4jdpZjmF5M3zi2HQy");
 
@@ -45,11 +47,29 @@ pub mod merkle_distributor {
         handle_new_distributor(ctx, &params)
     }
 
+    #[allow(clippy::result_large_err)]
+    pub fn new_campaign(ctx: Context<NewCampaign>, clawback_start_ts: i64) -> Result<()> {
+        handle_new_campaign(ctx, clawback_start_ts)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn new_campaign_distributor(
+        ctx: Context<NewCampaignDistributor>,
+        params: NewDistributorParams,
+    ) -> Result<()> {
+        handle_new_campaign_distributor(ctx, &params)
+    }
+
     /// only available in test phase
     #[allow(clippy::result_large_err)]
     pub fn close_distributor(ctx: Context<CloseDistributor>) -> Result<()> {
         handle_close_distributor(ctx)
     }
+
+    #[allow(clippy::result_large_err)]
+    pub fn close_clawed_back_distributor(ctx: Context<CloseClawedBackDistributor>) -> Result<()> {
+        handle_close_clawed_back_distributor(ctx)
+    }
     /// only available in test phase
     #[allow(clippy::result_large_err)]
     pub fn close_claim_status(ctx: Context<CloseClaimStatus>) -> Result<()> {
@@ -69,6 +89,24 @@ pub mod merkle_distributor {
         handle_clawback(ctx)
     }
 
+    #[allow(clippy::result_large_err)]
+    pub fn clawback_campaign(ctx: Context<ClawbackCampaign>) -> Result<()> {
+        handle_clawback_campaign(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn sweep_surplus(ctx: Context<SweepSurplus>) -> Result<()> {
+        handle_sweep_surplus(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn extend_clawback_start_ts(
+        ctx: Context<ExtendClawbackStartTs>,
+        clawback_start_ts: i64,
+    ) -> Result<()> {
+        handle_extend_clawback_start_ts(ctx, clawback_start_ts)
+    }
+
     #[allow(clippy::result_large_err)]
     pub fn set_clawback_receiver(ctx: Context<SetClawbackReceiver>) -> Result<()> {
         handle_set_clawback_receiver(ctx)
@@ -79,11 +117,79 @@ pub mod merkle_distributor {
         handle_set_admin(ctx)
     }
 
+    #[allow(clippy::result_large_err)]
+    pub fn propose_admin(ctx: Context<ProposeAdmin>) -> Result<()> {
+        handle_propose_admin(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        handle_accept_admin(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn cancel_pending_admin(ctx: Context<CancelPendingAdmin>) -> Result<()> {
+        handle_cancel_pending_admin(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn propose_clawback_receiver(ctx: Context<ProposeClawbackReceiver>) -> Result<()> {
+        handle_propose_clawback_receiver(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn accept_clawback_receiver(ctx: Context<AcceptClawbackReceiver>) -> Result<()> {
+        handle_accept_clawback_receiver(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn cancel_pending_clawback_receiver(
+        ctx: Context<CancelPendingClawbackReceiver>,
+    ) -> Result<()> {
+        handle_cancel_pending_clawback_receiver(ctx)
+    }
+
     #[allow(clippy::result_large_err)]
     pub fn set_operator(ctx: Context<SetOperator>, new_operator: Pubkey) -> Result<()> {
         handle_set_operator(ctx, new_operator)
     }
 
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_bitmap(ctx: Context<NewClaimBitmap>, chunk_index: u64) -> Result<()> {
+        handle_new_claim_bitmap(ctx, chunk_index)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn close_claim_bitmap(ctx: Context<CloseClaimBitmap>) -> Result<()> {
+        handle_close_claim_bitmap(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn revoke_claim(
+        ctx: Context<RevokeClaim>,
+        amount_unlocked: u64,
+        amount_locked: u64,
+        vesting_schedule: VestingSchedule,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_revoke_claim(ctx, amount_unlocked, amount_locked, vesting_schedule, proof)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn revoke_vesting(ctx: Context<RevokeVesting>) -> Result<()> {
+        handle_revoke_vesting(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn pause(ctx: Context<SetPause>) -> Result<()> {
+        handle_pause(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn unpause(ctx: Context<SetPause>) -> Result<()> {
+        handle_unpause(ctx)
+    }
+
     //// END ADMIN FUNCTIONS ////
     /// USER FUNCTIONS /////
     #[allow(clippy::result_large_err)]
@@ -96,11 +202,60 @@ pub mod merkle_distributor {
         handle_new_claim(ctx, amount_unlocked, amount_locked, proof)
     }
 
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_with_bitmap(
+        ctx: Context<NewClaimWithBitmap>,
+        leaf_index: u64,
+        amount_unlocked: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_new_claim_with_bitmap(ctx, leaf_index, amount_unlocked, proof)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_with_signature(
+        ctx: Context<NewClaimWithSignature>,
+        authorization: ClaimAuthorization,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_new_claim_with_signature(ctx, authorization, proof)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_with_schedule(
+        ctx: Context<NewClaimWithSchedule>,
+        amount_unlocked: u64,
+        amount_locked: u64,
+        vesting_schedule: VestingSchedule,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_new_claim_with_schedule(ctx, amount_unlocked, amount_locked, vesting_schedule, proof)
+    }
+
     #[allow(clippy::result_large_err)]
     pub fn claim_locked(ctx: Context<ClaimLocked>) -> Result<()> {
         handle_claim_locked(ctx)
     }
 
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_with_voucher(
+        ctx: Context<NewClaimWithVoucher>,
+        amount_unlocked: u64,
+        amount_locked: u64,
+        voucher: ClaimVoucher,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_new_claim_with_voucher(ctx, amount_unlocked, amount_locked, voucher, proof)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn claim_locked_with_voucher(
+        ctx: Context<ClaimLockedWithVoucher>,
+        voucher: ClaimVoucher,
+    ) -> Result<()> {
+        handle_claim_locked_with_voucher(ctx, voucher)
+    }
+
     #[allow(clippy::result_large_err)]
     pub fn new_claim_and_stake(
         ctx: Context<NewClaimAndStake>,
@@ -115,5 +270,47 @@ pub mod merkle_distributor {
     pub fn claim_locked_and_stake(ctx: Context<ClaimLockedAndStake>) -> Result<()> {
         handle_claim_locked_and_stake(ctx)
     }
+
+    #[allow(clippy::result_large_err)]
+    pub fn close_withdrawn_claim_status(ctx: Context<CloseWithdrawnClaimStatus>) -> Result<()> {
+        handle_close_withdrawn_claim_status(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn transfer_claim_status(ctx: Context<TransferClaimStatus>) -> Result<()> {
+        handle_transfer_claim_status(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, NewClaimBatch<'info>>,
+        claims: Vec<BatchClaim>,
+    ) -> Result<()> {
+        handle_new_claim_batch(ctx, claims)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn push_claim(
+        ctx: Context<PushClaim>,
+        amount_unlocked: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_push_claim(ctx, amount_unlocked, proof)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn claim_locked_early(ctx: Context<ClaimLockedEarly>) -> Result<()> {
+        handle_claim_locked_early(ctx)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn new_claim_with_hook<'info>(
+        ctx: Context<'_, '_, '_, 'info, NewClaimWithHook<'info>>,
+        amount_unlocked: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_new_claim_with_hook(ctx, amount_unlocked, proof)
+    }
+
     // END USER FUNCTIONS //
 }
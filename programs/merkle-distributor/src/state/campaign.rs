@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert;
+
+/// Owns a single token vault shared by several [MerkleDistributor] versions,
+/// so that they can be funded and clawed back at once.
+#[account(zero_copy)]
+#[derive(Default, Debug, InitSpace)]
+pub struct Campaign {
+    /// Base key of the campaign, also the base of its distributors.
+    pub base: Pubkey,
+    /// [Mint] of the token to be distributed.
+    pub mint: Pubkey,
+    /// Token Address of the vault shared by the distributors
+    pub token_vault: Pubkey,
+    /// Admin wallet
+    pub admin: Pubkey,
+    /// Clawback receiver
+    pub clawback_receiver: Pubkey,
+    /// Sum of the max_total_claim of the distributors
+    pub max_total_claim: u64,
+    /// Number of distributors drawing from the vault
+    pub num_distributors: u64,
+    /// Clawback start (Unix Timestamp), not before the clawback start of any distributor
+    pub clawback_start_ts: i64,
+    /// Bump seed.
+    pub bump: u8,
+    /// Whether or not the campaign has been clawed back
+    pub clawed_back: u8,
+    /// padding 0
+    pub padding_0: [u8; 6],
+    /// padding 1
+    pub padding_1: [u128; 4],
+}
+
+const_assert!(Campaign::INIT_SPACE == 256);
+
+impl Campaign {
+    pub fn clawed_back(&self) -> bool {
+        self.clawed_back == 1
+    }
+
+    pub fn signer(&self) -> CampaignSigner {
+        CampaignSigner::new(self.base, self.mint, self.bump)
+    }
+}
+
+pub struct CampaignSigner {
+    base: [u8; 32],
+    mint: [u8; 32],
+    bump: [u8; 1],
+}
+
+impl CampaignSigner {
+    /// Signer of the campaign PDA of `base` and `mint`, also used by the campaign distributors
+    pub fn new(base: Pubkey, mint: Pubkey, bump: u8) -> Self {
+        Self {
+            base: base.to_bytes(),
+            mint: mint.to_bytes(),
+            bump: [bump],
+        }
+    }
+
+    pub fn seeds(&self) -> [&[u8]; 4] {
+        [b"Campaign".as_ref(), &self.base, &self.mint, &self.bump]
+    }
+}
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use static_assertions::const_assert;
+
+/// Number of u64 words in a [ClaimBitmap] chunk
+pub const CLAIM_BITMAP_WORDS: usize = 1024;
+
+/// Number of leaves tracked by a single [ClaimBitmap] chunk
+pub const LEAVES_PER_CLAIM_BITMAP: u64 = CLAIM_BITMAP_WORDS as u64 * 64;
+
+/// Records which leaves of a bitmap distributor have been claimed.
+/// Chunk `i` tracks leaf indexes `[i * LEAVES_PER_CLAIM_BITMAP, (i + 1) * LEAVES_PER_CLAIM_BITMAP)`.
+#[account(zero_copy)]
+#[derive(InitSpace)]
+pub struct ClaimBitmap {
+    /// distributor
+    pub distributor: Pubkey,
+    /// admin who paid the rent, refunded when the bitmap is closed
+    pub admin: Pubkey,
+    /// index of this chunk
+    pub chunk_index: u64,
+    /// Bump seed.
+    pub bump: u8,
+    /// padding 0
+    pub padding_0: [u8; 7],
+    /// one bit per leaf, set once the leaf is claimed
+    pub bits: [u64; CLAIM_BITMAP_WORDS],
+}
+
+const_assert!(ClaimBitmap::INIT_SPACE == 8272);
+
+impl ClaimBitmap {
+    /// Returns the chunk holding the bit of a leaf
+    pub fn chunk_index_of(leaf_index: u64) -> u64 {
+        leaf_index / LEAVES_PER_CLAIM_BITMAP
+    }
+
+    fn position(leaf_index: u64) -> (usize, u64) {
+        let bit = leaf_index % LEAVES_PER_CLAIM_BITMAP;
+        ((bit / 64) as usize, 1u64 << (bit % 64))
+    }
+
+    pub fn is_claimed(&self, leaf_index: u64) -> bool {
+        let (word, mask) = Self::position(leaf_index);
+        self.bits[word] & mask != 0
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn set_claimed(&mut self, leaf_index: u64) -> Result<()> {
+        require!(
+            Self::chunk_index_of(leaf_index) == self.chunk_index,
+            ErrorCode::InvalidClaimBitmap
+        );
+        require!(!self.is_claimed(leaf_index), ErrorCode::LeafAlreadyClaimed);
+
+        let (word, mask) = Self::position(leaf_index);
+        self.bits[word] |= mask;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(chunk_index: u64) -> ClaimBitmap {
+        ClaimBitmap {
+            chunk_index,
+            ..bytemuck::Zeroable::zeroed()
+        }
+    }
+
+    #[test]
+    fn test_position() {
+        assert_eq!(ClaimBitmap::position(0), (0, 1));
+        assert_eq!(ClaimBitmap::position(63), (0, 1 << 63));
+        assert_eq!(ClaimBitmap::position(64), (1, 1));
+        assert_eq!(
+            ClaimBitmap::position(LEAVES_PER_CLAIM_BITMAP - 1),
+            (CLAIM_BITMAP_WORDS - 1, 1 << 63)
+        );
+        // the next chunk starts over
+        assert_eq!(ClaimBitmap::position(LEAVES_PER_CLAIM_BITMAP), (0, 1));
+
+        assert_eq!(ClaimBitmap::chunk_index_of(LEAVES_PER_CLAIM_BITMAP - 1), 0);
+        assert_eq!(ClaimBitmap::chunk_index_of(LEAVES_PER_CLAIM_BITMAP), 1);
+    }
+
+    #[test]
+    fn test_set_claimed() {
+        let mut bitmap = chunk(0);
+        for leaf_index in [0, 63, 64, LEAVES_PER_CLAIM_BITMAP - 1] {
+            assert!(!bitmap.is_claimed(leaf_index));
+            bitmap.set_claimed(leaf_index).unwrap();
+            assert!(bitmap.is_claimed(leaf_index));
+        }
+        // neighbours of the word boundaries are untouched
+        assert!(!bitmap.is_claimed(62));
+        assert!(!bitmap.is_claimed(65));
+        assert_eq!(bitmap.bits[0], 1 | 1 << 63);
+        assert_eq!(bitmap.bits[1], 1);
+        assert_eq!(bitmap.bits[CLAIM_BITMAP_WORDS - 1], 1 << 63);
+
+        // double claims
+        for leaf_index in [0, 63, 64, LEAVES_PER_CLAIM_BITMAP - 1] {
+            assert_eq!(
+                bitmap.set_claimed(leaf_index).unwrap_err(),
+                ErrorCode::LeafAlreadyClaimed.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_set_claimed_wrong_chunk() {
+        let mut bitmap = chunk(1);
+        // leaves of the previous and next chunks share bits with this one
+        for leaf_index in [0, LEAVES_PER_CLAIM_BITMAP - 1, 2 * LEAVES_PER_CLAIM_BITMAP] {
+            assert_eq!(
+                bitmap.set_claimed(leaf_index).unwrap_err(),
+                ErrorCode::InvalidClaimBitmap.into()
+            );
+        }
+        assert!(bitmap.bits.iter().all(|word| *word == 0));
+
+        bitmap.set_claimed(LEAVES_PER_CLAIM_BITMAP).unwrap();
+        assert_eq!(bitmap.bits[0], 1);
+        bitmap.set_claimed(2 * LEAVES_PER_CLAIM_BITMAP - 1).unwrap();
+        assert_eq!(bitmap.bits[CLAIM_BITMAP_WORDS - 1], 1 << 63);
+    }
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert;
+
+use crate::{math::SafeMath, state::merkle_distributor::MAX_BPS};
+
+/// Fee charged on every claim of a [MerkleDistributor], either a flat amount of lamports
+/// paid by the claimant or basis points of the claimed tokens.
+#[account(zero_copy)]
+#[derive(Default, Debug, InitSpace)]
+pub struct ClaimFee {
+    /// distributor
+    pub distributor: Pubkey,
+    /// Wallet receiving the fee, token fees go to its associated token account
+    pub fee_receiver: Pubkey,
+    /// Lamports paid by the claimant on every claim
+    pub lamports: u64,
+    /// Basis points of the tokens of every claim, taken out of the claimed amount
+    pub bps: u16,
+    /// Bump seed.
+    pub bump: u8,
+    /// padding 0
+    pub padding_0: [u8; 5],
+    /// padding 1
+    pub padding_1: [u128; 2],
+}
+
+const_assert!(ClaimFee::INIT_SPACE == 112);
+
+impl ClaimFee {
+    /// Part of a claim of `amount` tokens going to the fee receiver, rounded down
+    pub fn token_fee(&self, amount: u64) -> Result<u64> {
+        let fee = (amount as u128)
+            .safe_mul(self.bps.into())?
+            .safe_div(MAX_BPS)? as u64;
+        Ok(fee)
+    }
+}
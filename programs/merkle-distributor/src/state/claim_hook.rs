@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert;
+
+/// Hook program of a PermissionlessWithHook [MerkleDistributor].
+/// Also the signer of the hook calls, it owns no tokens so the hook cannot move the vault.
+#[account(zero_copy)]
+#[derive(Default, Debug, InitSpace)]
+pub struct ClaimHook {
+    /// distributor
+    pub distributor: Pubkey,
+    /// Program called with every claim
+    pub hook_program: Pubkey,
+    /// Bump seed.
+    pub bump: u8,
+    /// padding 0
+    pub padding_0: [u8; 7],
+    /// padding 1
+    pub padding_1: [u128; 2],
+}
+
+const_assert!(ClaimHook::INIT_SPACE == 104);
+
+impl ClaimHook {
+    /// Authority of the token accounts receiving the claims routed to the hook program,
+    /// a PDA of the hook program
+    pub fn hook_authority(&self) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"ClaimHook".as_ref(), self.distributor.as_ref()],
+            &self.hook_program,
+        )
+        .0
+    }
+
+    pub fn signer(&self) -> ClaimHookSigner {
+        ClaimHookSigner {
+            distributor: self.distributor.to_bytes(),
+            bump: [self.bump],
+        }
+    }
+}
+
+pub struct ClaimHookSigner {
+    distributor: [u8; 32],
+    bump: [u8; 1],
+}
+
+impl ClaimHookSigner {
+    pub fn seeds(&self) -> [&[u8]; 3] {
+        [b"ClaimHook".as_ref(), &self.distributor, &self.bump]
+    }
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use static_assertions::const_assert;
+
+/// Marks the leaf of a claimant as revoked by the admin, before it was claimed.
+/// Claims check that this PDA doesn't exist.
+#[account(zero_copy)]
+#[derive(Default, InitSpace)]
+pub struct ClaimRevocation {
+    /// distributor
+    pub distributor: Pubkey,
+    /// Claimant of the revoked leaf
+    pub claimant: Pubkey,
+    /// Amount removed from max_total_claim, leaf amounts and bonus share
+    pub revoked_amount: u64,
+    /// Revocation time (Unix Timestamp)
+    pub revoked_at: i64,
+}
+
+const_assert!(ClaimRevocation::INIT_SPACE == 80);
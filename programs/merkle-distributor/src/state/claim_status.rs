@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::error::ErrorCode::ArithmeticError;
+use crate::error::ErrorCode::{self, ArithmeticError};
 use static_assertions::const_assert;
 
 /// Holds whether or not a claimant has claimed tokens.
@@ -11,7 +11,8 @@ pub struct ClaimStatus {
     pub admin: Pubkey,
     /// distributor
     pub distributor: Pubkey,
-    /// Authority that claimed the tokens.
+    /// Authority that claimed the tokens, or the new owner once transferred.
+    /// The PDA stays derived from the claimant of the leaf.
     pub claimant: Pubkey,
     /// Locked amount  
     pub locked_amount: u64,
@@ -23,10 +24,82 @@ pub struct ClaimStatus {
     pub bonus_amount: u64,
     /// indicate that whether admin can close this account, for testing purpose
     pub closable: u8,
+    /// Whether the admin revoked the unvested locked amount, freezing locked_amount at the vested amount
+    pub revoked: u8,
+    /// Whether the claimant withdrew the whole locked amount early, net of the penalty,
+    /// freezing locked_amount at the amount withdrawn
+    pub exited_early: u8,
     /// padding 0
-    pub padding_0: [u8; 7],
-    /// padding 1
-    pub padding_1: u128,
+    pub padding_0: [u8; 5],
+    /// Vesting schedule of the locked amount, unset when the leaf uses the distributor lockup
+    pub vesting_schedule: VestingSchedule,
+}
+
+/// Cliff and step unlocks of a locked amount, committed in the merkle leaf.
+/// One step of `locked_amount / num_periods` unlocks at `cliff_ts`, then another one every `period` seconds.
+#[zero_copy]
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Default, InitSpace, PartialEq, Eq)]
+pub struct VestingSchedule {
+    /// Timestamp of the first unlock (Unix Timestamp)
+    pub cliff_ts: i64,
+    /// Seconds between two unlocks
+    pub period: u32,
+    /// Number of unlocks, 0 means no schedule
+    pub num_periods: u32,
+}
+
+impl VestingSchedule {
+    pub fn is_set(&self) -> bool {
+        self.num_periods > 0
+    }
+
+    /// Timestamp of the last unlock
+    pub fn end_ts(&self) -> Result<i64> {
+        let steps_after_cliff = (self.num_periods.saturating_sub(1) as i64)
+            .checked_mul(self.period as i64)
+            .ok_or(ArithmeticError)?;
+        let end_ts = self
+            .cliff_ts
+            .checked_add(steps_after_cliff)
+            .ok_or(ArithmeticError)?;
+        Ok(end_ts)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self, distributor_end_ts: i64) -> Result<()> {
+        require!(
+            self.is_set() && (self.num_periods == 1 || self.period > 0),
+            ErrorCode::InvalidVestingSchedule
+        );
+        // everything must unlock before the distributor can be clawed back
+        require!(
+            self.end_ts()? <= distributor_end_ts,
+            ErrorCode::InvalidVestingSchedule
+        );
+        Ok(())
+    }
+
+    /// Amount of `locked_amount` unlocked at `curr_ts`
+    #[allow(clippy::result_large_err)]
+    pub fn unlocked_amount(&self, locked_amount: u64, curr_ts: i64) -> Result<u64> {
+        if curr_ts < self.cliff_ts {
+            return Ok(0);
+        }
+        let elapsed_periods = match self.period {
+            0 => 0,
+            period => curr_ts.checked_sub(self.cliff_ts).ok_or(ArithmeticError)? / period as i64,
+        };
+        let unlocked_periods = (elapsed_periods as u64)
+            .saturating_add(1)
+            .min(self.num_periods as u64);
+
+        let amount = (locked_amount as u128)
+            .checked_mul(unlocked_periods as u128)
+            .ok_or(ArithmeticError)?
+            .checked_div(self.num_periods as u128)
+            .ok_or(ArithmeticError)? as u64;
+        Ok(amount)
+    }
 }
 
 const_assert!(ClaimStatus::INIT_SPACE == 152);
@@ -45,7 +118,8 @@ impl ClaimStatus {
     }
 
     /// Total amount unlocked
-    /// Equal to (time_into_unlock / total_unlock_time) * locked_amount  
+    /// Follows the leaf vesting schedule when it has one, otherwise
+    /// equal to (time_into_unlock / total_unlock_time) * locked_amount  
     /// Multiplication safety:
     ///    The maximum possible product is (2^64 -1) * (2^64 -1) = 2^128 - 2^65 + 1
     ///    which is less than 2^128 - 1 (the maximum value of a u128), meaning that
@@ -61,6 +135,15 @@ impl ClaimStatus {
     ///     Since b is a i64, this is always true, so no truncation can occur
     #[allow(clippy::result_large_err)]
     pub fn unlocked_amount(&self, curr_ts: i64, start_ts: i64, end_ts: i64) -> Result<u64> {
+        // the locked amount left after a revocation or an early exit is fully vested
+        if self.revoked() || self.exited_early() {
+            return Ok(self.locked_amount);
+        }
+        if self.vesting_schedule.is_set() {
+            return self
+                .vesting_schedule
+                .unlocked_amount(self.locked_amount, curr_ts);
+        }
         if curr_ts >= start_ts {
             if curr_ts >= end_ts {
                 Ok(self.locked_amount)
@@ -89,9 +172,49 @@ impl ClaimStatus {
         Ok(amount)
     }
 
+    /// Forfeits `penalty` of the locked amount and freezes locked_amount at the amount withdrawn.
+    /// Returns the amount left to transfer to the claimant.
+    pub fn exit_early(&mut self, penalty: u64) -> Result<u64> {
+        self.locked_amount = self
+            .locked_amount
+            .checked_sub(penalty)
+            .ok_or(ArithmeticError)?;
+        let amount = self
+            .locked_amount
+            .checked_sub(self.locked_amount_withdrawn)
+            .ok_or(ArithmeticError)?;
+        self.locked_amount_withdrawn = self.locked_amount;
+        self.exited_early = 1;
+        Ok(amount)
+    }
+
+    /// Start of the lockup, the leaf schedule cliff when it has one
+    pub fn lockup_start_ts(&self, start_ts: i64) -> i64 {
+        if self.vesting_schedule.is_set() {
+            return self.vesting_schedule.cliff_ts;
+        }
+        start_ts
+    }
+
+    /// End of the lockup, the leaf schedule end when it has one
+    pub fn lockup_end_ts(&self, end_ts: i64) -> Result<i64> {
+        if self.vesting_schedule.is_set() {
+            return self.vesting_schedule.end_ts();
+        }
+        Ok(end_ts)
+    }
+
     pub fn closable(&self) -> bool {
         self.closable == 1
     }
+
+    pub fn revoked(&self) -> bool {
+        self.revoked == 1
+    }
+
+    pub fn exited_early(&self) -> bool {
+        self.exited_early == 1
+    }
 }
 
 // #[test]
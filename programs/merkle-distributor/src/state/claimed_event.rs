@@ -1,5 +1,73 @@
 use anchor_lang::{event, prelude::*};
 
+/// Emitted when a new distributor is created.
+#[event]
+pub struct NewDistributorEvent {
+    /// Distributor that was created.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Mint to distribute.
+    pub mint: Pubkey,
+    /// Admin of the distributor.
+    pub admin: Pubkey,
+    /// Merkle root.
+    pub root: [u8; 32],
+    /// Maximum number of tokens that can be claimed, bonus included.
+    pub max_total_claim: u64,
+    /// Maximum number of nodes.
+    pub max_num_nodes: u64,
+    /// Lockup start (Unix Timestamp).
+    pub start_ts: i64,
+    /// Lockup end (Unix Timestamp).
+    pub end_ts: i64,
+    /// Clawback start (Unix Timestamp).
+    pub clawback_start_ts: i64,
+    /// Activation slot or timestamp.
+    pub activation_point: u64,
+    /// Activation type.
+    pub activation_type: u8,
+    /// Claim type.
+    pub claim_type: u8,
+    /// Total bonus.
+    pub total_bonus: u64,
+    /// Whether the admin can revoke unvested locked amounts.
+    pub revocable: bool,
+    /// Penalty of early exits before the lockup starts (bps), 0 when disabled.
+    pub early_exit_penalty_bps: u16,
+    /// Whether the penalty of early exits goes to the clawback receiver.
+    pub early_exit_penalty_to_clawback: bool,
+    /// Bonus curve type.
+    pub bonus_curve_type: u8,
+    /// Number of tiers of the step bonus curve.
+    pub bonus_num_steps: u16,
+    /// Whether claims pay the fee of the [ClaimFee] PDA of the distributor.
+    pub has_claim_fee: bool,
+    /// Whether the bonus of claim and stake follows the [LockBonusSchedule] PDA of the distributor.
+    pub has_lock_bonus_schedule: bool,
+}
+
+/// Emitted when a distributor and its vault are closed.
+#[event]
+pub struct CloseDistributorEvent {
+    /// Distributor that was closed.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Amount of tokens left in the vault and sent back to the admin, or to the clawback
+    /// receiver when closing a clawed back distributor.
+    pub amount: u64,
+}
+
+/// Emitted when a claim status is closed.
+#[event]
+pub struct CloseClaimStatusEvent {
+    /// Distributor of the claim status.
+    pub distributor: Pubkey,
+    /// User that claimed.
+    pub claimant: Pubkey,
+}
+
 /// Emitted when a new claim is created.
 #[event]
 pub struct NewClaimEvent {
@@ -7,6 +75,16 @@ pub struct NewClaimEvent {
     pub claimant: Pubkey,
     /// Timestamp.
     pub timestamp: i64,
+    /// Distributor claimed from.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Unlocked amount transferred, without the bonus.
+    pub unlocked_amount: u64,
+    /// Locked amount, to be withdrawn with claim_locked.
+    pub locked_amount: u64,
+    /// Bonus transferred along with the unlocked amount.
+    pub bonus_amount: u64,
 }
 
 /// Emitted when tokens are claimed.
@@ -16,4 +94,287 @@ pub struct ClaimedEvent {
     pub claimant: Pubkey,
     /// Amount of tokens to distribute.
     pub amount: u64,
+    /// Distributor claimed from.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Locked amount withdrawn so far, this claim included.
+    pub locked_amount_withdrawn: u64,
+}
+
+/// Emitted when the unclaimed tokens are clawed back.
+#[event]
+pub struct ClawbackEvent {
+    /// Distributor clawed back.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Token account receiving the tokens.
+    pub clawback_receiver: Pubkey,
+    /// Amount of tokens clawed back.
+    pub amount: u64,
+}
+
+/// Emitted when the vault surplus is swept.
+#[event]
+pub struct SweepSurplusEvent {
+    /// Distributor swept.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Token account receiving the tokens.
+    pub clawback_receiver: Pubkey,
+    /// Amount of tokens swept.
+    pub amount: u64,
+}
+
+/// Emitted when the admin changes.
+#[event]
+pub struct SetAdminEvent {
+    /// Distributor updated.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Previous admin.
+    pub old_admin: Pubkey,
+    /// New admin.
+    pub new_admin: Pubkey,
+}
+
+/// Emitted when the pending admin is proposed or cancelled.
+#[event]
+pub struct SetPendingAdminEvent {
+    /// Distributor updated.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Previous pending admin, default if none.
+    pub old_pending_admin: Pubkey,
+    /// New pending admin, default when cancelled.
+    pub new_pending_admin: Pubkey,
+}
+
+/// Emitted when the clawback receiver changes.
+#[event]
+pub struct SetClawbackReceiverEvent {
+    /// Distributor updated.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Previous clawback receiver.
+    pub old_clawback_receiver: Pubkey,
+    /// New clawback receiver.
+    pub new_clawback_receiver: Pubkey,
+}
+
+/// Emitted when the pending clawback receiver is proposed or cancelled.
+#[event]
+pub struct SetPendingClawbackReceiverEvent {
+    /// Distributor updated.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Previous pending clawback receiver, default if none.
+    pub old_pending_clawback_receiver: Pubkey,
+    /// New pending clawback receiver, default when cancelled.
+    pub new_pending_clawback_receiver: Pubkey,
+}
+
+/// Emitted when the operator changes.
+#[event]
+pub struct SetOperatorEvent {
+    /// Distributor updated.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Previous operator.
+    pub old_operator: Pubkey,
+    /// New operator.
+    pub new_operator: Pubkey,
+}
+
+/// Emitted when the activation point changes.
+#[event]
+pub struct SetActivationPointEvent {
+    /// Distributor updated.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Previous activation slot or timestamp.
+    pub old_activation_point: u64,
+    /// New activation slot or timestamp.
+    pub new_activation_point: u64,
+}
+
+/// Emitted when a claim bitmap chunk is created.
+#[event]
+pub struct NewClaimBitmapEvent {
+    /// Distributor of the claim bitmap.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Index of the chunk.
+    pub chunk_index: u64,
+}
+
+/// Emitted when a claim bitmap chunk is closed.
+#[event]
+pub struct CloseClaimBitmapEvent {
+    /// Distributor of the claim bitmap.
+    pub distributor: Pubkey,
+    /// Index of the chunk.
+    pub chunk_index: u64,
+}
+
+/// Emitted when the admin pauses claims.
+#[event]
+pub struct PausedEvent {
+    /// Distributor that was paused.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin unpauses claims.
+#[event]
+pub struct UnpausedEvent {
+    /// Distributor that was unpaused.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin extends the claim window.
+#[event]
+pub struct ClawbackStartExtendedEvent {
+    /// Distributor that was extended.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Previous clawback start (Unix Timestamp).
+    pub old_clawback_start_ts: i64,
+    /// New clawback start (Unix Timestamp).
+    pub new_clawback_start_ts: i64,
+}
+
+/// Emitted when the admin revokes an unclaimed leaf.
+#[event]
+pub struct ClaimRevokedEvent {
+    /// Distributor of the leaf.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Claimant of the revoked leaf.
+    pub claimant: Pubkey,
+    /// Amount removed from max_total_claim.
+    pub revoked_amount: u64,
+}
+
+/// Emitted when the admin revokes the unvested locked amount of a claim status.
+#[event]
+pub struct VestingRevokedEvent {
+    /// Distributor of the claim status.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Claimant of the claim status.
+    pub claimant: Pubkey,
+    /// Locked amount vested at revocation, still withdrawable by the claimant.
+    pub vested_amount: u64,
+    /// Unvested amount returned to the clawback receiver.
+    pub revoked_amount: u64,
+}
+
+/// Emitted when a claimant transfers their claim status to a new owner.
+#[event]
+pub struct ClaimStatusTransferredEvent {
+    /// Distributor of the claim status.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Claim status that was transferred.
+    pub claim_status: Pubkey,
+    /// Previous owner.
+    pub old_claimant: Pubkey,
+    /// New owner.
+    pub new_claimant: Pubkey,
+}
+
+/// Emitted when a campaign is created.
+#[event]
+pub struct NewCampaignEvent {
+    /// Campaign that was created.
+    pub campaign: Pubkey,
+    /// Mint to distribute.
+    pub mint: Pubkey,
+    /// Admin of the campaign.
+    pub admin: Pubkey,
+    /// Clawback start (Unix Timestamp).
+    pub clawback_start_ts: i64,
+}
+
+/// Emitted when the vault of a campaign is clawed back.
+#[event]
+pub struct CampaignClawbackEvent {
+    /// Campaign that was clawed back.
+    pub campaign: Pubkey,
+    /// Token account receiving the tokens.
+    pub clawback_receiver: Pubkey,
+    /// Amount clawed back.
+    pub amount: u64,
+}
+
+/// Emitted when a claimant withdraws the whole locked amount early.
+#[event]
+pub struct EarlyExitEvent {
+    /// Distributor of the claim status.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Claimant of the claim status.
+    pub claimant: Pubkey,
+    /// Amount transferred to the claimant, the vested amount not withdrawn yet included.
+    pub amount: u64,
+    /// Penalty forfeited on the unvested amount.
+    pub penalty: u64,
+    /// Whether the penalty went to the clawback receiver, otherwise it stays in the vault.
+    pub penalty_to_clawback: bool,
+}
+
+/// Emitted when a claim pays the fee of the distributor.
+#[event]
+pub struct ClaimFeeEvent {
+    /// Distributor of the claim.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// Claimant paying the fee.
+    pub claimant: Pubkey,
+    /// Account receiving the fee, the fee receiver or its token account.
+    pub fee_receiver: Pubkey,
+    /// Lamports paid by the claimant.
+    pub lamports: u64,
+    /// Tokens taken out of the claimed amount.
+    pub amount: u64,
+}
+
+/// Emitted when a claim is routed to the hook program of the distributor.
+#[event]
+pub struct ClaimHookEvent {
+    /// Distributor of the claim.
+    pub distributor: Pubkey,
+    /// Version of the airdrop.
+    pub version: u64,
+    /// User that claimed.
+    pub claimant: Pubkey,
+    /// Hook program called with the claim.
+    pub hook_program: Pubkey,
+    /// Token account of the hook receiving the claimed amount.
+    pub hook_token_account: Pubkey,
+    /// Amount transferred to the hook token account, bonus included.
+    pub amount: u64,
 }
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert;
+
+use crate::state::merkle_distributor::MAX_BPS;
+
+/// Maximum number of tiers of a [LockBonusSchedule]
+pub const MAX_LOCK_BONUS_TIERS: usize = 8;
+
+/// Share of the bonus given to claim and stake escrows by remaining lock duration.
+/// Max lock escrows get the full bonus, shorter locks the share of the highest tier they reach.
+#[account(zero_copy)]
+#[derive(Default, Debug, InitSpace)]
+pub struct LockBonusSchedule {
+    /// distributor
+    pub distributor: Pubkey,
+    /// Tiers sorted by increasing lock duration, the first num_tiers are set
+    pub tiers: [LockBonusTier; MAX_LOCK_BONUS_TIERS],
+    /// Number of tiers set
+    pub num_tiers: u8,
+    /// Bump seed.
+    pub bump: u8,
+    /// padding 0
+    pub padding_0: [u8; 6],
+    /// padding 1
+    pub padding_1: [u128; 2],
+}
+
+const_assert!(LockBonusSchedule::INIT_SPACE == 200);
+
+#[zero_copy]
+#[derive(Debug, Default, InitSpace)]
+pub struct LockBonusTier {
+    /// Minimum lock duration left on the escrow (seconds)
+    pub min_lock_duration: u64,
+    /// Share of the bonus given from this lock duration (bps)
+    pub bonus_bps: u16,
+    /// padding 0
+    pub padding_0: [u8; 6],
+}
+
+impl LockBonusSchedule {
+    /// Share of the bonus of an escrow locked for `lock_duration` more seconds (bps),
+    /// 0 below the first tier
+    pub fn bonus_bps(&self, lock_duration: u64, is_max_lock: bool) -> u64 {
+        if is_max_lock {
+            return MAX_BPS as u64;
+        }
+        self.tiers[..self.num_tiers as usize]
+            .iter()
+            .rev()
+            .find(|tier| tier.min_lock_duration <= lock_duration)
+            .map_or(0, |tier| tier.bonus_bps.into())
+    }
+}
@@ -1,11 +1,16 @@
 use crate::error::ErrorCode;
 use crate::math::safe_math::SafeMath;
+use crate::state::campaign::CampaignSigner;
 use anchor_lang::{
     account,
     prelude::{Pubkey, *},
 };
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use static_assertions::const_assert;
+use std::ops::Deref;
+
+/// Basis points of a whole amount
+pub const MAX_BPS: u128 = 10_000;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
@@ -23,6 +28,18 @@ pub enum ClaimType {
     Permissioned,              // 1, require double signing
     PermissionlessWithStaking, // 2, claim and staking
     PermissionedWithStaking,   // 3, require double signing
+    PermissionlessWithBitmap,  // 4, unlocked only, claims tracked in ClaimBitmap by leaf index
+    PermissionlessPush,        // 5, unlocked only, anyone pushes the claim to the claimant ATA
+    PermissionlessWithHook,    // 6, unlocked only, claims routed to the hook program in ClaimHook
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+/// Shape of the bonus of a claimant over time, from the activation point
+pub enum BonusCurveType {
+    Linear,           // 0, grows linearly to the max bonus over vesting_duration
+    Step,             // 1, grows in num_steps equal tiers over vesting_duration
+    ExponentialDecay, // 2, max bonus at activation, halving every vesting_duration
 }
 
 /// State for the account which distributes tokens.
@@ -51,7 +68,8 @@ pub struct MerkleDistributor {
     pub max_total_claim: u64,
     /// Maximum number of nodes in [MerkleDistributor].
     pub max_num_nodes: u64,
-    /// Total amount of tokens that have been claimed.
+    /// Total amount of tokens that have been claimed, or released by a vesting revocation
+    /// or an early exit penalty.
     pub total_amount_claimed: u64,
     /// Number of nodes that have been claimed.
     pub num_nodes_claimed: u64,
@@ -73,12 +91,32 @@ pub struct MerkleDistributor {
     pub clawed_back: u8,
     /// indicate that whether admin can close this pool, for testing purpose
     pub closable: u8,
-    /// Padding 0
-    pub padding_0: [u8; 3],
+    /// Whether or not claims are paused by the admin
+    pub paused: u8,
+    /// Whether or not the admin can revoke the unvested locked amount of claim statuses
+    pub revocable: u8,
+    /// Bump of the [Campaign] owning the token vault, when the distributor is part of one
+    pub campaign_bump: u8,
     // bonus multiplier
     pub airdrop_bonus: AirdropBonus,
+    /// Admin nominated by the current admin, until it accepts
+    pub pending_admin: Pubkey,
+    /// Clawback receiver nominated by the admin, until its owner accepts
+    pub pending_clawback_receiver: Pubkey,
+    /// Whether or not the distributor draws from the token vault of a [Campaign]
+    pub in_campaign: u8,
+    /// Whether the penalty of early exits goes to the clawback receiver, otherwise it stays
+    /// in the vault as surplus
+    pub early_exit_penalty_to_clawback: u8,
+    /// Penalty of claim_locked_early on the unvested amount before the lockup starts (bps),
+    /// decreasing linearly to 0 at the end of the lockup. 0 disables early exits
+    pub early_exit_penalty_bps: u16,
+    /// Whether or not claims pay the fee of the [ClaimFee] of the distributor
+    pub has_claim_fee: u8,
+    /// Whether or not the bonus of claim and stake follows the [LockBonusSchedule] of the distributor
+    pub has_lock_bonus_schedule: u8,
     // padding 2
-    pub padding_2: [u128; 5],
+    pub padding_2: [u8; 2],
 }
 
 #[zero_copy]
@@ -90,6 +128,20 @@ pub struct AirdropBonus {
     pub vesting_duration: u64,
     /// total bonus
     pub total_claimed_bonus: u64,
+    /// bonus curve type, 0 linear, 1 step tiers, 2 exponential decay
+    pub curve_type: u8,
+    /// padding 0
+    pub padding_0: u8,
+    /// number of tiers of the step curve
+    pub num_steps: u16,
+    /// padding 1
+    pub padding_1: [u8; 4],
+}
+
+impl AirdropBonus {
+    pub fn curve_type(&self) -> Result<BonusCurveType> {
+        Ok(BonusCurveType::try_from(self.curve_type).map_err(|_| ErrorCode::InvalidBonusCurve)?)
+    }
 }
 
 pub struct ActivationHandler {
@@ -99,6 +151,9 @@ pub struct ActivationHandler {
     pub activation_point: u64,
     /// bonus multiplier
     pub airdrop_bonus: AirdropBonus,
+    /// share of the bonus given to the claimant (bps), scaled down by the lock duration
+    /// of claim and stake escrows
+    pub lock_bonus_bps: u64,
 }
 
 impl ActivationHandler {
@@ -109,24 +164,70 @@ impl ActivationHandler {
         );
         Ok(())
     }
+    /// Scales the bonus down to `lock_bonus_bps` of the curve
+    pub fn with_lock_bonus_bps(self, lock_bonus_bps: u64) -> Self {
+        Self {
+            lock_bonus_bps,
+            ..self
+        }
+    }
     pub fn get_bonus_for_a_claimaint(&self, max_bonus: u64) -> Result<u64> {
+        let bonus = self.get_curve_bonus(max_bonus)?;
+        let amount = (bonus as u128)
+            .safe_mul(self.lock_bonus_bps.into())?
+            .safe_div(MAX_BPS)? as u64;
+        Ok(amount)
+    }
+    fn get_curve_bonus(&self, max_bonus: u64) -> Result<u64> {
         let curr_point = self.curr_point;
         let start_point = self.activation_point;
-        let end_point = self.airdrop_bonus.vesting_duration.safe_add(start_point)?;
-
-        if curr_point >= start_point {
-            if curr_point >= end_point {
-                Ok(max_bonus)
-            } else {
-                let duration_into_unlock = curr_point.safe_sub(start_point)?;
-                let total_unlock_duration = self.airdrop_bonus.vesting_duration;
-
-                let amount = ((duration_into_unlock as u128).safe_mul(max_bonus as u128)?)
-                    .safe_div(total_unlock_duration as u128)? as u64;
-                Ok(amount)
+        if curr_point < start_point {
+            return Ok(0);
+        }
+        let duration_into_unlock = curr_point.safe_sub(start_point)?;
+        let total_unlock_duration = self.airdrop_bonus.vesting_duration;
+
+        match self.airdrop_bonus.curve_type()? {
+            BonusCurveType::Linear => {
+                if duration_into_unlock >= total_unlock_duration {
+                    Ok(max_bonus)
+                } else {
+                    let amount = ((duration_into_unlock as u128).safe_mul(max_bonus as u128)?)
+                        .safe_div(total_unlock_duration as u128)?
+                        as u64;
+                    Ok(amount)
+                }
+            }
+            BonusCurveType::Step => {
+                if duration_into_unlock >= total_unlock_duration {
+                    Ok(max_bonus)
+                } else {
+                    let num_steps = self.airdrop_bonus.num_steps as u128;
+                    let steps_reached = (duration_into_unlock as u128)
+                        .safe_mul(num_steps)?
+                        .safe_div(total_unlock_duration as u128)?;
+                    let amount = steps_reached
+                        .safe_mul(max_bonus as u128)?
+                        .safe_div(num_steps)? as u64;
+                    Ok(amount)
+                }
+            }
+            BonusCurveType::ExponentialDecay => {
+                // vesting_duration is the half-life, the bonus is interpolated linearly
+                // between two halvings
+                let num_halvings = duration_into_unlock.safe_div(total_unlock_duration)?;
+                if num_halvings >= u64::BITS as u64 {
+                    return Ok(0);
+                }
+                let bonus_before = max_bonus.safe_shr(num_halvings as u32)?;
+                let bonus_after = bonus_before.safe_shr(1)?;
+                let duration_into_halving = duration_into_unlock.safe_rem(total_unlock_duration)?;
+
+                let decay = ((bonus_before.safe_sub(bonus_after)? as u128)
+                    .safe_mul(duration_into_halving as u128)?)
+                .safe_div(total_unlock_duration as u128)? as u64;
+                Ok(bonus_before.safe_sub(decay)?)
             }
-        } else {
-            Ok(0)
         }
     }
 }
@@ -142,6 +243,7 @@ impl MerkleDistributor {
             curr_point,
             activation_point: self.activation_point,
             airdrop_bonus: self.airdrop_bonus,
+            lock_bonus_bps: MAX_BPS as u64,
         })
     }
     pub fn accumulate_bonus(&mut self, bonus: u64) -> Result<()> {
@@ -167,6 +269,42 @@ impl MerkleDistributor {
         let max_bonus = self.get_max_bonus_for_a_claimant(unlocked_amount)?;
         activation_handler.get_bonus_for_a_claimaint(max_bonus)
     }
+
+    /// Removes a revoked leaf amount and its share of the bonus from max_total_claim,
+    /// keeping the bonus ratio of the remaining claimants. Returns the removed amount.
+    pub fn revoke_leaf(&mut self, leaf_amount: u64) -> Result<u64> {
+        let max_total_claim_without_bonus =
+            self.max_total_claim
+                .safe_sub(self.airdrop_bonus.total_bonus)? as u128;
+
+        // round up, so the bonus of the remaining claimants can only shrink
+        let bonus = (leaf_amount as u128)
+            .safe_mul(self.airdrop_bonus.total_bonus as u128)?
+            .safe_add(max_total_claim_without_bonus.safe_sub(1)?)?
+            .safe_div(max_total_claim_without_bonus)? as u64;
+        let bonus = bonus.min(self.airdrop_bonus.total_bonus);
+        let revoked_amount = leaf_amount.safe_add(bonus)?;
+
+        self.airdrop_bonus.total_bonus = self.airdrop_bonus.total_bonus.safe_sub(bonus)?;
+        self.max_total_claim = self.max_total_claim.safe_sub(revoked_amount)?;
+        require!(
+            self.total_amount_claimed <= self.max_total_claim,
+            ErrorCode::ExceededMaxClaim
+        );
+        Ok(revoked_amount)
+    }
+
+    /// Counts an amount no longer owed to anyone in total_amount_claimed. max_total_claim,
+    /// the base of the bonus of the claimants, is left unchanged.
+    pub fn release_amount(&mut self, amount: u64) -> Result<()> {
+        self.total_amount_claimed = self.total_amount_claimed.safe_add(amount)?;
+        require!(
+            self.total_amount_claimed <= self.max_total_claim,
+            ErrorCode::ExceededMaxClaim
+        );
+        Ok(())
+    }
+
     pub fn clawed_back(&self) -> bool {
         self.clawed_back == 1
     }
@@ -174,10 +312,90 @@ impl MerkleDistributor {
         self.clawed_back = 1;
     }
 
+    pub fn paused(&self) -> bool {
+        self.paused == 1
+    }
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused.into();
+    }
+
     pub fn closable(&self) -> bool {
         self.closable == 1
     }
 
+    pub fn revocable(&self) -> bool {
+        self.revocable == 1
+    }
+
+    pub fn in_campaign(&self) -> bool {
+        self.in_campaign == 1
+    }
+
+    pub fn early_exit_penalty_to_clawback(&self) -> bool {
+        self.early_exit_penalty_to_clawback == 1
+    }
+
+    pub fn has_claim_fee(&self) -> bool {
+        self.has_claim_fee == 1
+    }
+
+    pub fn has_lock_bonus_schedule(&self) -> bool {
+        self.has_lock_bonus_schedule == 1
+    }
+
+    /// Claim instructions not collecting the [ClaimFee] are closed to fee charging distributors
+    pub fn check_no_claim_fee(&self) -> Result<()> {
+        require!(!self.has_claim_fee(), ErrorCode::ClaimFeeNotCollected);
+        Ok(())
+    }
+
+    /// Penalty of an early exit at `curr_ts` on an `unvested_amount` locked from
+    /// `lockup_start_ts` to `lockup_end_ts`.
+    /// The full `early_exit_penalty_bps` applies until the lockup starts, then it decreases
+    /// linearly to 0 at the end of the lockup.
+    #[allow(clippy::result_large_err)]
+    pub fn early_exit_penalty(
+        &self,
+        unvested_amount: u64,
+        curr_ts: i64,
+        lockup_start_ts: i64,
+        lockup_end_ts: i64,
+    ) -> Result<u64> {
+        if curr_ts >= lockup_end_ts {
+            return Ok(0);
+        }
+        let full_penalty = (unvested_amount as u128)
+            .checked_mul(self.early_exit_penalty_bps.into())
+            .ok_or(ErrorCode::ArithmeticError)?
+            .checked_div(MAX_BPS)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        if curr_ts <= lockup_start_ts {
+            return Ok(full_penalty as u64);
+        }
+        let remaining_time = lockup_end_ts
+            .checked_sub(curr_ts)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        let total_lockup_time = lockup_end_ts
+            .checked_sub(lockup_start_ts)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        let penalty = full_penalty
+            .checked_mul(remaining_time as u128)
+            .ok_or(ErrorCode::ArithmeticError)?
+            .checked_div(total_lockup_time as u128)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        Ok(penalty as u64)
+    }
+
+    /// Owner of the token vault, the [Campaign] of the distributor when it is part of one
+    pub fn token_vault_authority(&self, distributor: &Pubkey) -> Result<Pubkey> {
+        if !self.in_campaign() {
+            return Ok(*distributor);
+        }
+        let campaign = Pubkey::create_program_address(&self.campaign_signer().seeds(), &crate::ID)
+            .map_err(|_| ErrorCode::InvalidCampaign)?;
+        Ok(campaign)
+    }
+
     pub fn authorize_claim<'info>(&self, operator: &Option<Signer<'info>>) -> Result<()> {
         // check operator
         let claim_type =
@@ -215,6 +433,61 @@ impl MerkleDistributor {
         Ok(())
     }
 
+    /// Claim and stake escrows must be max locks, unless the [LockBonusSchedule] of the
+    /// distributor scales the bonus by lock duration
+    #[allow(clippy::result_large_err)]
+    pub fn check_escrow_lock(&self, is_max_lock: bool) -> Result<()> {
+        require!(
+            is_max_lock || self.has_lock_bonus_schedule(),
+            ErrorCode::EscrowIsNotMaxLock
+        );
+        Ok(())
+    }
+
+    pub fn authorize_claim_with_voucher(&self) -> Result<()> {
+        let claim_type =
+            ClaimType::try_from(self.claim_type).map_err(|_| ErrorCode::TypeCastedError)?;
+
+        require!(
+            claim_type == ClaimType::Permissioned,
+            ErrorCode::InvalidClaimType
+        );
+        Ok(())
+    }
+
+    pub fn authorize_claim_with_bitmap(&self) -> Result<()> {
+        let claim_type =
+            ClaimType::try_from(self.claim_type).map_err(|_| ErrorCode::TypeCastedError)?;
+
+        require!(
+            claim_type == ClaimType::PermissionlessWithBitmap,
+            ErrorCode::InvalidClaimType
+        );
+        Ok(())
+    }
+
+    pub fn authorize_push_claim(&self) -> Result<()> {
+        let claim_type =
+            ClaimType::try_from(self.claim_type).map_err(|_| ErrorCode::TypeCastedError)?;
+
+        require!(
+            claim_type == ClaimType::PermissionlessPush,
+            ErrorCode::InvalidClaimType
+        );
+        Ok(())
+    }
+
+    pub fn authorize_claim_with_hook(&self) -> Result<()> {
+        let claim_type =
+            ClaimType::try_from(self.claim_type).map_err(|_| ErrorCode::TypeCastedError)?;
+
+        require!(
+            claim_type == ClaimType::PermissionlessWithHook,
+            ErrorCode::InvalidClaimType
+        );
+        Ok(())
+    }
+
     pub fn signer(&self) -> MerkleDistributorSigner {
         MerkleDistributorSigner {
             base: self.base.to_bytes(),
@@ -223,6 +496,19 @@ impl MerkleDistributor {
             bump: [self.bump],
         }
     }
+
+    /// Signer of the [Campaign] of the distributor, when it is part of one
+    pub fn campaign_signer(&self) -> CampaignSigner {
+        CampaignSigner::new(self.base, self.mint, self.campaign_bump)
+    }
+
+    /// Signer of the token vault transfers, the [Campaign] of the distributor when it is part of one
+    pub fn vault_signer(&self) -> VaultSigner {
+        if self.in_campaign() {
+            return VaultSigner::Campaign(self.campaign_signer());
+        }
+        VaultSigner::Distributor(self.signer())
+    }
 }
 
 const_assert!(MerkleDistributor::INIT_SPACE == 440);
@@ -246,7 +532,294 @@ impl MerkleDistributorSigner {
     }
 }
 
+/// Owner of the token vault of a distributor, see [MerkleDistributor::vault_signer]
+pub enum VaultSigner {
+    Distributor(MerkleDistributorSigner),
+    Campaign(CampaignSigner),
+}
+
+impl VaultSigner {
+    pub fn seeds(&self) -> VaultSeeds<'_> {
+        match self {
+            Self::Distributor(signer) => VaultSeeds::Distributor(signer.seeds()),
+            Self::Campaign(signer) => VaultSeeds::Campaign(signer.seeds()),
+        }
+    }
+}
+
+/// Seeds of a [VaultSigner], dereferencing to the seed slice
+pub enum VaultSeeds<'a> {
+    Distributor([&'a [u8]; 5]),
+    Campaign([&'a [u8]; 4]),
+}
+
+impl<'a> Deref for VaultSeeds<'a> {
+    type Target = [&'a [u8]];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Distributor(seeds) => seeds,
+            Self::Campaign(seeds) => seeds,
+        }
+    }
+}
+
 // #[test]
 // fn test_size() {
 //     println!("{} ", MerkleDistributor::INIT_SPACE)
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::claim_status::ClaimStatus;
+
+    #[test]
+    fn test_release_amount_keeps_bonus() {
+        let mut distributor = MerkleDistributor {
+            max_total_claim: 1_100,
+            airdrop_bonus: AirdropBonus {
+                total_bonus: 100,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let bonus = distributor.get_max_bonus_for_a_claimant(500).unwrap();
+        assert_eq!(bonus, 50);
+
+        distributor.release_amount(400).unwrap();
+        assert_eq!(distributor.total_amount_claimed, 400);
+        assert_eq!(distributor.max_total_claim, 1_100);
+        assert_eq!(
+            distributor.get_max_bonus_for_a_claimant(500).unwrap(),
+            bonus
+        );
+
+        // the remaining claimants can still claim everything they are owed
+        distributor.release_amount(700).unwrap();
+        assert_eq!(
+            distributor.release_amount(1).unwrap_err(),
+            ErrorCode::ExceededMaxClaim.into()
+        );
+    }
+
+    #[test]
+    fn test_revoke_leaf_keeps_bonus_of_others() {
+        let mut distributor = MerkleDistributor {
+            max_total_claim: 1_100,
+            airdrop_bonus: AirdropBonus {
+                total_bonus: 100,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        // the other leaves of the 1_000 without bonus
+        let others = [1, 299, 300, 67];
+        let bonuses: Vec<u64> = others
+            .iter()
+            .map(|amount| distributor.get_max_bonus_for_a_claimant(*amount).unwrap())
+            .collect();
+
+        // the revoked bonus rounds up, 33.3 of the 333 leaf
+        assert_eq!(distributor.revoke_leaf(333).unwrap(), 367);
+        assert_eq!(distributor.airdrop_bonus.total_bonus, 66);
+        assert_eq!(distributor.max_total_claim, 733);
+
+        for (amount, bonus) in others.iter().zip(bonuses) {
+            assert!(distributor.get_max_bonus_for_a_claimant(*amount).unwrap() <= bonus);
+        }
+        // the remaining bonus still covers the other leaves
+        assert!(
+            others
+                .iter()
+                .map(|amount| distributor.get_max_bonus_for_a_claimant(*amount).unwrap())
+                .sum::<u64>()
+                <= distributor.airdrop_bonus.total_bonus
+        );
+    }
+
+    #[test]
+    fn test_revoke_leaf_exceeded_max_claim() {
+        let distributor = |total_amount_claimed| MerkleDistributor {
+            max_total_claim: 1_100,
+            total_amount_claimed,
+            airdrop_bonus: AirdropBonus {
+                total_bonus: 100,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        // the 200 leaf and its 20 bonus leave 880 to claim, less than claimed
+        assert_eq!(
+            distributor(900).revoke_leaf(200).unwrap_err(),
+            ErrorCode::ExceededMaxClaim.into()
+        );
+
+        let mut distributor = distributor(880);
+        assert_eq!(distributor.revoke_leaf(200).unwrap(), 220);
+        assert_eq!(distributor.max_total_claim, 880);
+    }
+
+    fn curve_handler(curve_type: BonusCurveType, num_steps: u16) -> ActivationHandler {
+        ActivationHandler {
+            curr_point: 0,
+            activation_point: 0,
+            airdrop_bonus: AirdropBonus {
+                vesting_duration: 100,
+                curve_type: curve_type.into(),
+                num_steps,
+                ..Default::default()
+            },
+            lock_bonus_bps: MAX_BPS as u64,
+        }
+    }
+
+    #[test]
+    fn test_step_bonus() {
+        let mut handler = curve_handler(BonusCurveType::Step, 4);
+        let mut bonus = |curr_point| {
+            handler.curr_point = curr_point;
+            handler.get_curve_bonus(1_000).unwrap()
+        };
+        // tier boundaries
+        assert_eq!(bonus(24), 0);
+        assert_eq!(bonus(25), 250);
+        assert_eq!(bonus(49), 250);
+        assert_eq!(bonus(50), 500);
+        assert_eq!(bonus(99), 750);
+        assert_eq!(bonus(100), 1_000);
+        assert_eq!(bonus(1_000), 1_000);
+
+        // 3 steps over 100, a tier is reached once curr_point * 3 / 100 rounds down to it
+        let mut handler = curve_handler(BonusCurveType::Step, 3);
+        let mut bonus = |curr_point| {
+            handler.curr_point = curr_point;
+            handler.get_curve_bonus(1_000).unwrap()
+        };
+        assert_eq!(bonus(33), 0);
+        assert_eq!(bonus(34), 333);
+        assert_eq!(bonus(66), 333);
+        assert_eq!(bonus(67), 666);
+        assert_eq!(bonus(99), 666);
+        assert_eq!(bonus(100), 1_000);
+
+        // step curves without steps fail
+        let mut handler = curve_handler(BonusCurveType::Step, 0);
+        handler.curr_point = 50;
+        assert!(handler.get_curve_bonus(1_000).is_err());
+    }
+
+    #[test]
+    fn test_exponential_decay_bonus() {
+        let mut handler = curve_handler(BonusCurveType::ExponentialDecay, 0);
+        let mut bonus = |curr_point, max_bonus| {
+            handler.curr_point = curr_point;
+            handler.get_curve_bonus(max_bonus).unwrap()
+        };
+        // halving boundaries, linear in between
+        assert_eq!(bonus(0, 1_000), 1_000);
+        assert_eq!(bonus(50, 1_000), 750);
+        assert_eq!(bonus(99, 1_000), 505);
+        assert_eq!(bonus(100, 1_000), 500);
+        assert_eq!(bonus(199, 1_000), 253);
+        assert_eq!(bonus(200, 1_000), 250);
+
+        // 63 halvings leave 1 of u64::MAX, from 64 halvings the bonus is 0
+        assert_eq!(bonus(6_300, u64::MAX), 1);
+        assert_eq!(bonus(6_399, u64::MAX), 1);
+        assert_eq!(bonus(6_400, u64::MAX), 0);
+        assert_eq!(bonus(u64::MAX, u64::MAX), 0);
+    }
+
+    #[test]
+    fn test_check_escrow_lock() {
+        // new_claim_and_stake and claim_locked_and_stake share the check
+        let mut distributor = MerkleDistributor::default();
+        assert!(distributor.check_escrow_lock(true).is_ok());
+        assert_eq!(
+            distributor.check_escrow_lock(false).unwrap_err(),
+            ErrorCode::EscrowIsNotMaxLock.into()
+        );
+
+        distributor.has_lock_bonus_schedule = 1;
+        assert!(distributor.check_escrow_lock(true).is_ok());
+        assert!(distributor.check_escrow_lock(false).is_ok());
+    }
+
+    fn early_exit_distributor() -> MerkleDistributor {
+        MerkleDistributor {
+            max_total_claim: 1_000,
+            early_exit_penalty_bps: 2_000,
+            start_ts: 100,
+            end_ts: 200,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_early_exit_penalty() {
+        let distributor = early_exit_distributor();
+        let penalty = |curr_ts| {
+            distributor
+                .early_exit_penalty(1_000, curr_ts, 100, 200)
+                .unwrap()
+        };
+
+        // full penalty until the lockup starts
+        assert_eq!(penalty(0), 200);
+        assert_eq!(penalty(100), 200);
+        // halfway through the lockup
+        assert_eq!(penalty(150), 100);
+        // no penalty from the end of the lockup
+        assert_eq!(penalty(200), 0);
+        assert_eq!(penalty(300), 0);
+    }
+
+    #[test]
+    fn test_exit_early_after_partial_claim() {
+        let mut distributor = early_exit_distributor();
+        let mut claim_status = ClaimStatus {
+            locked_amount: 1_000,
+            ..Default::default()
+        };
+        let curr_ts = 150;
+
+        // claim_locked withdraws what vested halfway through the lockup
+        let withdrawn = claim_status
+            .amount_withdrawable(curr_ts, distributor.start_ts, distributor.end_ts)
+            .unwrap();
+        assert_eq!(withdrawn, 500);
+        claim_status.locked_amount_withdrawn = withdrawn;
+        distributor.total_amount_claimed = withdrawn;
+
+        let vested_amount = claim_status
+            .unlocked_amount(curr_ts, distributor.start_ts, distributor.end_ts)
+            .unwrap();
+        let penalty = distributor
+            .early_exit_penalty(
+                claim_status.locked_amount - vested_amount,
+                curr_ts,
+                claim_status.lockup_start_ts(distributor.start_ts),
+                claim_status.lockup_end_ts(distributor.end_ts).unwrap(),
+            )
+            .unwrap();
+        assert_eq!(penalty, 50);
+
+        let amount = claim_status.exit_early(penalty).unwrap();
+        assert_eq!(amount, 450);
+        assert_eq!(claim_status.locked_amount, 950);
+        assert_eq!(claim_status.locked_amount_withdrawn, 950);
+        assert!(claim_status.exited_early());
+        assert_eq!(
+            claim_status
+                .amount_withdrawable(curr_ts, distributor.start_ts, distributor.end_ts)
+                .unwrap(),
+            0
+        );
+
+        distributor.total_amount_claimed += amount;
+        distributor.release_amount(penalty).unwrap();
+        assert_eq!(distributor.total_amount_claimed, 1_000);
+        assert_eq!(distributor.max_total_claim, 1_000);
+    }
+}
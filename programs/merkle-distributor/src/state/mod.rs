@@ -1,3 +1,9 @@
+pub mod campaign;
+pub mod claim_bitmap;
+pub mod claim_fee;
+pub mod claim_hook;
+pub mod claim_revocation;
 pub mod claim_status;
 pub mod claimed_event;
+pub mod lock_bonus_schedule;
 pub mod merkle_distributor;
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ErrorCode,
+    state::{campaign::Campaign, merkle_distributor::MerkleDistributor},
+};
+
+/// Returns the owner of the distributor token vault, signing the vault transfers.
+/// That is the distributor itself, or its [Campaign], which must not have been clawed back.
+#[allow(clippy::result_large_err)]
+pub fn token_vault_authority<'info>(
+    distributor: &MerkleDistributor,
+    distributor_account: &AccountLoader<'info, MerkleDistributor>,
+    campaign: &Option<AccountLoader<'info, Campaign>>,
+) -> Result<AccountInfo<'info>> {
+    if !distributor.in_campaign() {
+        return Ok(distributor_account.to_account_info());
+    }
+    let campaign = campaign.as_ref().ok_or(ErrorCode::InvalidCampaign)?;
+    require!(!campaign.load()?.clawed_back(), ErrorCode::ClaimExpired);
+    Ok(campaign.to_account_info())
+}
@@ -0,0 +1,237 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+use jito_merkle_verify::verify;
+
+use crate::{
+    error::ErrorCode,
+    instructions::LEAF_PREFIX,
+    state::{
+        claim_fee::ClaimFee,
+        claim_status::{ClaimStatus, VestingSchedule},
+        claimed_event::NewClaimEvent,
+        merkle_distributor::{ActivationHandler, MerkleDistributor},
+    },
+    utils::release_with_claim_fee,
+};
+
+/// Leaf of a claimant in the merkle tree of a [MerkleDistributor]
+#[derive(Default)]
+pub struct ClaimLeaf {
+    pub claimant: Pubkey,
+    pub amount_unlocked: u64,
+    pub amount_locked: u64,
+    /// Vesting schedule of the locked amount, only part of the leaf when set
+    pub vesting_schedule: VestingSchedule,
+}
+
+impl ClaimLeaf {
+    pub fn new(claimant: Pubkey, amount_unlocked: u64, amount_locked: u64) -> Self {
+        Self {
+            claimant,
+            amount_unlocked,
+            amount_locked,
+            ..Default::default()
+        }
+    }
+
+    /// Hash of the leaf, prefixed with [LEAF_PREFIX]
+    pub fn node(&self) -> [u8; 32] {
+        let claimant = self.claimant.to_bytes();
+        let amount_unlocked = self.amount_unlocked.to_le_bytes();
+        let amount_locked = self.amount_locked.to_le_bytes();
+        let cliff_ts = self.vesting_schedule.cliff_ts.to_le_bytes();
+        let period = self.vesting_schedule.period.to_le_bytes();
+        let num_periods = self.vesting_schedule.num_periods.to_le_bytes();
+
+        let mut fields: Vec<&[u8]> = vec![&claimant, &amount_unlocked, &amount_locked];
+        if self.vesting_schedule.is_set() {
+            fields.extend([&cliff_ts[..], &period[..], &num_periods[..]]);
+        }
+        let node = hashv(&fields);
+        hashv(&[LEAF_PREFIX, &node.to_bytes()]).to_bytes()
+    }
+}
+
+/// Counts the claim of a leaf and seeds its [ClaimStatus], shared by the new claim instructions.
+/// Returns the token fee and the amount to transfer to the claimant.
+/// 1. Increments num_nodes_claimed by 1
+/// 2. Seeds the claim status with the leaf and the bonus of its unlocked amount
+/// 3. Releases the unlocked amount and the bonus, see [release_with_claim_fee]
+/// CHECK:
+///     1. Num nodes claimed is less than max_num_nodes
+///     2. The merkle proof is valid
+#[allow(clippy::result_large_err)]
+pub fn seed_new_claim(
+    distributor: &mut MerkleDistributor,
+    distributor_key: Pubkey,
+    claim_status: &mut ClaimStatus,
+    leaf: &ClaimLeaf,
+    proof: Vec<[u8; 32]>,
+    activation_handler: &ActivationHandler,
+    claim_fee: Option<&ClaimFee>,
+) -> Result<(u64, u64)> {
+    distributor.num_nodes_claimed = distributor
+        .num_nodes_claimed
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    require!(
+        distributor.num_nodes_claimed <= distributor.max_num_nodes,
+        ErrorCode::MaxNodesExceeded
+    );
+
+    require!(
+        verify(proof, distributor.root, leaf.node()),
+        ErrorCode::InvalidProof
+    );
+
+    // Seed initial values
+    claim_status.distributor = distributor_key;
+    claim_status.claimant = leaf.claimant;
+    claim_status.locked_amount = leaf.amount_locked;
+    claim_status.locked_amount_withdrawn = 0;
+    claim_status.closable = distributor.closable;
+    claim_status.admin = distributor.admin;
+    claim_status.vesting_schedule = leaf.vesting_schedule;
+
+    claim_status.unlocked_amount = leaf.amount_unlocked;
+    claim_status.bonus_amount =
+        distributor.get_bonus_for_a_claimaint(leaf.amount_unlocked, activation_handler)?;
+
+    let amount_with_bonus = claim_status.get_total_unlocked_amount()?;
+    let amounts = release_with_claim_fee(distributor, claim_fee, amount_with_bonus)?;
+
+    distributor.accumulate_bonus(claim_status.bonus_amount)?;
+
+    Ok(amounts)
+}
+
+/// Emits the [NewClaimEvent] of a claim status seeded by [seed_new_claim]
+#[allow(clippy::result_large_err)]
+pub fn emit_new_claim(distributor: &MerkleDistributor, claim_status: &ClaimStatus) -> Result<()> {
+    emit!(NewClaimEvent {
+        claimant: claim_status.claimant,
+        timestamp: Clock::get()?.unix_timestamp,
+        distributor: claim_status.distributor,
+        version: distributor.version,
+        unlocked_amount: claim_status.unlocked_amount,
+        locked_amount: claim_status.locked_amount,
+        bonus_amount: claim_status.bonus_amount,
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::merkle_distributor::AirdropBonus;
+
+    fn leaf_distributor(leaf: &ClaimLeaf) -> MerkleDistributor {
+        MerkleDistributor {
+            root: leaf.node(),
+            max_num_nodes: 1,
+            max_total_claim: 1_100,
+            airdrop_bonus: AirdropBonus {
+                total_bonus: 100,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn activation_handler(distributor: &MerkleDistributor) -> ActivationHandler {
+        ActivationHandler {
+            curr_point: 0,
+            activation_point: 0,
+            airdrop_bonus: distributor.airdrop_bonus,
+            lock_bonus_bps: 10_000,
+        }
+    }
+
+    #[test]
+    fn test_claim_leaf_node() {
+        let leaf = ClaimLeaf::new(Pubkey::new_unique(), 600, 400);
+        let node = hashv(&[
+            &leaf.claimant.to_bytes(),
+            &600u64.to_le_bytes(),
+            &400u64.to_le_bytes(),
+        ]);
+        assert_eq!(
+            leaf.node(),
+            hashv(&[LEAF_PREFIX, &node.to_bytes()]).to_bytes()
+        );
+
+        // the vesting schedule is committed in the leaf once set
+        let scheduled = ClaimLeaf {
+            vesting_schedule: VestingSchedule {
+                cliff_ts: 100,
+                period: 10,
+                num_periods: 4,
+            },
+            ..ClaimLeaf::new(leaf.claimant, 600, 400)
+        };
+        assert_ne!(scheduled.node(), leaf.node());
+    }
+
+    #[test]
+    fn test_seed_new_claim() {
+        let leaf = ClaimLeaf::new(Pubkey::new_unique(), 600, 400);
+        let mut distributor = leaf_distributor(&leaf);
+        let handler = activation_handler(&distributor);
+        let distributor_key = Pubkey::new_unique();
+        let mut claim_status = ClaimStatus::default();
+
+        let (fee_amount, amount_to_claimant) = seed_new_claim(
+            &mut distributor,
+            distributor_key,
+            &mut claim_status,
+            &leaf,
+            vec![],
+            &handler,
+            None,
+        )
+        .unwrap();
+        assert_eq!(fee_amount, 0);
+        // 600 unlocked and its 60 bonus
+        assert_eq!(amount_to_claimant, 660);
+        assert_eq!(claim_status.distributor, distributor_key);
+        assert_eq!(claim_status.claimant, leaf.claimant);
+        assert_eq!(claim_status.locked_amount, 400);
+        assert_eq!(claim_status.bonus_amount, 60);
+        assert_eq!(distributor.num_nodes_claimed, 1);
+        assert_eq!(distributor.total_amount_claimed, 660);
+        assert_eq!(distributor.airdrop_bonus.total_claimed_bonus, 60);
+
+        // max_num_nodes is reached
+        let err = seed_new_claim(
+            &mut distributor,
+            distributor_key,
+            &mut ClaimStatus::default(),
+            &leaf,
+            vec![],
+            &handler,
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(err, ErrorCode::MaxNodesExceeded.into());
+    }
+
+    #[test]
+    fn test_seed_new_claim_rejects_other_leaf() {
+        let leaf = ClaimLeaf::new(Pubkey::new_unique(), 600, 400);
+        let mut distributor = leaf_distributor(&leaf);
+        let handler = activation_handler(&distributor);
+
+        // same claimant, with the locked amount moved to unlocked
+        let err = seed_new_claim(
+            &mut distributor,
+            Pubkey::new_unique(),
+            &mut ClaimStatus::default(),
+            &ClaimLeaf::new(leaf.claimant, 1_000, 0),
+            vec![],
+            &handler,
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidProof.into());
+    }
+}
@@ -0,0 +1,192 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+
+use crate::{
+    error::ErrorCode,
+    math::SafeMath,
+    state::{claim_fee::ClaimFee, merkle_distributor::MerkleDistributor},
+};
+
+/// Returns the [ClaimFee] of the distributor and the account receiving it, when it charges one.
+/// Lamport fees go to the fee receiver, token fees to its associated token account.
+#[allow(clippy::result_large_err)]
+pub fn claim_fee_of<'info>(
+    distributor: &MerkleDistributor,
+    claim_fee: &Option<AccountLoader<'info, ClaimFee>>,
+    fee_receiver: &Option<UncheckedAccount<'info>>,
+    token_program: &Pubkey,
+) -> Result<Option<(ClaimFee, AccountInfo<'info>)>> {
+    if !distributor.has_claim_fee() {
+        return Ok(None);
+    }
+    let claim_fee = *claim_fee
+        .as_ref()
+        .ok_or(ErrorCode::InvalidClaimFeeAccount)?
+        .load()?;
+    let fee_receiver = fee_receiver
+        .as_ref()
+        .ok_or(ErrorCode::InvalidClaimFeeAccount)?;
+
+    let expected_fee_receiver = if claim_fee.bps > 0 {
+        get_associated_token_address_with_program_id(
+            &claim_fee.fee_receiver,
+            &distributor.mint,
+            token_program,
+        )
+    } else {
+        claim_fee.fee_receiver
+    };
+    require_keys_eq!(
+        fee_receiver.key(),
+        expected_fee_receiver,
+        ErrorCode::InvalidClaimFeeAccount
+    );
+    Ok(Some((claim_fee, fee_receiver.to_account_info())))
+}
+
+/// Releases a claim of `amount` tokens from the distributor and splits it into the token fee
+/// and the amount going to the claimant.
+/// The token fee is part of the claimed amount, total_amount_claimed counts both.
+#[allow(clippy::result_large_err)]
+pub fn release_with_claim_fee(
+    distributor: &mut MerkleDistributor,
+    claim_fee: Option<&ClaimFee>,
+    amount: u64,
+) -> Result<(u64, u64)> {
+    let fee_amount = match claim_fee {
+        Some(claim_fee) => claim_fee.token_fee(amount)?,
+        None => 0,
+    };
+    let amount_to_claimant = amount.safe_sub(fee_amount)?;
+    distributor.release_amount(amount)?;
+    Ok((fee_amount, amount_to_claimant))
+}
+
+/// Transfers the lamport fee of a claim from the claimant to the fee receiver.
+#[allow(clippy::result_large_err)]
+pub fn transfer_lamport_fee<'info>(
+    claimant: AccountInfo<'info>,
+    fee_receiver: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    lamports: u64,
+) -> Result<()> {
+    if lamports == 0 {
+        return Ok(());
+    }
+    system_program::transfer(
+        CpiContext::new(
+            system_program,
+            system_program::Transfer {
+                from: claimant,
+                to: fee_receiver,
+            },
+        ),
+        lamports,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{__private::bytemuck, Discriminator};
+
+    fn fee_distributor() -> MerkleDistributor {
+        MerkleDistributor {
+            max_total_claim: 1_000,
+            has_claim_fee: 1,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_release_with_claim_fee_counts_gross_amount() {
+        let mut distributor = fee_distributor();
+        let claim_fee = ClaimFee {
+            bps: 250,
+            ..Default::default()
+        };
+
+        let (fee_amount, amount_to_claimant) =
+            release_with_claim_fee(&mut distributor, Some(&claim_fee), 400).unwrap();
+        assert_eq!(fee_amount, 10);
+        assert_eq!(amount_to_claimant, 390);
+        assert_eq!(distributor.total_amount_claimed, 400);
+
+        let (fee_amount, amount_to_claimant) =
+            release_with_claim_fee(&mut distributor, None, 100).unwrap();
+        assert_eq!((fee_amount, amount_to_claimant), (0, 100));
+        assert_eq!(distributor.total_amount_claimed, 500);
+
+        // the fee does not leave room for more claims
+        release_with_claim_fee(&mut distributor, Some(&claim_fee), 500).unwrap();
+        assert_eq!(
+            distributor.total_amount_claimed,
+            distributor.max_total_claim
+        );
+        assert_eq!(
+            release_with_claim_fee(&mut distributor, Some(&claim_fee), 1).unwrap_err(),
+            ErrorCode::ExceededMaxClaim.into()
+        );
+    }
+
+    #[test]
+    fn test_claim_fee_of_missing_accounts() {
+        let distributor = fee_distributor();
+        let token_program = anchor_spl::token::ID;
+
+        let claim_fee_key = Pubkey::new_unique();
+        let mut claim_fee_lamports = 0;
+        let mut claim_fee_data = ClaimFee::DISCRIMINATOR.to_vec();
+        claim_fee_data.extend_from_slice(bytemuck::bytes_of(&ClaimFee {
+            lamports: 1_000,
+            ..Default::default()
+        }));
+        let claim_fee_info = AccountInfo::new(
+            &claim_fee_key,
+            false,
+            false,
+            &mut claim_fee_lamports,
+            &mut claim_fee_data,
+            &crate::ID,
+            false,
+            0,
+        );
+        let fee_receiver_key = Pubkey::default();
+        let mut fee_receiver_lamports = 0;
+        let mut fee_receiver_data = vec![];
+        let fee_receiver_info = AccountInfo::new(
+            &fee_receiver_key,
+            false,
+            true,
+            &mut fee_receiver_lamports,
+            &mut fee_receiver_data,
+            &system_program::ID,
+            false,
+            0,
+        );
+        let claim_fee = Some(AccountLoader::<ClaimFee>::try_from(&claim_fee_info).unwrap());
+        let fee_receiver = Some(UncheckedAccount::try_from(fee_receiver_info));
+
+        let (loaded, receiver) =
+            claim_fee_of(&distributor, &claim_fee, &fee_receiver, &token_program)
+                .unwrap()
+                .unwrap();
+        assert_eq!(loaded.lamports, 1_000);
+        assert_eq!(receiver.key(), fee_receiver_key);
+
+        for (claim_fee, fee_receiver) in
+            [(&claim_fee, &None), (&None, &fee_receiver), (&None, &None)]
+        {
+            assert_eq!(
+                claim_fee_of(&distributor, claim_fee, fee_receiver, &token_program).unwrap_err(),
+                ErrorCode::InvalidClaimFeeAccount.into()
+            );
+        }
+
+        // distributors without a fee need neither
+        let distributor = MerkleDistributor::default();
+        assert!(claim_fee_of(&distributor, &None, &None, &token_program)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,66 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        hash::hash,
+        instruction::{AccountMeta, Instruction},
+        program::invoke_signed,
+    },
+};
+
+/// Instruction of the hook program called after a claim, as an Anchor `on_claim(amount: u64)`.
+/// Accounts passed to the hook, in order:
+/// 1. claim hook, the [crate::state::claim_hook::ClaimHook] PDA of the distributor, signer
+/// 2. distributor
+/// 3. claimant, signer
+/// 4. hook token account holding the claimed amount, writable
+/// 5. mint
+/// 6. the remaining accounts of the claim
+pub const HOOK_ON_CLAIM_IX: &str = "global:on_claim";
+
+/// Calls the hook program with the claimed amount, signed by the claim hook PDA.
+/// The distributor owns the token vault and never signs the hook call.
+#[allow(clippy::result_large_err)]
+pub fn invoke_claim_hook<'info>(
+    hook_program: AccountInfo<'info>,
+    claim_hook: AccountInfo<'info>,
+    distributor: AccountInfo<'info>,
+    claimant: AccountInfo<'info>,
+    hook_token_account: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let mut data = hash(HOOK_ON_CLAIM_IX.as_bytes()).to_bytes()[..8].to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(claim_hook.key(), true),
+        AccountMeta::new_readonly(distributor.key(), false),
+        AccountMeta::new_readonly(claimant.key(), true),
+        AccountMeta::new(hook_token_account.key(), false),
+        AccountMeta::new_readonly(mint.key(), false),
+    ];
+    accounts.extend(remaining_accounts.iter().map(|account| {
+        if account.is_writable {
+            AccountMeta::new(account.key(), account.is_signer)
+        } else {
+            AccountMeta::new_readonly(account.key(), account.is_signer)
+        }
+    }));
+
+    let mut account_infos = vec![claim_hook, distributor, claimant, hook_token_account, mint];
+    account_infos.extend_from_slice(remaining_accounts);
+    account_infos.push(hook_program.clone());
+
+    invoke_signed(
+        &Instruction {
+            program_id: hook_program.key(),
+            accounts,
+            data,
+        },
+        &account_infos,
+        &[signer_seeds],
+    )?;
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+    error::ErrorCode,
+    state::{campaign::Campaign, merkle_distributor::MerkleDistributor},
+};
+
+/// Returns whether the distributor has been clawed back, by itself or with its [Campaign].
+/// Clawing back a campaign leaves the clawed_back flag of its distributors untouched.
+#[allow(clippy::result_large_err)]
+pub fn distributor_clawed_back(
+    distributor: &MerkleDistributor,
+    distributor_key: &Pubkey,
+    campaign: &Option<AccountLoader<Campaign>>,
+) -> Result<bool> {
+    if distributor.clawed_back() || !distributor.in_campaign() {
+        return Ok(distributor.clawed_back());
+    }
+    let campaign = campaign.as_ref().ok_or(ErrorCode::InvalidCampaign)?;
+    require_keys_eq!(
+        campaign.key(),
+        distributor.token_vault_authority(distributor_key)?,
+        ErrorCode::InvalidCampaign
+    );
+    let clawed_back = campaign.load()?.clawed_back();
+    Ok(clawed_back)
+}
+
+/// Returns whether claiming from the distributor is over for good: it has been clawed back,
+/// or closed since. A closed distributor is an empty account owned by the system program.
+#[allow(clippy::result_large_err)]
+pub fn distributor_claims_ended(
+    distributor: &AccountInfo,
+    campaign: &Option<AccountLoader<Campaign>>,
+) -> Result<bool> {
+    if distributor.data_is_empty() && *distributor.owner == system_program::ID {
+        return Ok(true);
+    }
+    let distributor_loader = AccountLoader::<MerkleDistributor>::try_from(distributor)?;
+    let distributor_state = distributor_loader.load()?;
+    distributor_clawed_back(&distributor_state, distributor.key, campaign)
+}
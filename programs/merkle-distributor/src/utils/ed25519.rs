@@ -0,0 +1,192 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        ed25519_program,
+        instruction::Instruction,
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    },
+};
+
+use crate::error::ErrorCode;
+
+// Layout of the ed25519 program instruction data, see solana_sdk::ed25519_instruction
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 14;
+const PUBKEY_SERIALIZED_SIZE: usize = 32;
+
+/// Checks that the instruction right before the current one is an ed25519 program
+/// instruction verifying a single signature of `signer` over `message`.
+/// The ed25519 program fails the transaction if the signature is invalid, so only the
+/// signer and message it verified are compared here.
+#[allow(clippy::result_large_err)]
+pub fn verify_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, ErrorCode::InvalidEd25519Instruction);
+
+    let ix = load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+    verify_ed25519_instruction(&ix, signer, message)
+}
+
+/// Checks that `ix` is an ed25519 program instruction verifying a single signature of
+/// `signer` over `message`, with the signature, public key and message in its own data.
+#[allow(clippy::result_large_err)]
+pub fn verify_ed25519_instruction(ix: &Instruction, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    require!(
+        ix.program_id == ed25519_program::ID && ix.accounts.is_empty(),
+        ErrorCode::InvalidEd25519Instruction
+    );
+
+    let data = &ix.data;
+    require!(
+        data.len() >= SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SERIALIZED_SIZE && data[0] == 1,
+        ErrorCode::InvalidEd25519Instruction
+    );
+
+    let read_u16 = |i: usize| {
+        let start = SIGNATURE_OFFSETS_START + i * 2;
+        u16::from_le_bytes([data[start], data[start + 1]])
+    };
+    let signature_instruction_index = read_u16(1);
+    let public_key_offset = read_u16(2) as usize;
+    let public_key_instruction_index = read_u16(3);
+    let message_data_offset = read_u16(4) as usize;
+    let message_data_size = read_u16(5) as usize;
+    let message_instruction_index = read_u16(6);
+
+    // signature, public key and message must all live in the ed25519 instruction itself
+    require!(
+        signature_instruction_index == u16::MAX
+            && public_key_instruction_index == u16::MAX
+            && message_instruction_index == u16::MAX,
+        ErrorCode::InvalidEd25519Instruction
+    );
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + PUBKEY_SERIALIZED_SIZE)
+        .ok_or(ErrorCode::InvalidEd25519Instruction)?;
+    let message_data = data
+        .get(message_data_offset..message_data_offset + message_data_size)
+        .ok_or(ErrorCode::InvalidEd25519Instruction)?;
+
+    require!(
+        public_key == signer.as_ref() && message_data == message,
+        ErrorCode::InvalidEd25519Instruction
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    const PUBKEY_OFFSET: u16 = 16;
+    const SIGNATURE_OFFSET: u16 = 48;
+    const MESSAGE_OFFSET: u16 = 112;
+
+    /// ed25519 instruction with the layout of solana_sdk::ed25519_instruction, the signature
+    /// is not checked by verify_ed25519_instruction
+    pub(crate) fn ed25519_ix(signer: &Pubkey, message: &[u8], offsets: [u16; 7]) -> Instruction {
+        let mut data = vec![1, 0];
+        for offset in offsets {
+            data.extend_from_slice(&offset.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[7; 64]);
+        data.extend_from_slice(message);
+        Instruction {
+            program_id: ed25519_program::ID,
+            accounts: vec![],
+            data,
+        }
+    }
+
+    pub(crate) fn offsets(message: &[u8]) -> [u16; 7] {
+        [
+            SIGNATURE_OFFSET,
+            u16::MAX,
+            PUBKEY_OFFSET,
+            u16::MAX,
+            MESSAGE_OFFSET,
+            message.len() as u16,
+            u16::MAX,
+        ]
+    }
+
+    #[test]
+    fn test_verify_ed25519_instruction() {
+        let signer = Pubkey::new_unique();
+        let message = b"claim".to_vec();
+        let ix = ed25519_ix(&signer, &message, offsets(&message));
+        assert!(verify_ed25519_instruction(&ix, &signer, &message).is_ok());
+
+        let not_ed25519 = Instruction {
+            program_id: Pubkey::new_unique(),
+            ..ix.clone()
+        };
+        assert_eq!(
+            verify_ed25519_instruction(&not_ed25519, &signer, &message).unwrap_err(),
+            ErrorCode::InvalidEd25519Instruction.into()
+        );
+    }
+
+    #[test]
+    fn test_verify_ed25519_instruction_index() {
+        let signer = Pubkey::new_unique();
+        let message = b"claim".to_vec();
+        // signature, public key or message read from another instruction
+        for i in [1, 3, 6] {
+            let mut offsets = offsets(&message);
+            offsets[i] = 0;
+            let ix = ed25519_ix(&signer, &message, offsets);
+            assert_eq!(
+                verify_ed25519_instruction(&ix, &signer, &message).unwrap_err(),
+                ErrorCode::InvalidEd25519Instruction.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_ed25519_offsets() {
+        let signer = Pubkey::new_unique();
+        let message = b"claim".to_vec();
+
+        // public key read from the signature
+        let mut wrong_pubkey = offsets(&message);
+        wrong_pubkey[2] = SIGNATURE_OFFSET;
+        // message read past the end of the data
+        let mut wrong_message = offsets(&message);
+        wrong_message[4] = MESSAGE_OFFSET + 1;
+        // message size shorter than the message
+        let mut short_message = offsets(&message);
+        short_message[5] = message.len() as u16 - 1;
+
+        for offsets in [wrong_pubkey, wrong_message, short_message] {
+            let ix = ed25519_ix(&signer, &message, offsets);
+            assert_eq!(
+                verify_ed25519_instruction(&ix, &signer, &message).unwrap_err(),
+                ErrorCode::InvalidEd25519Instruction.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_ed25519_mismatch() {
+        let signer = Pubkey::new_unique();
+        let message = b"claim".to_vec();
+        let ix = ed25519_ix(&signer, &message, offsets(&message));
+
+        assert_eq!(
+            verify_ed25519_instruction(&ix, &signer, b"clain").unwrap_err(),
+            ErrorCode::InvalidEd25519Instruction.into()
+        );
+        assert_eq!(
+            verify_ed25519_instruction(&ix, &Pubkey::new_unique(), &message).unwrap_err(),
+            ErrorCode::InvalidEd25519Instruction.into()
+        );
+    }
+}
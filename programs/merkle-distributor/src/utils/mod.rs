@@ -0,0 +1,14 @@
+pub mod campaign;
+pub mod claim;
+pub mod claim_fee;
+pub mod claim_hook;
+pub mod distributor;
+pub mod ed25519;
+pub mod token;
+pub use campaign::*;
+pub use claim::*;
+pub use claim_fee::*;
+pub use claim_hook::*;
+pub use distributor::*;
+pub use ed25519::*;
+pub use token::*;
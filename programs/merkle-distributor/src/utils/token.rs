@@ -0,0 +1,101 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke};
+use anchor_spl::{
+    token::Token,
+    token_2022::spl_token_2022::{
+        self,
+        extension::{
+            transfer_fee::{
+                instruction::harvest_withheld_tokens_to_mint, TransferFeeAmount, TransferFeeConfig,
+            },
+            BaseStateWithExtensions, StateWithExtensions,
+        },
+    },
+    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{error::ErrorCode, state::merkle_distributor::VaultSigner};
+
+/// Transfers `amount` out of the token vault of a distributor, signed by its vault authority.
+#[allow(clippy::result_large_err)]
+pub fn transfer_from_vault<'info>(
+    token_program: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: AccountInfo<'info>,
+    vault_authority: AccountInfo<'info>,
+    signer: &VaultSigner,
+    amount: u64,
+) -> Result<()> {
+    let seeds = signer.seeds();
+    token_interface::transfer_checked(
+        CpiContext::new(
+            token_program,
+            TransferChecked {
+                from,
+                mint: mint.to_account_info(),
+                to,
+                authority: vault_authority,
+            },
+        )
+        .with_signer(&[&seeds[..]]),
+        amount,
+        mint.decimals,
+    )
+}
+
+/// Returns the fee withheld by the Token-2022 transfer-fee extension when `amount` leaves the vault.
+/// Mints owned by the SPL Token program, or without the extension, never charge a fee.
+#[allow(clippy::result_large_err)]
+pub fn calculate_transfer_fee(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+    if *mint_info.owner == Token::id() {
+        return Ok(0);
+    }
+
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    let fee = match mint.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => transfer_fee_config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(ErrorCode::ArithmeticError)?,
+        Err(_) => 0,
+    };
+    Ok(fee)
+}
+
+/// Moves the fees withheld on a Token-2022 token account to its mint, as Token-2022 refuses to
+/// close an account with withheld fees. Harvesting is permissionless, no signature is needed.
+#[allow(clippy::result_large_err)]
+pub fn harvest_withheld_fees<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_account: &InterfaceAccount<'info, TokenAccount>,
+) -> Result<()> {
+    let account_info = token_account.to_account_info();
+    if *account_info.owner == Token::id() {
+        return Ok(());
+    }
+
+    let withheld_amount = {
+        let account_data = account_info.try_borrow_data()?;
+        let account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account_data)?;
+        match account.get_extension::<TransferFeeAmount>() {
+            Ok(transfer_fee_amount) => u64::from(transfer_fee_amount.withheld_amount),
+            Err(_) => 0,
+        }
+    };
+    if withheld_amount == 0 {
+        return Ok(());
+    }
+
+    invoke(
+        &harvest_withheld_tokens_to_mint(
+            &token_program.key(),
+            &mint.key(),
+            &[&token_account.key()],
+        )?,
+        &[mint.to_account_info(), account_info],
+    )?;
+
+    Ok(())
+}
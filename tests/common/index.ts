@@ -157,7 +157,7 @@ export function getRandomInt(min, max) {
 
 
 
-export const getOrCreateAssociatedTokenAccountWrap = async (connection: web3.Connection, payer: web3.Keypair, tokenMint: web3.PublicKey, owner: web3.PublicKey) => {
+export const getOrCreateAssociatedTokenAccountWrap = async (connection: web3.Connection, payer: web3.Keypair, tokenMint: web3.PublicKey, owner: web3.PublicKey, tokenProgram: web3.PublicKey = TOKEN_PROGRAM_ID) => {
   return (await getOrCreateAssociatedTokenAccount(
     connection,
     payer,
@@ -168,7 +168,7 @@ export const getOrCreateAssociatedTokenAccountWrap = async (connection: web3.Con
     {
       commitment: "confirmed",
     },
-    TOKEN_PROGRAM_ID,
+    tokenProgram,
     ASSOCIATED_TOKEN_PROGRAM_ID
   )).address;
 }
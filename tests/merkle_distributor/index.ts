@@ -32,6 +32,17 @@ export function deriveDistributor(
     return pk
 }
 
+export function deriveClaimRevocation(
+    distributor: web3.PublicKey,
+    claimant: web3.PublicKey,
+) {
+    let [pk, _] = web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("ClaimRevocation"), claimant.toBuffer(), distributor.toBuffer()],
+        MERKLE_DISTRIBUTOR_PROGRAM_ID
+    );
+    return pk
+}
+
 
 
 export function deriveClaimStatus(
@@ -76,13 +87,20 @@ export interface CreateNewDisitrbutorParams {
     activationPoint: BN;
     activationType: number;
     closable: boolean;
+    revocable?: boolean;
+    earlyExitPenaltyBps?: number;
+    earlyExitPenaltyToClawback?: boolean;
     totalBonus: BN;
     bonusVestingDuration: BN;
+    bonusCurveType?: number;
+    bonusNumSteps?: number;
+    lockBonusSchedule?: { minLockDuration: BN, bonusBps: number }[];
     claimType: number;
     operator: PublicKey;
     locker: PublicKey;
     mint: PublicKey;
     clawbackReceiver: PublicKey;
+    tokenProgram?: PublicKey;
 }
 
 export async function createNewDistributor(
@@ -99,15 +117,17 @@ export async function createNewDistributor(
         clawbackStartTs,
         activationPoint,
         activationType,
-        closable, totalBonus, bonusVestingDuration,
+        closable, revocable, earlyExitPenaltyBps, earlyExitPenaltyToClawback,
+        totalBonus, bonusVestingDuration, bonusCurveType, bonusNumSteps, lockBonusSchedule,
         claimType, operator, locker, mint, clawbackReceiver
     } = params;
+    let tokenProgram = params.tokenProgram ?? TOKEN_PROGRAM_ID;
     const program = createDistributorProgram(new Wallet(admin));
 
     let base = Keypair.generate();
 
     let distributor = deriveDistributor(base.publicKey, mint, version);
-    let tokenVault = await getOrCreateAssociatedTokenAccountWrap(program.provider.connection, admin, mint, distributor);
+    let tokenVault = await getOrCreateAssociatedTokenAccountWrap(program.provider.connection, admin, mint, distributor, tokenProgram);
     await program.methods
         .newDistributor({
             version: new BN(version),
@@ -120,11 +140,17 @@ export async function createNewDistributor(
             activationPoint,
             activationType,
             closable,
+            revocable: revocable ?? false,
+            earlyExitPenaltyBps: earlyExitPenaltyBps ?? 0,
+            earlyExitPenaltyToClawback: earlyExitPenaltyToClawback ?? false,
             totalBonus,
             bonusVestingDuration,
+            bonusCurveType: bonusCurveType ?? 0,
+            bonusNumSteps: bonusNumSteps ?? 0,
             claimType,
             operator,
             locker,
+            lockBonusSchedule: lockBonusSchedule ?? [],
         })
         .accounts({
             distributor,
@@ -134,7 +160,7 @@ export async function createNewDistributor(
             admin: admin.publicKey,
             base: base.publicKey,
             systemProgram: web3.SystemProgram.programId,
-            tokenProgram: TOKEN_PROGRAM_ID,
+            tokenProgram,
         }).signers([base])
         .rpc().catch(console.log).then(console.log);
 
@@ -148,6 +174,7 @@ export interface ClaimParams {
     amountUnlocked: BN;
     amountLocked: BN;
     proof: Array<number>[];
+    tokenProgram?: PublicKey;
 }
 
 
@@ -155,21 +182,25 @@ export async function claim(
     params: ClaimParams
 ) {
     let { claimant, amountUnlocked, amountLocked, proof, distributor, operator } = params;
+    let tokenProgram = params.tokenProgram ?? TOKEN_PROGRAM_ID;
     const program = createDistributorProgram(new Wallet(claimant));
 
     let distributorState = await program.account.merkleDistributor.fetch(distributor);
     let claimStatus = deriveClaimStatus(distributor, claimant.publicKey);
-    let to = await getOrCreateAssociatedTokenAccountWrap(program.provider.connection, claimant, distributorState.mint, claimant.publicKey);
+    let claimRevocation = deriveClaimRevocation(distributor, claimant.publicKey);
+    let to = await getOrCreateAssociatedTokenAccountWrap(program.provider.connection, claimant, distributorState.mint, claimant.publicKey, tokenProgram);
 
     if (operator == null) {
         await program.methods.newClaim(amountUnlocked, amountLocked, proof).accounts({
             distributor,
             claimant: claimant.publicKey,
             claimStatus,
+            claimRevocation,
             from: distributorState.tokenVault,
             to,
+            mint: distributorState.mint,
             systemProgram: web3.SystemProgram.programId,
-            tokenProgram: TOKEN_PROGRAM_ID,
+            tokenProgram,
             operator: null,
         }).rpc().catch(console.log).then(console.log);
     } else {
@@ -178,10 +209,12 @@ export async function claim(
             distributor,
             claimant: claimant.publicKey,
             claimStatus,
+            claimRevocation,
             from: distributorState.tokenVault,
             to,
+            mint: distributorState.mint,
             systemProgram: web3.SystemProgram.programId,
-            tokenProgram: TOKEN_PROGRAM_ID,
+            tokenProgram,
             operator: operator.publicKey,
         }).transaction();
 
@@ -219,12 +252,14 @@ export async function claimAndStake(
 
     let distributorState = await program.account.merkleDistributor.fetch(distributor);
     let claimStatus = deriveClaimStatus(distributor, claimant.publicKey);
+    let claimRevocation = deriveClaimRevocation(distributor, claimant.publicKey);
 
     if (operator == null) {
         await program.methods.newClaimAndStake(amountUnlocked, amountLocked, proof).accounts({
             distributor,
             claimant: claimant.publicKey,
             claimStatus,
+            claimRevocation,
             from: distributorState.tokenVault,
             systemProgram: web3.SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
@@ -240,6 +275,7 @@ export async function claimAndStake(
             distributor,
             claimant: claimant.publicKey,
             claimStatus,
+            claimRevocation,
             from: distributorState.tokenVault,
             systemProgram: web3.SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
@@ -257,17 +293,19 @@ export interface ClaimLockedParams {
     claimant: Keypair;
     operator?: Keypair;
     distributor: PublicKey,
+    tokenProgram?: PublicKey;
 }
 
 export async function claimLocked(
     params: ClaimLockedParams
 ) {
     let { claimant, distributor, operator } = params;
+    let tokenProgram = params.tokenProgram ?? TOKEN_PROGRAM_ID;
     const program = createDistributorProgram(new Wallet(claimant));
 
     let distributorState = await program.account.merkleDistributor.fetch(distributor);
     let claimStatus = deriveClaimStatus(distributor, claimant.publicKey);
-    let to = await getOrCreateAssociatedTokenAccountWrap(program.provider.connection, claimant, distributorState.mint, claimant.publicKey);
+    let to = await getOrCreateAssociatedTokenAccountWrap(program.provider.connection, claimant, distributorState.mint, claimant.publicKey, tokenProgram);
 
     if (operator == null) {
         await program.methods.claimLocked().accounts({
@@ -276,7 +314,8 @@ export async function claimLocked(
             claimStatus,
             from: distributorState.tokenVault,
             to,
-            tokenProgram: TOKEN_PROGRAM_ID,
+            mint: distributorState.mint,
+            tokenProgram,
             operator: null,
         }).rpc().catch(console.log).then(console.log);
     } else {
@@ -286,7 +325,8 @@ export async function claimLocked(
             claimStatus,
             from: distributorState.tokenVault,
             to,
-            tokenProgram: TOKEN_PROGRAM_ID,
+            mint: distributorState.mint,
+            tokenProgram,
             operator: operator.publicKey,
         }).signers([operator]).rpc().catch(console.log).then(console.log);
     }
@@ -343,12 +383,14 @@ export async function claimLockedAndStake(
 export interface ClawbackParams {
     payer: Keypair;
     distributor: PublicKey,
+    tokenProgram?: PublicKey;
 }
 
 export async function clawBack(
     params: ClawbackParams
 ) {
     let { payer, distributor } = params;
+    let tokenProgram = params.tokenProgram ?? TOKEN_PROGRAM_ID;
     const program = createDistributorProgram(new Wallet(payer));
 
     let distributorState = await program.account.merkleDistributor.fetch(distributor);
@@ -357,6 +399,31 @@ export async function clawBack(
         distributor,
         from: distributorState.tokenVault,
         clawbackReceiver: distributorState.clawbackReceiver,
+        mint: distributorState.mint,
+        tokenProgram,
+    }).rpc().catch(console.log).then(console.log);
+}
+
+export interface CloseClawedBackDistributorParams {
+    admin: Keypair;
+    distributor: PublicKey,
+}
+
+export async function closeClawedBackDistributor(
+    params: CloseClawedBackDistributorParams
+) {
+    let { admin, distributor } = params;
+    const program = createDistributorProgram(new Wallet(admin));
+
+    let distributorState = await program.account.merkleDistributor.fetch(distributor);
+
+    await program.methods.closeClawedBackDistributor().accounts({
+        distributor,
+        tokenVault: distributorState.tokenVault,
+        clawbackReceiver: distributorState.clawbackReceiver,
+        mint: distributorState.mint,
+        admin: admin.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
+        campaign: null,
     }).rpc().catch(console.log).then(console.log);
-}
\ No newline at end of file
+}
@@ -1,11 +1,12 @@
 import * as anchor from "@coral-xyz/anchor";
 import { BalanceTree } from "./merkle_tree";
 import { Wallet, web3 } from "@coral-xyz/anchor";
-import { ADMIN, claim, claimLocked, clawBack, createNewDistributor } from "./merkle_distributor";
+import { ADMIN, claim, claimLocked, clawBack, closeClawedBackDistributor, createNewDistributor } from "./merkle_distributor";
 import { createAndFundWallet, getBlockTime, getOrCreateAssociatedTokenAccountWrap, getRandomInt, sleep } from "./common";
 import { BN } from "bn.js";
 import { Keypair, PublicKey } from "@solana/web3.js";
-import { createMint, mintTo, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { createMint, getAccount, mintTo, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { expect } from "chai";
 const provider = anchor.AnchorProvider.env();
 
 describe("Claim permissionless", () => {
@@ -156,5 +157,21 @@ describe("Claim permissionless", () => {
             distributor,
             payer: ADMIN,
         })
+
+        console.log("close clawed back distributor")
+        // tokens sent to the vault after the clawback must not block the close
+        let leftover = 1000;
+        await mintTo(provider.connection, ADMIN, mint, tokenVault, ADMIN, leftover);
+        let clawbackReceiverBefore = await getAccount(provider.connection, clawbackReceiver);
+
+        await closeClawedBackDistributor({
+            distributor,
+            admin,
+        })
+
+        let clawbackReceiverAfter = await getAccount(provider.connection, clawbackReceiver);
+        expect(clawbackReceiverAfter.amount - clawbackReceiverBefore.amount).to.equal(BigInt(leftover));
+        expect(await provider.connection.getAccountInfo(tokenVault)).to.be.null;
+        expect(await provider.connection.getAccountInfo(distributor)).to.be.null;
     })
 })
\ No newline at end of file
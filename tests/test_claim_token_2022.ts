@@ -0,0 +1,261 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BalanceTree } from "./merkle_tree";
+import { Wallet, web3 } from "@coral-xyz/anchor";
+import { ADMIN, claim, claimLocked, clawBack, createDistributorProgram, createNewDistributor } from "./merkle_distributor";
+import { createAndFundWallet, getBlockTime, getOrCreateAssociatedTokenAccountWrap, getRandomInt, sleep } from "./common";
+import { BN } from "bn.js";
+import { Keypair, PublicKey, sendAndConfirmTransaction, SystemProgram, Transaction } from "@solana/web3.js";
+import {
+    createInitializeMintInstruction,
+    createInitializeTransferFeeConfigInstruction,
+    createTransferCheckedInstruction,
+    ExtensionType,
+    getAccount,
+    getMintLen,
+    mintTo,
+    TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { expect } from "chai";
+const provider = anchor.AnchorProvider.env();
+
+const TRANSFER_FEE_BPS = 100;
+const MAX_TRANSFER_FEE = BigInt(1_000_000_000);
+const DECIMALS = 6;
+
+// fee withheld by Token-2022 on a transfer of `amount`, rounded up
+function transferFee(amount: bigint): bigint {
+    let fee = (amount * BigInt(TRANSFER_FEE_BPS) + BigInt(9_999)) / BigInt(10_000);
+    return fee > MAX_TRANSFER_FEE ? MAX_TRANSFER_FEE : fee;
+}
+
+// fee to add to `amount` so the destination still receives it in full, as fund_all does
+function inverseTransferFee(amount: bigint): bigint {
+    let preFeeAmount = (amount * BigInt(10_000) + BigInt(10_000 - TRANSFER_FEE_BPS - 1)) / BigInt(10_000 - TRANSFER_FEE_BPS);
+    return transferFee(preFeeAmount);
+}
+
+async function createTransferFeeMint(connection: web3.Connection, payer: Keypair): Promise<PublicKey> {
+    let mintKeypair = Keypair.generate();
+    let mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+    let lamports = await connection.getMinimumBalanceForRentExemption(mintLen);
+    let tx = new Transaction().add(
+        SystemProgram.createAccount({
+            fromPubkey: payer.publicKey,
+            newAccountPubkey: mintKeypair.publicKey,
+            space: mintLen,
+            lamports,
+            programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        createInitializeTransferFeeConfigInstruction(
+            mintKeypair.publicKey,
+            payer.publicKey,
+            payer.publicKey,
+            TRANSFER_FEE_BPS,
+            MAX_TRANSFER_FEE,
+            TOKEN_2022_PROGRAM_ID
+        ),
+        createInitializeMintInstruction(mintKeypair.publicKey, DECIMALS, payer.publicKey, null, TOKEN_2022_PROGRAM_ID)
+    );
+    await sendAndConfirmTransaction(connection, tx, [payer, mintKeypair], { commitment: "confirmed" });
+    return mintKeypair.publicKey;
+}
+
+describe("Claim Token-2022 with transfer fee", () => {
+    let admin = Keypair.generate();
+    let tree: BalanceTree;
+    let maxNumNodes = 5;
+    let whitelistedKPs: web3.Keypair[] = [];
+    let amountUnlockedArr: anchor.BN[] = [];
+    let amountLockedArr: anchor.BN[] = [];
+    let totalClaim = new BN(0);
+    let mint: PublicKey;
+    before(async () => {
+        await createAndFundWallet(provider.connection, ADMIN);
+        await createAndFundWallet(provider.connection, admin);
+
+        for (let i = 0; i < maxNumNodes; i++) {
+            const result = await createAndFundWallet(provider.connection);
+            whitelistedKPs.push(result.keypair);
+            let amountUnlocked = new BN(getRandomInt(1000, 20000));
+            let amountLocked = new BN(getRandomInt(1000, 20000));
+
+            amountUnlockedArr.push(amountUnlocked);
+            amountLockedArr.push(amountLocked);
+            totalClaim = totalClaim.add(amountUnlocked).add(amountLocked);
+        }
+
+        tree = new BalanceTree(
+            whitelistedKPs.map((kp, index) => {
+                return { account: kp.publicKey, amountUnlocked: amountUnlockedArr[index], amountLocked: amountLockedArr[index] };
+            })
+        );
+
+        mint = await createTransferFeeMint(provider.connection, ADMIN);
+    })
+    it("Full flow", async () => {
+        console.log("create distributor");
+        let currentTime = await getBlockTime(provider.connection);
+        let startVestingTs = new BN(currentTime + 3);
+        let endVestingTs = new BN(currentTime + 6);
+        let clawbackStartTs = new BN(currentTime + 7);
+        let activationType = 1; // timestamp
+        let activationPoint = new BN(currentTime + 2);
+        let closable = false;
+        let totalBonus = new BN(0);
+        let bonusVestingDuration = new BN(0);
+        let claimType = 0;
+        let operator = web3.SystemProgram.programId;
+        let locker = web3.SystemProgram.programId;
+        let tokenProgram = TOKEN_2022_PROGRAM_ID;
+
+        let clawbackReceiver = await getOrCreateAssociatedTokenAccountWrap(provider.connection, ADMIN, mint, ADMIN.publicKey, tokenProgram);
+        let { distributor, tokenVault } = await createNewDistributor({
+            admin,
+            version: 0,
+            root: tree.getRoot(),
+            totalClaim,
+            maxNumNodes: new BN(maxNumNodes),
+            startVestingTs,
+            endVestingTs,
+            clawbackStartTs,
+            activationPoint,
+            activationType,
+            closable,
+            totalBonus,
+            bonusVestingDuration,
+            claimType,
+            operator,
+            locker,
+            mint,
+            clawbackReceiver,
+            tokenProgram,
+        });
+
+        console.log("fund distributor");
+        // gross up the funding so the vault still receives max_total_claim after the transfer fee
+        let maxTotalClaim = BigInt(totalClaim.toString());
+        let grossAmount = maxTotalClaim + inverseTransferFee(maxTotalClaim);
+        let source = await getOrCreateAssociatedTokenAccountWrap(provider.connection, ADMIN, mint, ADMIN.publicKey, tokenProgram);
+        await mintTo(provider.connection, ADMIN, mint, source, ADMIN, grossAmount, [], { commitment: "confirmed" }, tokenProgram);
+        await sendAndConfirmTransaction(
+            provider.connection,
+            new Transaction().add(
+                createTransferCheckedInstruction(source, mint, tokenVault, ADMIN.publicKey, grossAmount, DECIMALS, [], tokenProgram)
+            ),
+            [ADMIN],
+            { commitment: "confirmed" }
+        );
+        let vault = await getAccount(provider.connection, tokenVault, "confirmed", tokenProgram);
+        let fundedAmount = vault.amount;
+        expect(fundedAmount).to.equal(grossAmount - transferFee(grossAmount));
+        expect(fundedAmount >= maxTotalClaim).to.be.true;
+
+        while (true) {
+            const currentTime = await getBlockTime(provider.connection);
+            if (currentTime > activationPoint.toNumber()) {
+                break;
+            } else {
+                await sleep(1000);
+                console.log("Wait until activationPoint");
+            }
+        }
+
+        const program = createDistributorProgram(new Wallet(admin));
+        let totalClaimed = BigInt(0);
+
+        console.log("claim")
+        for (let i = 0; i < maxNumNodes - 1; i++) {
+            var proofBuffers = tree.getProof(
+                whitelistedKPs[i].publicKey,
+                amountUnlockedArr[i],
+                amountLockedArr[i]
+            );
+            let proof = [];
+            proofBuffers.forEach(function (value) {
+                proof.push(Array.from(new Uint8Array(value)));
+            });
+            console.log("claim index: ", i);
+            await claim({
+                distributor,
+                claimant: whitelistedKPs[i],
+                amountUnlocked: amountUnlockedArr[i],
+                amountLocked: amountLockedArr[i],
+                proof,
+                tokenProgram,
+            })
+
+            // the claimant receives the unlocked amount net of the fee, the distributor counts it gross
+            let amountUnlocked = BigInt(amountUnlockedArr[i].toString());
+            totalClaimed += amountUnlocked;
+            let to = await getOrCreateAssociatedTokenAccountWrap(provider.connection, whitelistedKPs[i], mint, whitelistedKPs[i].publicKey, tokenProgram);
+            let claimantAccount = await getAccount(provider.connection, to, "confirmed", tokenProgram);
+            expect(claimantAccount.amount).to.equal(amountUnlocked - transferFee(amountUnlocked));
+        }
+
+        let distributorState = await program.account.merkleDistributor.fetch(distributor);
+        expect(distributorState.totalAmountClaimed.toString()).to.equal(totalClaimed.toString());
+        vault = await getAccount(provider.connection, tokenVault, "confirmed", tokenProgram);
+        expect(vault.amount).to.equal(fundedAmount - totalClaimed);
+
+        while (true) {
+            const currentTime = await getBlockTime(provider.connection);
+            if (currentTime > endVestingTs.toNumber()) {
+                break;
+            } else {
+                await sleep(1000);
+                console.log("Wait until endVestingTs");
+            }
+        }
+        console.log("claim locked")
+        for (let i = 0; i < maxNumNodes - 1; i++) {
+            console.log("claim locked index: ", i);
+            await claimLocked({
+                distributor,
+                claimant: whitelistedKPs[i],
+                tokenProgram,
+            })
+
+            let amountUnlocked = BigInt(amountUnlockedArr[i].toString());
+            let amountLocked = BigInt(amountLockedArr[i].toString());
+            totalClaimed += amountLocked;
+            let to = await getOrCreateAssociatedTokenAccountWrap(provider.connection, whitelistedKPs[i], mint, whitelistedKPs[i].publicKey, tokenProgram);
+            let claimantAccount = await getAccount(provider.connection, to, "confirmed", tokenProgram);
+            expect(claimantAccount.amount).to.equal(
+                amountUnlocked - transferFee(amountUnlocked) + amountLocked - transferFee(amountLocked)
+            );
+        }
+
+        // the vault still covers what the unclaimed leaf is owed
+        distributorState = await program.account.merkleDistributor.fetch(distributor);
+        expect(distributorState.totalAmountClaimed.toString()).to.equal(totalClaimed.toString());
+        expect(distributorState.maxTotalClaim.toString()).to.equal(maxTotalClaim.toString());
+        vault = await getAccount(provider.connection, tokenVault, "confirmed", tokenProgram);
+        expect(vault.amount).to.equal(fundedAmount - totalClaimed);
+        expect(vault.amount >= maxTotalClaim - totalClaimed).to.be.true;
+
+        while (true) {
+            const currentTime = await getBlockTime(provider.connection);
+            if (currentTime > clawbackStartTs.toNumber()) {
+                break;
+            } else {
+                await sleep(1000);
+                console.log("Wait until clawbackStartTs");
+            }
+        }
+        console.log("clawback")
+        let clawbackAmount = vault.amount;
+        let clawbackReceiverBefore = await getAccount(provider.connection, clawbackReceiver, "confirmed", tokenProgram);
+        await clawBack({
+            distributor,
+            payer: ADMIN,
+            tokenProgram,
+        })
+
+        vault = await getAccount(provider.connection, tokenVault, "confirmed", tokenProgram);
+        expect(vault.amount).to.equal(BigInt(0));
+        let clawbackReceiverAfter = await getAccount(provider.connection, clawbackReceiver, "confirmed", tokenProgram);
+        expect(clawbackReceiverAfter.amount - clawbackReceiverBefore.amount).to.equal(
+            clawbackAmount - transferFee(clawbackAmount)
+        );
+    })
+})
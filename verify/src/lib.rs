@@ -1,4 +1,61 @@
-use solana_program::hash::hashv;
+use std::{fmt, str::FromStr};
+
+use solana_program::{hash::hashv, keccak};
+
+/// Hashing of the leaves and intermediate nodes of a merkle tree.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum HashMode {
+    /// sha256 with a `[0]` leaf prefix and a `[1]` intermediate node prefix, verified by the program
+    #[default]
+    Sha256,
+    /// keccak256 without prefixes and with double-hashed leaves, as OpenZeppelin's
+    /// `StandardMerkleTree`, verified by `MerkleProof.verify` on EVM chains
+    Keccak,
+}
+
+impl HashMode {
+    /// Hashes the (already hashed) data of a leaf into the leaf node.
+    pub fn hash_leaf(&self, data: &[u8]) -> [u8; 32] {
+        match self {
+            HashMode::Sha256 => hashv(&[&[0u8], data]).to_bytes(),
+            HashMode::Keccak => keccak::hashv(&[data]).to_bytes(),
+        }
+    }
+
+    /// Hashes two nodes into their parent node, in the given order.
+    pub fn hash_intermediate(&self, left: &[u8], right: &[u8]) -> [u8; 32] {
+        match self {
+            HashMode::Sha256 => hashv(&[&[1u8], left, right]).to_bytes(),
+            HashMode::Keccak => keccak::hashv(&[left, right]).to_bytes(),
+        }
+    }
+}
+
+impl FromStr for HashMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sha256" => Ok(HashMode::Sha256),
+            "keccak" => Ok(HashMode::Keccak),
+            _ => Err(format!("unknown hash mode {s}, expected sha256 or keccak")),
+        }
+    }
+}
+
+impl fmt::Display for HashMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HashMode::Sha256 => write!(f, "sha256"),
+            HashMode::Keccak => write!(f, "keccak"),
+        }
+    }
+}
 
 /// modified version of https://github.com/saber-hq/merkle-distributor/blob/ac937d1901033ecb7fa3b0db22f7b39569c8e052/programs/merkle-distributor/src/merkle_proof.rs#L8
 /// This function deals with verification of Merkle trees (hash trees).
@@ -8,16 +65,112 @@ use solana_program::hash::hashv;
 /// sibling hashes on the branch from the leaf to the root of the tree. Each
 /// pair of leaves and each pair of pre-images are assumed to be sorted.
 pub fn verify(proof: Vec<[u8; 32]>, root: [u8; 32], leaf: [u8; 32]) -> bool {
+    verify_with_hash_mode(proof, root, leaf, HashMode::Sha256)
+}
+
+/// Same as [verify], hashing the intermediate nodes with `hash_mode`.
+pub fn verify_with_hash_mode(
+    proof: Vec<[u8; 32]>,
+    root: [u8; 32],
+    leaf: [u8; 32],
+    hash_mode: HashMode,
+) -> bool {
     let mut computed_hash = leaf;
     for proof_element in proof.into_iter() {
         if computed_hash <= proof_element {
             // Hash(current computed hash + current element of the proof)
-            computed_hash = hashv(&[&[1u8], &computed_hash, &proof_element]).to_bytes();
+            computed_hash = hash_mode.hash_intermediate(&computed_hash, &proof_element);
         } else {
             // Hash(current element of the proof + current computed hash)
-            computed_hash = hashv(&[&[1u8], &proof_element, &computed_hash]).to_bytes();
+            computed_hash = hash_mode.hash_intermediate(&proof_element, &computed_hash);
         }
     }
     // Check if the computed hash (root) is equal to the provided root
     computed_hash == root
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_hex(s: &str) -> [u8; 32] {
+        hex::decode(s).unwrap().try_into().unwrap()
+    }
+
+    /// Leaf of `[address, uint256]` values in an OpenZeppelin `StandardMerkleTree`,
+    /// `keccak256(bytes.concat(keccak256(abi.encode(address, amount))))`
+    fn standard_leaf(address: [u8; 20], amount: u128) -> [u8; 32] {
+        let mut encoded = [0u8; 64];
+        encoded[12..32].copy_from_slice(&address);
+        encoded[48..].copy_from_slice(&amount.to_be_bytes());
+        HashMode::Keccak.hash_leaf(&keccak::hash(&encoded).to_bytes())
+    }
+
+    #[test]
+    fn test_verify_standard_merkle_tree_proof() {
+        // StandardMerkleTree.of([[0x1111..., 5e18], [0x2222..., 2.5e18]], ["address", "uint256"])
+        // from the OpenZeppelin merkle-tree README
+        let root = from_hex("d4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77");
+        let leaf = standard_leaf([0x11; 20], 5_000_000_000_000_000_000);
+        let other_leaf = standard_leaf([0x22; 20], 2_500_000_000_000_000_000);
+        assert_eq!(
+            leaf,
+            from_hex("eb02c421cfa48976e66dfb29120745909ea3a0f843456c263cf8f1253483e283")
+        );
+
+        assert!(verify_with_hash_mode(
+            vec![other_leaf],
+            root,
+            leaf,
+            HashMode::Keccak
+        ));
+        assert!(verify_with_hash_mode(
+            vec![leaf],
+            root,
+            other_leaf,
+            HashMode::Keccak
+        ));
+        // the program hashing rejects it
+        assert!(!verify_with_hash_mode(
+            vec![other_leaf],
+            root,
+            leaf,
+            HashMode::Sha256
+        ));
+        assert!(!verify(vec![other_leaf], root, leaf));
+    }
+
+    #[test]
+    fn test_verify_standard_merkle_tree_proof_of_two_levels() {
+        // with a third [0x3333..., 1e18] leaf
+        let root = from_hex("1bc1c2bd927f8451332d9a50179032863d2cf0ffca6fcfec1b3e36e0f6260d3e");
+        let leaf = standard_leaf([0x22; 20], 2_500_000_000_000_000_000);
+        let proof = vec![
+            from_hex("e4fc5b35ba4bd627dffb795fa4c398e7896386584837a8a23f7f3c9ab869b7cc"),
+            from_hex("eb02c421cfa48976e66dfb29120745909ea3a0f843456c263cf8f1253483e283"),
+        ];
+
+        assert!(verify_with_hash_mode(
+            proof.clone(),
+            root,
+            leaf,
+            HashMode::Keccak
+        ));
+        assert!(!verify_with_hash_mode(
+            proof.clone(),
+            root,
+            standard_leaf([0x22; 20], 2_500_000_000_000_000_001),
+            HashMode::Keccak
+        ));
+        assert!(!verify_with_hash_mode(proof, root, leaf, HashMode::Sha256));
+    }
+
+    #[test]
+    fn test_hash_mode_roundtrip() {
+        for hash_mode in [HashMode::Sha256, HashMode::Keccak] {
+            assert_eq!(HashMode::from_str(&hash_mode.to_string()), Ok(hash_mode));
+        }
+        assert_eq!(HashMode::default(), HashMode::Sha256);
+        assert!(HashMode::from_str("keccak256").is_err());
+    }
+}